@@ -1,9 +1,12 @@
+use std::io;
+use std::sync::Arc;
 use std::error::Error as StdError;
+use std::time::Duration;
 use uuid::Uuid;
 use futures::{Future, Poll};
-use futures::future::{FutureResult, IntoFuture, Either};
+use futures::future::{self, FutureResult, IntoFuture, Either, Loop};
 use futures_cpupool::{CpuPool, CpuFuture};
-use tokio_core::reactor::Handle;
+use tokio_core::reactor::{Handle, Timeout};
 use elastic_reqwest::{AsyncBody, AsyncElasticClient};
 use reqwest::Error as ReqwestError;
 use reqwest::unstable::async::{Client as AsyncHttpClient, ClientBuilder as AsyncHttpClientBuilder};
@@ -12,6 +15,9 @@ use error::{self, Error};
 use client::requests::HttpRequest;
 use client::responses::{async_response, AsyncResponseBuilder};
 use client::{private, Client, RequestParams, Sender};
+use client::nodes::{is_unreachable, NodeAddresses, NodePool, SniffedNodesBuilder};
+use client::responses::NodesInfoResponse;
+use client::retry::{is_failure_status, Attempts, FailureKind, RetryPolicy};
 
 /** 
 An asynchronous Elasticsearch client.
@@ -50,6 +56,9 @@ pub type AsyncClient = Client<AsyncSender>;
 pub struct AsyncSender {
     pub(in client) http: AsyncHttpClient,
     pub(in client) serde_pool: Option<CpuPool>,
+    pub(in client) handle: Option<Handle>,
+    pub(in client) nodes: NodeAddresses,
+    pub(in client) retry: Option<Arc<RetryPolicy>>,
 }
 
 impl private::Sealed for AsyncSender {}
@@ -63,35 +72,118 @@ impl Sender for AsyncSender {
         TRequest: Into<HttpRequest<'static, TBody>>,
         TBody: Into<Self::Body>,
     {
-        let serde_pool = self.serde_pool.clone();
-        let correlation_id = Uuid::new_v4();
         let req = req.into();
+        let req = HttpRequest {
+            url: req.url,
+            method: req.method,
+            body: req.body.map(Into::into),
+        };
+
+        if let Some(refresh) = self.nodes.should_refresh() {
+            let sender = self.clone();
+            let params = params.clone();
+
+            let refresh_future = self.send(refresh.sniff_request(), &refresh.sniff_params(&params))
+                .and_then(|res| res.into_response::<NodesInfoResponse>())
+                .then(move |res| {
+                    refresh.refresh(res);
+                    Ok(())
+                });
+
+            return Pending::new(refresh_future.and_then(move |()| sender.send(req, &params)));
+        }
+
+        if params.get_timeout().is_some() && self.handle.is_none() {
+            return Pending::new(future::err(error::request(io::Error::new(
+                io::ErrorKind::Other,
+                "a timeout requires an `AsyncClient` built with a reactor `Handle`",
+            ))));
+        }
+
+        let correlation_id = Uuid::new_v4();
+        let sender = self.clone();
+        let params = params.clone();
+        let attempts = Attempts::new(self.retry.clone(), req);
+
+        let req_future = future::loop_fn(attempts, move |mut attempts| {
+            let req = attempts.next();
+            let serde_pool = sender.serde_pool.clone();
+            let handle = sender.handle.clone();
+
+            let node = sender.nodes.next();
+            let node_params = node.as_ref().map(|node| node.params(&params));
+            let params = node_params.as_ref().unwrap_or(&params);
+
+            info!(
+                "Elasticsearch Request: correlation_id: '{}', attempt: '{}', method: '{:?}', base url: '{}', path: '{}'",
+                correlation_id,
+                attempts.attempt(),
+                req.method,
+                params.get_base_url(),
+                req.url.as_ref()
+            );
+
+            let unreachable_node = node.clone();
+            let req_future = sender.http.elastic_req(params, req).map_err(move |e| {
+                if let Some(ref node) = unreachable_node {
+                    if is_unreachable(&e) {
+                        node.mark_dead();
+                    }
+                }
 
-        info!(
-            "Elasticsearch Request: correlation_id: '{}', method: '{:?}', path: '{}'",
-            correlation_id,
-            req.method,
-            req.url.as_ref()
-        );
-
-        let req_future = self.http
-            .elastic_req(params, req)
-            .map_err(move |e| {
-                error!(
-                    "Elasticsearch Response: correlation_id: '{}', error: '{}'",
-                    correlation_id,
-                    e
-                );
                 error::request(e)
-            })
-            .map(move |res| {
-                info!(
-                    "Elasticsearch Response: correlation_id: '{}', status: '{}'",
-                    correlation_id,
-                    res.status()
-                );
-                async_response(res, serde_pool)
             });
+            let req_future = with_timeout(req_future, params.get_timeout(), handle.as_ref());
+
+            req_future.then(move |res| {
+                let (res, backoff) = match res {
+                    Ok(res) => {
+                        if let Some(ref node) = node {
+                            node.mark_alive();
+                        }
+
+                        info!(
+                            "Elasticsearch Response: correlation_id: '{}', status: '{}'",
+                            correlation_id,
+                            res.status()
+                        );
+
+                        let status: u16 = res.status().into();
+                        let backoff = if is_failure_status(status) {
+                            attempts.retry(FailureKind::Status(status))
+                        } else {
+                            None
+                        };
+
+                        (Ok(res), backoff)
+                    }
+                    Err(e) => {
+                        error!(
+                            "Elasticsearch Response: correlation_id: '{}', error: '{}'",
+                            correlation_id,
+                            e
+                        );
+
+                        let backoff = attempts.retry(FailureKind::Error(&e));
+
+                        (Err(e), backoff)
+                    }
+                };
+
+                let delay = match (backoff, handle) {
+                    (Some(backoff), Some(handle)) => Timeout::new(backoff, &handle).ok(),
+                    _ => None,
+                };
+
+                match delay {
+                    Some(delay) => Either::A(delay.then(move |_| Ok(Loop::Continue(attempts)))),
+                    None => Either::B(
+                        res.map(|res| Loop::Break(async_response(res, serde_pool)))
+                            .into_future(),
+                    ),
+                }
+            })
+        });
 
         Pending::new(req_future)
     }
@@ -112,7 +204,38 @@ impl AsyncSender {
     }
 }
 
-/** A future returned by calling `send`. */
+/**
+Race a request future against a timeout.
+
+If the timeout expires first then the request future is dropped, which cancels the request.
+*/
+fn with_timeout<F>(fut: F, timeout: Option<Duration>, handle: Option<&Handle>) -> Box<Future<Item = F::Item, Error = Error>>
+where
+    F: Future<Error = Error> + 'static,
+{
+    let delay = match (timeout, handle) {
+        (Some(timeout), Some(handle)) => Timeout::new(timeout, handle),
+        _ => return Box::new(fut),
+    };
+
+    let delay = match delay {
+        Ok(delay) => delay,
+        Err(e) => return Box::new(future::err(error::request(e))),
+    };
+
+    Box::new(fut.select2(delay).then(|res| match res {
+        Ok(Either::A((res, _))) => Ok(res),
+        Ok(Either::B(_)) => Err(error::timeout()),
+        Err(Either::A((e, _))) => Err(e),
+        Err(Either::B((e, _))) => Err(error::request(e)),
+    }))
+}
+
+/**
+A future returned by calling `send`.
+
+Dropping a `Pending` before it completes cancels the request.
+*/
 pub struct Pending {
     inner: Box<Future<Item = AsyncResponseBuilder, Error = Error>>,
 }
@@ -140,6 +263,8 @@ impl Future for Pending {
 /** A builder for an asynchronous client. */
 pub struct AsyncClientBuilder {
     serde_pool: Option<CpuPool>,
+    nodes: NodeAddresses,
+    retry: Option<Arc<RetryPolicy>>,
     params: RequestParams,
 }
 
@@ -150,6 +275,7 @@ This trait has a few default implementations:
 
 - `AsyncHttpClient`: returns `self`
 - `Handle`: returns a new `AsyncHttpClient` bound to `self`.
+- `(AsyncHttpClient, Handle)`: returns the `AsyncHttpClient` and keeps the `Handle` it's bound to.
 */
 pub trait IntoAsyncHttpClient {
     /** The type of error returned by the conversion. */
@@ -157,6 +283,16 @@ pub trait IntoAsyncHttpClient {
 
     /** Convert `self` into an `AsyncHttpClient`. */
     fn into_async_http_client(self) -> Result<AsyncHttpClient, Self::Error>;
+
+    /**
+    Get a handle to the reactor the `AsyncHttpClient` is bound to.
+
+    The handle is used to spawn background tasks, like the bulk processor.
+    If no handle is returned then those features won't be available.
+    */
+    fn reactor_handle(&self) -> Option<Handle> {
+        None
+    }
 }
 
 impl IntoAsyncHttpClient for AsyncHttpClient {
@@ -173,6 +309,22 @@ impl<'a> IntoAsyncHttpClient for &'a Handle {
     fn into_async_http_client(self) -> Result<AsyncHttpClient, Self::Error> {
         AsyncHttpClientBuilder::new().build(self)
     }
+
+    fn reactor_handle(&self) -> Option<Handle> {
+        Some((*self).clone())
+    }
+}
+
+impl<'a> IntoAsyncHttpClient for (AsyncHttpClient, &'a Handle) {
+    type Error = Error;
+
+    fn into_async_http_client(self) -> Result<AsyncHttpClient, Self::Error> {
+        Ok(self.0)
+    }
+
+    fn reactor_handle(&self) -> Option<Handle> {
+        Some(self.1.clone())
+    }
 }
 
 impl Default for AsyncClientBuilder {
@@ -191,10 +343,13 @@ impl AsyncClientBuilder {
     - Not deserialise repsonses on a cpu pool
     - Not use any authentication
     - Not use TLS
+    - Not time out requests
     */
     pub fn new() -> Self {
         AsyncClientBuilder {
             serde_pool: None,
+            nodes: NodeAddresses::Default,
+            retry: None,
             params: RequestParams::default(),
         }
     }
@@ -205,6 +360,8 @@ impl AsyncClientBuilder {
     pub fn from_params(params: RequestParams) -> Self {
         AsyncClientBuilder {
             serde_pool: None,
+            nodes: NodeAddresses::Default,
+            retry: None,
             params: params,
         }
     }
@@ -235,6 +392,93 @@ impl AsyncClientBuilder {
         self
     }
 
+    /**
+    Send requests to a fixed set of nodes.
+
+    The nodes must be fully qualified urls.
+    Requests are sent to each node in turn.
+    If a request fails to connect to a node then that node won't be used again until a back-off period has passed.
+    The back-off starts at a minute and doubles for each consecutive failure.
+
+    Setting static nodes overrides the base url from the default request parameters.
+
+    # Examples
+
+    Send requests to a cluster of three nodes:
+
+    ```
+    # use elastic::prelude::*;
+    let builder = AsyncClientBuilder::new()
+        .static_nodes(vec![
+            "http://es_node_1:9200",
+            "http://es_node_2:9200",
+            "http://es_node_3:9200",
+        ]);
+    ```
+    */
+    pub fn static_nodes<I, S>(mut self, nodes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.nodes = NodeAddresses::Static(NodePool::new(nodes));
+
+        self
+    }
+
+    /**
+    Sniff the nodes in the cluster, starting from a seed node.
+
+    The seed node must be a fully qualified url.
+    Before the first request is sent the client will fetch the `http.publish_address` of each node in the cluster, and send requests to those nodes in turn.
+    The nodes will be sniffed again every `5` minutes.
+    Use [`sniff_nodes_fluent`][AsyncClientBuilder.sniff_nodes_fluent] to change how often nodes are sniffed.
+
+    Sniffing nodes overrides the base url from the default request parameters.
+
+    # Examples
+
+    Sniff the nodes in a cluster from a local node:
+
+    ```
+    # use elastic::prelude::*;
+    let builder = AsyncClientBuilder::new()
+        .sniff_nodes("http://localhost:9200");
+    ```
+
+    [AsyncClientBuilder.sniff_nodes_fluent]: #method.sniff_nodes_fluent
+    */
+    pub fn sniff_nodes<I>(self, seed: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.sniff_nodes_fluent(seed, |n| n)
+    }
+
+    /**
+    Sniff the nodes in the cluster, starting from a seed node, with a function to configure how nodes are sniffed.
+
+    # Examples
+
+    Sniff the nodes in a cluster every minute:
+
+    ```
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    let builder = AsyncClientBuilder::new()
+        .sniff_nodes_fluent("http://localhost:9200", |n| n.wait(Duration::from_secs(60)));
+    ```
+    */
+    pub fn sniff_nodes_fluent<I, F>(mut self, seed: I, builder: F) -> Self
+    where
+        I: Into<String>,
+        F: Fn(SniffedNodesBuilder) -> SniffedNodesBuilder,
+    {
+        self.nodes = NodeAddresses::Sniffed(builder(SniffedNodesBuilder::new(seed)).build());
+
+        self
+    }
+
     /**
     Specify default request parameters.
     
@@ -283,6 +527,67 @@ impl AsyncClientBuilder {
         self
     }
 
+    /**
+    Set a default timeout for requests.
+
+    By default, requests don't time out.
+    If a response isn't received before the timeout expires then the request
+    will fail with a client error where [`is_timeout`][ClientError.is_timeout] is `true`.
+    The timeout applies to each attempt when requests are retried, and can be overridden per request.
+
+    Timeouts are scheduled on the reactor, so an `AsyncClient` with a default timeout must be built with a `Handle`.
+
+    # Examples
+
+    Wait at most 10 seconds for a response:
+
+    ```
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    let builder = AsyncClientBuilder::new()
+        .timeout(Duration::from_secs(10));
+    ```
+
+    [ClientError.is_timeout]: ../error/struct.ClientError.html#method.is_timeout
+    */
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.params = self.params.timeout(timeout);
+
+        self
+    }
+
+    /**
+    Retry requests that fail with a transient error using the given policy.
+
+    By default, requests aren't retried.
+    Only requests with an idempotent method are retried unless the policy opts in to retrying any method.
+    See the [`RetryPolicy`][RetryPolicy] trait for more details.
+
+    The back-off between retries is scheduled on the reactor, so an `AsyncClient` with a retry policy must be built with a `Handle`.
+
+    # Examples
+
+    Retry requests using an exponential back-off:
+
+    ```
+    # use elastic::prelude::*;
+    use elastic::client::ExponentialBackoff;
+
+    let builder = AsyncClientBuilder::new()
+        .retry_policy(ExponentialBackoff::new().max_attempts(5));
+    ```
+
+    [RetryPolicy]: trait.RetryPolicy.html
+    */
+    pub fn retry_policy<P>(mut self, policy: P) -> Self
+    where
+        P: RetryPolicy + 'static,
+    {
+        self.retry = Some(Arc::new(policy));
+
+        self
+    }
+
     /** 
     Use the given `CpuPool` for serialising and deserialising responses.
 
@@ -358,18 +663,58 @@ impl AsyncClientBuilder {
     # }
     ```
 
+    Build with a given `AsyncHttpClient` and the `Handle` it's bound to.
+    Features that spawn background tasks, like the bulk processor, need a `Handle`:
+
+    ```no_run
+    # extern crate tokio_core;
+    # extern crate reqwest;
+    # extern crate elastic;
+    # use tokio_core::reactor::Core;
+    # use reqwest::unstable::async::Client;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    let mut core = Core::new()?;
+    let handle = core.handle();
+    let client = Client::new(&handle);
+
+    let builder = AsyncClientBuilder::new().build((client, &handle));
+    # Ok(())
+    # }
+    ```
+
     [AsyncClient]: type.AsyncClient.html
     */
     pub fn build<TIntoHttp>(self, client: TIntoHttp) -> Result<AsyncClient, Error>
     where
         TIntoHttp: IntoAsyncHttpClient,
     {
+        let handle = client.reactor_handle();
+
+        if self.retry.is_some() && handle.is_none() {
+            return Err(error::build(io::Error::new(
+                io::ErrorKind::Other,
+                "a retry policy requires an `AsyncClient` built with a reactor `Handle`",
+            )));
+        }
+
+        if self.params.get_timeout().is_some() && handle.is_none() {
+            return Err(error::build(io::Error::new(
+                io::ErrorKind::Other,
+                "a timeout requires an `AsyncClient` built with a reactor `Handle`",
+            )));
+        }
+
         let http = client.into_async_http_client().map_err(error::build)?;
 
         Ok(AsyncClient {
             sender: AsyncSender {
                 http: http,
                 serde_pool: self.serde_pool,
+                handle: handle,
+                nodes: self.nodes,
+                retry: self.retry,
             },
             params: self.params,
         })
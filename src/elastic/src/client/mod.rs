@@ -102,16 +102,58 @@ They're exposed as methods on the `Client`:
 Client method                                                 | Elasticsearch API                  | Raw request type                                        | Response type
 ------------------------------------------------------------- | ---------------------------------- | ------------------------------------------------------- | ------------------------------------
 [`search`][Client.search]                                     | [Search][docs-search]              | [`SearchRequest`][SearchRequest]                        | [`SearchResponse`][SearchResponse]
+[`msearch`][Client.msearch]                                   | [Multi Search][docs-msearch]       | [`MsearchRequest`][MsearchRequest]                      | [`MsearchResponse`][MsearchResponse]
+[`msearch_template`][Client.msearch_template]                 | [Multi Search Template][docs-msearch-template] | [`MsearchTemplateRequest`][MsearchTemplateRequest] | [`MsearchResponse`][MsearchResponse]
+[`count`][Client.count]                                       | [Count][docs-count]                | [`CountRequest`][CountRequest]                          | [`CountResponse`][CountResponse]
+[`validate_query`][Client.validate_query]                     | [Validate Query][docs-validate]    | [`IndicesValidateQueryRequest`][IndicesValidateQueryRequest] | [`ValidateQueryResponse`][ValidateQueryResponse]
+[`search_template`][Client.search_template]                   | [Search Template][docs-search-template] | [`SearchTemplateRequest`][SearchTemplateRequest]   | [`SearchResponse`][SearchResponse]
+[`search_template_render`][Client.search_template_render]     | [Render Search Template][docs-search-template] | [`RenderSearchTemplateRequest`][RenderSearchTemplateRequest] | [`RenderSearchTemplateResponse`][RenderSearchTemplateResponse]
+[`search_template_put`][Client.search_template_put]           | [Put Stored Search Template][docs-stored-templates] | [`PutTemplateRequest`][PutTemplateRequest] | [`CommandResponse`][CommandResponse]
+[`search_template_get`][Client.search_template_get]           | [Get Stored Search Template][docs-stored-templates] | [`GetTemplateRequest`][GetTemplateRequest] | [`GetSearchTemplateResponse`][GetSearchTemplateResponse]
+[`search_template_delete`][Client.search_template_delete]     | [Delete Stored Search Template][docs-stored-templates] | [`DeleteTemplateRequest`][DeleteTemplateRequest] | [`CommandResponse`][CommandResponse]
 [`document_get`][Client.document_get]                         | [Get Document][docs-get]           | [`GetRequest`][GetRequest]                              | [`GetResponse`][GetResponse]
+[`document_explain`][Client.document_explain]                 | [Explain][docs-explain]            | [`ExplainRequest`][ExplainRequest]                      | [`ExplainResponse`][ExplainResponse]
+[`document_mget`][Client.document_mget]                       | [Multi Get Document][docs-mget]    | [`MgetRequest`][MgetRequest]                            | [`MgetResponse`][MgetResponse]
+[`document_termvectors`][Client.document_termvectors]         | [Term Vectors][docs-termvectors]   | [`TermvectorsRequest`][TermvectorsRequest]              | [`TermvectorsResponse`][TermvectorsResponse]
+[`document_mtermvectors`][Client.document_mtermvectors]       | [Multi Term Vectors][docs-mtermvectors] | [`MtermvectorsRequest`][MtermvectorsRequest]       | [`MtermvectorsResponse`][MtermvectorsResponse]
 [`document_index`][Client.document_index]                     | [Index Document][docs-index]       | [`IndexRequest`][IndexRequest]                          | [`IndexResponse`][IndexResponse]
 [`document_update`][Client.document_update]                   | [Update Document][docs-update]     | [`UpdateRequest`][UpdateRequest]                        | [`UpdateResponse`][UpdateResponse]
 [`document_delete`][Client.document_delete]                   | [Delete Document][docs-delete]     | [`DeleteRequest`][DeleteRequest]                        | [`DeleteResponse`][DeleteResponse]
 [`document_put_mapping`][Client.document_put_mapping]         | [Put Mapping][docs-mapping]        | [`IndicesPutMappingRequest`][IndicesPutMappingRequest]  | [`CommandResponse`][CommandResponse]
+[`document_get_mapping`][Client.document_get_mapping]         | [Get Mapping][docs-get-mapping]    | [`IndicesGetMappingRequest`][IndicesGetMappingRequest]  | [`GetMappingResponse`][GetMappingResponse]
+[`delete_by_query`][Client.delete_by_query]                   | [Delete By Query][docs-delete-by-query] | [`DeleteByQueryRequest`][DeleteByQueryRequest]     | [`DeleteByQueryResponse`][DeleteByQueryResponse]
+[`update_by_query`][Client.update_by_query]                   | [Update By Query][docs-update-by-query] | [`UpdateByQueryRequest`][UpdateByQueryRequest]     | [`UpdateByQueryResponse`][UpdateByQueryResponse]
+[`reindex`][Client.reindex]                                   | [Reindex][docs-reindex]            | [`ReindexRequest`][ReindexRequest]                      | [`ReindexResponse`][ReindexResponse]
+[`tasks_list`][Client.tasks_list]                             | [List Tasks][docs-tasks]           | [`TasksListRequest`][TasksListRequest]                  | [`ListTasksResponse`][ListTasksResponse]
+[`bulk`][Client.bulk]                                         | [Bulk][docs-bulk]                  | [`BulkRequest`][BulkRequest]                            | [`BulkResponse`][BulkResponse]
 [`index_create`][Client.index_create]                         | [Create Index][docs-create-index]  | [`IndicesCreateRequest`][IndicesCreateRequest]          | [`CommandResponse`][CommandResponse]
 [`index_open`][Client.index_open]                             | [Open Index][docs-open-index]      | [`IndicesOpenRequest`][IndicesOpenRequest]              | [`CommandResponse`][CommandResponse]
 [`index_close`][Client.index_close]                           | [Close Index][docs-close-index]    | [`IndicesCloseRequest`][IndicesCloseRequest]            | [`CommandResponse`][CommandResponse]
 [`index_delete`][Client.index_delete]                         | [Delete Index][docs-delete-index]  | [`IndicesDeleteRequest`][IndicesDeleteRequest]          | [`CommandResponse`][CommandResponse]
 [`index_exists`][Client.index_exists]                         | [Index Exists][docs-index-exists]  | [`IndicesExistsRequest`][IndicesExistsRequest]          | [`IndicesExistsResponse`][IndicesExistsResponse]
+[`index_aliases`][Client.index_aliases]                       | [Update Aliases][docs-aliases]     | [`IndicesUpdateAliasesRequest`][IndicesUpdateAliasesRequest] | [`CommandResponse`][CommandResponse]
+[`alias_get`][Client.alias_get]                               | [Get Alias][docs-get-alias]        | [`IndicesGetAliasRequest`][IndicesGetAliasRequest]      | [`GetAliasesResponse`][GetAliasesResponse]
+[`alias_exists`][Client.alias_exists]                         | [Alias Exists][docs-get-alias]     | [`IndicesExistsAliasRequest`][IndicesExistsAliasRequest] | [`AliasExistsResponse`][AliasExistsResponse]
+[`index_template_put`][Client.index_template_put]             | [Put Index Template][docs-templates] | [`IndicesPutTemplateRequest`][IndicesPutTemplateRequest] | [`CommandResponse`][CommandResponse]
+[`index_template_get`][Client.index_template_get]             | [Get Index Template][docs-templates] | [`IndicesGetTemplateRequest`][IndicesGetTemplateRequest] | [`GetIndexTemplatesResponse`][GetIndexTemplatesResponse]
+[`index_template_delete`][Client.index_template_delete]       | [Delete Index Template][docs-templates] | [`IndicesDeleteTemplateRequest`][IndicesDeleteTemplateRequest] | [`CommandResponse`][CommandResponse]
+[`index_template_exists`][Client.index_template_exists]       | [Index Template Exists][docs-templates] | [`IndicesExistsTemplateRequest`][IndicesExistsTemplateRequest] | [`IndexTemplateExistsResponse`][IndexTemplateExistsResponse]
+[`index_settings_get`][Client.index_settings_get]             | [Get Settings][docs-get-settings]  | [`IndicesGetSettingsRequest`][IndicesGetSettingsRequest] | [`GetIndexSettingsResponse`][GetIndexSettingsResponse]
+[`index_settings_put`][Client.index_settings_put]             | [Update Settings][docs-update-settings] | [`IndicesPutSettingsRequest`][IndicesPutSettingsRequest] | [`CommandResponse`][CommandResponse]
+[`snapshot_repository_put`][Client.snapshot_repository_put]   | [Create Repository][docs-repositories] | [`SnapshotCreateRepositoryRequest`][SnapshotCreateRepositoryRequest] | [`CommandResponse`][CommandResponse]
+[`snapshot_repository_get`][Client.snapshot_repository_get]   | [Get Repository][docs-repositories] | [`SnapshotGetRepositoryRequest`][SnapshotGetRepositoryRequest] | [`GetRepositoriesResponse`][GetRepositoriesResponse]
+[`snapshot_repository_delete`][Client.snapshot_repository_delete] | [Delete Repository][docs-repositories] | [`SnapshotDeleteRepositoryRequest`][SnapshotDeleteRepositoryRequest] | [`CommandResponse`][CommandResponse]
+[`snapshot_create`][Client.snapshot_create]                   | [Create Snapshot][docs-snapshots]  | [`SnapshotCreateRequest`][SnapshotCreateRequest]        | [`CreateSnapshotResponse`][CreateSnapshotResponse]
+[`snapshot_get`][Client.snapshot_get]                         | [Get Snapshot][docs-snapshots]     | [`SnapshotGetRequest`][SnapshotGetRequest]              | [`GetSnapshotsResponse`][GetSnapshotsResponse]
+[`snapshot_delete`][Client.snapshot_delete]                   | [Delete Snapshot][docs-snapshots]  | [`SnapshotDeleteRequest`][SnapshotDeleteRequest]        | [`CommandResponse`][CommandResponse]
+[`snapshot_restore`][Client.snapshot_restore]                 | [Restore Snapshot][docs-restore]   | [`SnapshotRestoreRequest`][SnapshotRestoreRequest]      | [`RestoreSnapshotResponse`][RestoreSnapshotResponse]
+[`snapshot_status`][Client.snapshot_status]                   | [Snapshot Status][docs-snapshot-status] | [`SnapshotStatusRequest`][SnapshotStatusRequest]        | [`SnapshotStatusResponse`][SnapshotStatusResponse]
+[`ingest_pipeline_put`][Client.ingest_pipeline_put]           | [Put Pipeline][docs-put-pipeline] | [`IngestPutPipelineRequest`][IngestPutPipelineRequest]  | [`CommandResponse`][CommandResponse]
+[`ingest_pipeline_get`][Client.ingest_pipeline_get]           | [Get Pipeline][docs-get-pipeline] | [`IngestGetPipelineRequest`][IngestGetPipelineRequest]  | [`GetPipelinesResponse`][GetPipelinesResponse]
+[`ingest_pipeline_delete`][Client.ingest_pipeline_delete]     | [Delete Pipeline][docs-delete-pipeline] | [`IngestDeletePipelineRequest`][IngestDeletePipelineRequest] | [`CommandResponse`][CommandResponse]
+[`ingest_pipeline_simulate`][Client.ingest_pipeline_simulate] | [Simulate Pipeline][docs-simulate-pipeline] | [`IngestSimulateRequest`][IngestSimulateRequest] | [`SimulatePipelineResponse`][SimulatePipelineResponse]
+[`cluster_health`][Client.cluster_health]                     | [Cluster Health][docs-cluster-health] | [`ClusterHealthRequest`][ClusterHealthRequest]   | [`ClusterHealthResponse`][ClusterHealthResponse]
+[`analyze`][Client.analyze]                                   | [Analyze][docs-analyze]            | [`IndicesAnalyzeRequest`][IndicesAnalyzeRequest]        | [`AnalyzeResponse`][AnalyzeResponse]
 [`ping`][Client.ping]                                         | -                                  | [`PingRequest`][PingRequest]                            | [`PingResponse`][PingResponse]
 
 All builders follow a standard pattern:
@@ -438,16 +480,47 @@ future.and_then(|body| {
 For more details see the [`responses`][responses-mod] module.
 
 [docs-search]: http://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html
+[docs-msearch]: http://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html
+[docs-msearch-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/multi-search-template.html
+[docs-count]: http://www.elastic.co/guide/en/elasticsearch/reference/current/search-count.html
+[docs-validate]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html
+[docs-search-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html
+[docs-stored-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html#pre-registered-templates
+[docs-explain]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html
+[docs-termvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html
+[docs-mtermvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-termvectors.html
 [docs-get]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html
+[docs-mget]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-get.html
 [docs-update]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update.html
 [docs-delete]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete.html
 [docs-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-index_.html
 [docs-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping.html
+[docs-get-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html
+[docs-delete-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html
+[docs-update-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html
+[docs-reindex]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html
+[docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
 [docs-create-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-create-index.html
 [docs-close-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-open-close.html
 [docs-open-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-open-close.html
 [docs-index-exists]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-exists.html
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+[docs-get-alias]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html#alias-retrieving
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+[docs-get-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html
+[docs-update-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-update-settings.html
 [docs-delete-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-delete-index.html
+[docs-repositories]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_repositories
+[docs-snapshots]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot
+[docs-restore]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_restore
+[docs-snapshot-status]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot_status
+[docs-put-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/put-pipeline-api.html
+[docs-get-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/get-pipeline-api.html
+[docs-delete-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/delete-pipeline-api.html
+[docs-simulate-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/simulate-pipeline-api.html
+[docs-cluster-health]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html
+[docs-analyze]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-analyze.html
 
 [tokio]: https://tokio.rs
 
@@ -459,32 +532,116 @@ For more details see the [`responses`][responses-mod] module.
 [AsyncClientBuilder]: struct.AsyncClientBuilder.html
 [Client.request]: struct.Client.html#method.request
 [Client.search]: struct.Client.html#search-request
+[Client.msearch]: struct.Client.html#multi-search-request
+[Client.msearch_template]: struct.Client.html#multi-search-template-request
+[Client.count]: struct.Client.html#count-request
+[Client.validate_query]: struct.Client.html#validate-query-request
+[Client.search_template]: struct.Client.html#search-template-request
+[Client.search_template_render]: struct.Client.html#render-search-template-request
+[Client.search_template_put]: struct.Client.html#put-stored-search-template-request
+[Client.search_template_get]: struct.Client.html#get-stored-search-template-request
+[Client.search_template_delete]: struct.Client.html#delete-stored-search-template-request
+[Client.document_explain]: struct.Client.html#explain-request
+[Client.document_termvectors]: struct.Client.html#term-vectors-request
+[Client.document_mtermvectors]: struct.Client.html#multi-term-vectors-request
 [Client.document_get]: struct.Client.html#get-document-request
+[Client.document_mget]: struct.Client.html#multi-get-document-request
 [Client.document_update]: struct.Client.html#update-document-request
 [Client.document_delete]: struct.Client.html#delete-document-request
 [Client.document_index]: struct.Client.html#index-document-request
 [Client.document_put_mapping]: struct.Client.html#method.document_put_mapping
+[Client.document_get_mapping]: struct.Client.html#get-mapping-request
+[Client.delete_by_query]: struct.Client.html#delete-by-query-request
+[Client.update_by_query]: struct.Client.html#update-by-query-request
+[Client.reindex]: struct.Client.html#reindex-request
+[Client.tasks_list]: struct.Client.html#list-tasks-request
+[Client.bulk]: struct.Client.html#bulk-request
 [Client.index_create]: struct.Client.html#create-index-request
 [Client.index_open]: struct.Client.html#open-index-request
 [Client.index_close]: struct.Client.html#close-index-request
 [Client.index_delete]: struct.Client.html#delete-index-request
 [Client.index_exists]: struct.Client.html#index-exists-request
+[Client.index_aliases]: struct.Client.html#update-aliases-request
+[Client.alias_get]: struct.Client.html#get-alias-request
+[Client.alias_exists]: struct.Client.html#alias-exists-request
+[Client.index_template_put]: struct.Client.html#put-index-template-request
+[Client.index_template_get]: struct.Client.html#get-index-template-request
+[Client.index_template_delete]: struct.Client.html#delete-index-template-request
+[Client.index_template_exists]: struct.Client.html#index-template-exists-request
+[Client.index_settings_get]: struct.Client.html#get-index-settings-request
+[Client.index_settings_put]: struct.Client.html#update-index-settings-request
+[Client.snapshot_repository_put]: struct.Client.html#create-snapshot-repository-request
+[Client.snapshot_repository_get]: struct.Client.html#get-snapshot-repository-request
+[Client.snapshot_repository_delete]: struct.Client.html#delete-snapshot-repository-request
+[Client.snapshot_create]: struct.Client.html#create-snapshot-request
+[Client.snapshot_get]: struct.Client.html#get-snapshot-request
+[Client.snapshot_delete]: struct.Client.html#delete-snapshot-request
+[Client.snapshot_restore]: struct.Client.html#restore-snapshot-request
+[Client.snapshot_status]: struct.Client.html#snapshot-status-request
+[Client.ingest_pipeline_put]: struct.Client.html#put-pipeline-request
+[Client.ingest_pipeline_get]: struct.Client.html#get-pipeline-request
+[Client.ingest_pipeline_delete]: struct.Client.html#delete-pipeline-request
+[Client.ingest_pipeline_simulate]: struct.Client.html#simulate-pipeline-request
+[Client.cluster_health]: struct.Client.html#cluster-health-request
+[Client.analyze]: struct.Client.html#analyze-request
 [Client.ping]: struct.Client.html#ping-request
 
 [RequestBuilder]: requests/struct.RequestBuilder.html
 [RequestBuilder.params]: requests/struct.RequestBuilder.html#method.params
 [RawRequestBuilder]: requests/type.RawRequestBuilder.html
 [SearchRequest]: requests/endpoints/struct.SearchRequest.html
+[MsearchRequest]: requests/endpoints/struct.MsearchRequest.html
+[MsearchTemplateRequest]: requests/endpoints/struct.MsearchTemplateRequest.html
+[CountRequest]: requests/endpoints/struct.CountRequest.html
+[IndicesValidateQueryRequest]: requests/endpoints/struct.IndicesValidateQueryRequest.html
+[SearchTemplateRequest]: requests/endpoints/struct.SearchTemplateRequest.html
+[RenderSearchTemplateRequest]: requests/endpoints/struct.RenderSearchTemplateRequest.html
+[PutTemplateRequest]: requests/endpoints/struct.PutTemplateRequest.html
+[GetTemplateRequest]: requests/endpoints/struct.GetTemplateRequest.html
+[DeleteTemplateRequest]: requests/endpoints/struct.DeleteTemplateRequest.html
+[ExplainRequest]: requests/endpoints/struct.ExplainRequest.html
+[TermvectorsRequest]: requests/endpoints/struct.TermvectorsRequest.html
+[MtermvectorsRequest]: requests/endpoints/struct.MtermvectorsRequest.html
 [GetRequest]: requests/endpoints/struct.GetRequest.html
+[MgetRequest]: requests/endpoints/struct.MgetRequest.html
 [UpdateRequest]: requests/endpoints/struct.UpdateRequest.html
 [DeleteRequest]: requests/endpoints/struct.DeleteRequest.html
 [IndexRequest]: requests/endpoints/struct.IndexRequest.html
 [IndicesPutMappingRequest]: requests/endpoints/struct.IndicesPutMappingRequest.html
+[IndicesGetMappingRequest]: requests/endpoints/struct.IndicesGetMappingRequest.html
+[DeleteByQueryRequest]: requests/endpoints/struct.DeleteByQueryRequest.html
+[UpdateByQueryRequest]: requests/endpoints/struct.UpdateByQueryRequest.html
+[ReindexRequest]: requests/endpoints/struct.ReindexRequest.html
+[TasksListRequest]: requests/endpoints/struct.TasksListRequest.html
+[BulkRequest]: requests/endpoints/struct.BulkRequest.html
 [IndicesCreateRequest]: requests/endpoints/struct.IndicesCreateRequest.html
 [IndicesOpenRequest]: requests/endpoints/struct.IndicesOpenRequest.html
 [IndicesCloseRequest]: requests/endpoints/struct.IndicesCloseRequest.html
 [IndicesDeleteRequest]: requests/endpoints/struct.IndicesDeleteRequest.html
 [IndicesExistsRequest]: requests/endpoints/struct.IndicesExistsRequest.html
+[IndicesUpdateAliasesRequest]: requests/endpoints/struct.IndicesUpdateAliasesRequest.html
+[IndicesGetAliasRequest]: requests/endpoints/struct.IndicesGetAliasRequest.html
+[IndicesExistsAliasRequest]: requests/endpoints/struct.IndicesExistsAliasRequest.html
+[IndicesPutTemplateRequest]: requests/endpoints/struct.IndicesPutTemplateRequest.html
+[IndicesGetTemplateRequest]: requests/endpoints/struct.IndicesGetTemplateRequest.html
+[IndicesDeleteTemplateRequest]: requests/endpoints/struct.IndicesDeleteTemplateRequest.html
+[IndicesExistsTemplateRequest]: requests/endpoints/struct.IndicesExistsTemplateRequest.html
+[IndicesGetSettingsRequest]: requests/endpoints/struct.IndicesGetSettingsRequest.html
+[IndicesPutSettingsRequest]: requests/endpoints/struct.IndicesPutSettingsRequest.html
+[SnapshotCreateRepositoryRequest]: requests/endpoints/struct.SnapshotCreateRepositoryRequest.html
+[SnapshotGetRepositoryRequest]: requests/endpoints/struct.SnapshotGetRepositoryRequest.html
+[SnapshotDeleteRepositoryRequest]: requests/endpoints/struct.SnapshotDeleteRepositoryRequest.html
+[SnapshotCreateRequest]: requests/endpoints/struct.SnapshotCreateRequest.html
+[SnapshotGetRequest]: requests/endpoints/struct.SnapshotGetRequest.html
+[SnapshotDeleteRequest]: requests/endpoints/struct.SnapshotDeleteRequest.html
+[SnapshotRestoreRequest]: requests/endpoints/struct.SnapshotRestoreRequest.html
+[SnapshotStatusRequest]: requests/endpoints/struct.SnapshotStatusRequest.html
+[IngestPutPipelineRequest]: requests/endpoints/struct.IngestPutPipelineRequest.html
+[IngestGetPipelineRequest]: requests/endpoints/struct.IngestGetPipelineRequest.html
+[IngestDeletePipelineRequest]: requests/endpoints/struct.IngestDeletePipelineRequest.html
+[IngestSimulateRequest]: requests/endpoints/struct.IngestSimulateRequest.html
+[ClusterHealthRequest]: requests/endpoints/struct.ClusterHealthRequest.html
+[IndicesAnalyzeRequest]: requests/endpoints/struct.IndicesAnalyzeRequest.html
 [PingRequest]: requests/endpoints/struct.PingRequest.html
 
 [responses-mod]: responses/index.html
@@ -495,11 +652,40 @@ For more details see the [`responses`][responses-mod] module.
 [AsyncResponseBuilder.into_response]: responses/struct.AsyncResponseBuilder.html#method.into_response
 [AsyncResponseBuilder.into_raw]: responses/struct.AsyncResponseBuilder.html#method.into_raw
 [SearchResponse]: responses/type.SearchResponse.html
+[MsearchResponse]: responses/struct.MsearchResponse.html
+[CountResponse]: responses/struct.CountResponse.html
+[ValidateQueryResponse]: responses/struct.ValidateQueryResponse.html
+[RenderSearchTemplateResponse]: responses/struct.RenderSearchTemplateResponse.html
+[GetSearchTemplateResponse]: responses/struct.GetSearchTemplateResponse.html
+[ExplainResponse]: responses/struct.ExplainResponse.html
+[TermvectorsResponse]: responses/struct.TermvectorsResponse.html
+[MtermvectorsResponse]: responses/struct.MtermvectorsResponse.html
 [GetResponse]: responses/type.GetResponse.html
+[MgetResponse]: responses/struct.MgetResponse.html
 [UpdateResponse]: responses/type.UpdateResponse.html
 [DeleteResponse]: responses/type.DeleteResponse.html
 [IndexResponse]: responses/struct.IndexResponse.html
-[IndicesExistsResponse]: responses/struct.IndicesExistsResponse.html
+[GetMappingResponse]: responses/struct.GetMappingResponse.html
+[DeleteByQueryResponse]: responses/type.DeleteByQueryResponse.html
+[UpdateByQueryResponse]: responses/type.UpdateByQueryResponse.html
+[ReindexResponse]: responses/type.ReindexResponse.html
+[ListTasksResponse]: responses/tasks/struct.ListTasksResponse.html
+[BulkResponse]: responses/struct.BulkResponse.html
+[IndicesExistsResponse]: responses/type.IndicesExistsResponse.html
+[GetAliasesResponse]: responses/struct.GetAliasesResponse.html
+[AliasExistsResponse]: responses/type.AliasExistsResponse.html
+[GetIndexTemplatesResponse]: responses/struct.GetIndexTemplatesResponse.html
+[IndexTemplateExistsResponse]: responses/type.IndexTemplateExistsResponse.html
+[GetIndexSettingsResponse]: responses/struct.GetIndexSettingsResponse.html
+[GetRepositoriesResponse]: responses/struct.GetRepositoriesResponse.html
+[CreateSnapshotResponse]: responses/struct.CreateSnapshotResponse.html
+[GetSnapshotsResponse]: responses/struct.GetSnapshotsResponse.html
+[RestoreSnapshotResponse]: responses/struct.RestoreSnapshotResponse.html
+[SnapshotStatusResponse]: responses/struct.SnapshotStatusResponse.html
+[GetPipelinesResponse]: responses/struct.GetPipelinesResponse.html
+[SimulatePipelineResponse]: responses/struct.SimulatePipelineResponse.html
+[ClusterHealthResponse]: responses/struct.ClusterHealthResponse.html
+[AnalyzeResponse]: responses/struct.AnalyzeResponse.html
 [PingResponse]: responses/struct.PingResponse.html
 [CommandResponse]: responses/struct.CommandResponse.html
 [SyncHttpResponse]: responses/struct.SyncHttpResponse.html
@@ -514,6 +700,11 @@ use self::requests::HttpRequest;
 
 mod sync;
 mod async;
+mod nodes;
+pub use self::nodes::SniffedNodesBuilder;
+
+mod retry;
+pub use self::retry::{ExponentialBackoff, Failure, FailureKind, RetryPolicy};
 pub use self::sync::*;
 pub use self::async::*;
 
@@ -621,4 +812,26 @@ mod tests {
         assert_send::<SyncClient>();
         assert_sync::<SyncClient>();
     }
+
+    #[test]
+    fn sync_timeout_requires_default_http_client() {
+        use std::time::Duration;
+        use reqwest::Client as SyncHttpClient;
+
+        let builder = SyncClientBuilder::new().timeout(Duration::from_secs(5));
+
+        assert!(builder.http_client(SyncHttpClient::new()).build().is_err());
+    }
+
+    #[test]
+    fn sync_timeout_with_http_client_builder() {
+        use std::time::Duration;
+        use reqwest::ClientBuilder as SyncHttpClientBuilder;
+
+        let builder = SyncClientBuilder::new()
+            .http_client_builder(SyncHttpClientBuilder::new)
+            .timeout(Duration::from_secs(5));
+
+        assert!(builder.build().is_ok());
+    }
 }
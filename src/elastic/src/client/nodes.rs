@@ -0,0 +1,620 @@
+/*!
+Multiple node addresses for a client.
+
+Requests are sent to the nodes in a pool in round-robin order.
+Nodes that fail with a connection error are marked as dead and skipped until a back-off period has passed.
+The nodes in a pool can either be static, or sniffed from the cluster.
+*/
+
+use std::cmp;
+use std::sync::{Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use elastic_reqwest::Error as ElasticReqwestError;
+
+use error::Result;
+use client::RequestParams;
+use client::requests::endpoints::NodesInfoRequest;
+use client::responses::NodesInfoResponse;
+
+/** The base amount of time a node is considered dead for after a failure. */
+const DEAD_BACKOFF_BASE_SECS: u64 = 60;
+/** The maximum number of times the dead back-off is doubled. */
+const DEAD_BACKOFF_MAX_DOUBLINGS: u32 = 5;
+/** The default amount of time to wait between sniffing the nodes in a cluster. */
+const DEFAULT_SNIFF_WAIT_SECS: u64 = 5 * 60;
+
+/**
+The addresses of the Elasticsearch nodes that requests will be sent to.
+*/
+#[derive(Clone)]
+pub(crate) enum NodeAddresses {
+    /** Send requests to the `base_url` on the request parameters. */
+    Default,
+    /** Send requests to a fixed set of nodes. */
+    Static(NodePool),
+    /** Send requests to a set of nodes that's periodically sniffed from the cluster. */
+    Sniffed(SniffedNodes),
+}
+
+impl NodeAddresses {
+    /** Get the next node to send a request to. */
+    pub(crate) fn next(&self) -> Option<Node> {
+        match *self {
+            NodeAddresses::Default => None,
+            NodeAddresses::Static(ref pool) => pool.next(),
+            NodeAddresses::Sniffed(ref sniffed) => sniffed.next(),
+        }
+    }
+
+    /**
+    Get a guard for refreshing the sniffed nodes if they need to be refreshed.
+
+    Only one caller will be returned a guard until that guard is dropped.
+    */
+    pub(crate) fn should_refresh(&self) -> Option<RefreshGuard> {
+        match *self {
+            NodeAddresses::Sniffed(ref sniffed) if sniffed.should_refresh() => Some(RefreshGuard {
+                nodes: sniffed.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/**
+A builder for a set of nodes that's sniffed from the cluster.
+
+The nodes are first sniffed before the client sends its first request, and then again each time the `wait` period has passed.
+Nodes are sniffed using a [nodes info request][docs-nodes-info] to one of the currently known nodes, starting with the given seed address.
+The `http.publish_address` of each node is used as its address.
+
+[docs-nodes-info]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-nodes-info.html
+*/
+pub struct SniffedNodesBuilder {
+    seed: String,
+    wait: Duration,
+}
+
+impl SniffedNodesBuilder {
+    /**
+    Create a new builder for sniffed nodes.
+
+    The seed address must be a fully qualified url.
+    Its scheme is used for all sniffed nodes.
+    */
+    pub fn new<I>(seed: I) -> Self
+    where
+        I: Into<String>,
+    {
+        SniffedNodesBuilder {
+            seed: seed.into(),
+            wait: Duration::from_secs(DEFAULT_SNIFF_WAIT_SECS),
+        }
+    }
+
+    /**
+    Set the amount of time to wait between sniffing the nodes in the cluster.
+
+    The default wait is `5` minutes.
+    */
+    pub fn wait(mut self, wait: Duration) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    pub(crate) fn build(self) -> SniffedNodes {
+        let scheme = match self.seed.find("://") {
+            Some(index) => self.seed[..index].to_owned(),
+            None => "http".to_owned(),
+        };
+
+        SniffedNodes {
+            inner: Arc::new(SniffedNodesInner {
+                pool: RwLock::new(NodePool::new(vec![self.seed])),
+                refresh: Mutex::new(RefreshState {
+                    last_refreshed: None,
+                    refreshing: false,
+                }),
+                scheme: scheme,
+                wait: self.wait,
+            }),
+        }
+    }
+}
+
+/**
+A set of nodes that's periodically sniffed from the cluster.
+
+Clones of a `SniffedNodes` share the same set of nodes.
+*/
+#[derive(Clone)]
+pub(crate) struct SniffedNodes {
+    inner: Arc<SniffedNodesInner>,
+}
+
+struct SniffedNodesInner {
+    pool: RwLock<NodePool>,
+    refresh: Mutex<RefreshState>,
+    scheme: String,
+    wait: Duration,
+}
+
+struct RefreshState {
+    last_refreshed: Option<Instant>,
+    refreshing: bool,
+}
+
+impl SniffedNodes {
+    fn next(&self) -> Option<Node> {
+        self.inner.pool.read().ok().and_then(|pool| pool.next())
+    }
+
+    fn should_refresh(&self) -> bool {
+        let mut refresh = match self.inner.refresh.lock() {
+            Ok(refresh) => refresh,
+            Err(_) => return false,
+        };
+
+        let due = match refresh.last_refreshed {
+            Some(last_refreshed) => last_refreshed + self.inner.wait <= Instant::now(),
+            None => true,
+        };
+
+        if due && !refresh.refreshing {
+            refresh.refreshing = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn refresh(&self, res: Result<NodesInfoResponse>) {
+        if let Ok(res) = res {
+            let addresses: Vec<_> = res.nodes()
+                .filter_map(|(_, node)| node.http_publish_address())
+                .map(|address| self.address(address))
+                .collect();
+
+            if !addresses.is_empty() {
+                if let Ok(mut pool) = self.inner.pool.write() {
+                    *pool = pool.with_addresses(addresses);
+                }
+            }
+        }
+
+        if let Ok(mut refresh) = self.inner.refresh.lock() {
+            refresh.last_refreshed = Some(Instant::now());
+        }
+    }
+
+    /**
+    Convert a published address into a fully qualified url.
+
+    Published addresses can be in the form `ip:port` or `hostname/ip:port`.
+    If a hostname is given then it's used instead of the ip.
+    */
+    fn address(&self, publish_address: &str) -> String {
+        let (host, address) = match publish_address.find('/') {
+            Some(index) => (&publish_address[..index], &publish_address[index + 1..]),
+            None => ("", publish_address),
+        };
+
+        let address = match (host, address.rfind(':')) {
+            ("", _) | (_, None) => address.to_owned(),
+            (host, Some(index)) => format!("{}{}", host, &address[index..]),
+        };
+
+        format!("{}://{}", self.inner.scheme, address)
+    }
+}
+
+/**
+A guard for refreshing a set of sniffed nodes.
+
+No other caller will refresh the nodes while the guard is alive.
+If the guard is dropped without calling `refresh`, like when a request panics or its future is dropped, then the nodes will be sniffed again by the next request.
+*/
+pub(crate) struct RefreshGuard {
+    nodes: SniffedNodes,
+}
+
+impl RefreshGuard {
+    /** Get a request to sniff the nodes in the cluster. */
+    pub(crate) fn sniff_request(&self) -> NodesInfoRequest<'static> {
+        NodesInfoRequest::for_metric("http")
+    }
+
+    /**
+    Get the parameters for a request to sniff the nodes in the cluster.
+
+    Only the base url and headers of the given parameters are used, so url params for the request that triggered the sniff aren't sent.
+    */
+    pub(crate) fn sniff_params(&self, params: &RequestParams) -> RequestParams {
+        params.base_params()
+    }
+
+    /**
+    Refresh the set of nodes from a sniff response.
+
+    If sniffing failed, or no nodes with a http address were returned then the current nodes are kept.
+    Nodes that are still in the cluster keep their dead back-off state.
+    Nodes won't be sniffed again until the wait period has passed.
+    */
+    pub(crate) fn refresh(self, res: Result<NodesInfoResponse>) {
+        self.nodes.refresh(res);
+    }
+}
+
+impl Drop for RefreshGuard {
+    fn drop(&mut self) {
+        if let Ok(mut refresh) = self.nodes.inner.refresh.lock() {
+            refresh.refreshing = false;
+        }
+    }
+}
+
+/**
+A set of nodes that are selected in round-robin order.
+
+Clones of a `NodePool` share the same set of nodes and their state.
+*/
+#[derive(Clone)]
+pub(crate) struct NodePool {
+    inner: Arc<NodePoolInner>,
+}
+
+struct NodePoolInner {
+    nodes: Vec<NodeInner>,
+    next: AtomicUsize,
+}
+
+struct NodeInner {
+    address: String,
+    state: Mutex<NodeState>,
+}
+
+#[derive(Clone, Default)]
+struct NodeState {
+    failures: u32,
+    dead_until: Option<Instant>,
+}
+
+impl NodePool {
+    pub(crate) fn new<I, S>(addresses: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let nodes = addresses
+            .into_iter()
+            .map(|address| NodeInner {
+                address: address.into(),
+                state: Mutex::new(NodeState::default()),
+            })
+            .collect();
+
+        NodePool::from_nodes(nodes)
+    }
+
+    /**
+    Create a new pool with the given addresses.
+
+    Nodes that are also in this pool keep their state, so dead nodes aren't resurrected early.
+    */
+    fn with_addresses<I, S>(&self, addresses: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let nodes = addresses
+            .into_iter()
+            .map(|address| {
+                let address = address.into();
+
+                let state = self.inner
+                    .nodes
+                    .iter()
+                    .find(|node| node.address == address)
+                    .and_then(|node| node.state.lock().ok().map(|state| state.clone()))
+                    .unwrap_or_default();
+
+                NodeInner {
+                    address: address,
+                    state: Mutex::new(state),
+                }
+            })
+            .collect();
+
+        NodePool::from_nodes(nodes)
+    }
+
+    fn from_nodes(nodes: Vec<NodeInner>) -> Self {
+        NodePool {
+            inner: Arc::new(NodePoolInner {
+                nodes: nodes,
+                next: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /**
+    Get the next live node in the pool.
+
+    If every node is dead then the node that's due to be resurrected first is returned.
+    */
+    fn next(&self) -> Option<Node> {
+        let nodes = &self.inner.nodes;
+
+        if nodes.is_empty() {
+            return None;
+        }
+
+        let now = Instant::now();
+        let start = self.inner.next.fetch_add(1, Ordering::Relaxed);
+
+        let alive = (0..nodes.len())
+            .map(|offset| (start + offset) % nodes.len())
+            .find(|&index| nodes[index].is_alive(now));
+
+        let index = alive.unwrap_or_else(|| {
+            (0..nodes.len())
+                .min_by_key(|&index| nodes[index].dead_until())
+                .unwrap_or(0)
+        });
+
+        Some(Node {
+            pool: self.clone(),
+            index: index,
+        })
+    }
+}
+
+impl NodeInner {
+    fn is_alive(&self, now: Instant) -> bool {
+        match self.dead_until() {
+            Some(dead_until) => dead_until <= now,
+            None => true,
+        }
+    }
+
+    fn dead_until(&self) -> Option<Instant> {
+        self.state.lock().ok().and_then(|state| state.dead_until)
+    }
+}
+
+/**
+A node selected from a pool to send a request to.
+*/
+#[derive(Clone)]
+pub(crate) struct Node {
+    pool: NodePool,
+    index: usize,
+}
+
+impl Node {
+    fn inner(&self) -> &NodeInner {
+        &self.pool.inner.nodes[self.index]
+    }
+
+    /** The base address of the node. */
+    pub(crate) fn address(&self) -> &str {
+        &self.inner().address
+    }
+
+    /** Get request parameters that will send a request to this node. */
+    pub(crate) fn params(&self, params: &RequestParams) -> RequestParams {
+        params.clone().base_url(self.address())
+    }
+
+    /** Mark the node as alive after a successful request. */
+    pub(crate) fn mark_alive(&self) {
+        if let Ok(mut state) = self.inner().state.lock() {
+            *state = NodeState::default();
+        }
+    }
+
+    /**
+    Mark the node as dead after a failed request.
+
+    The node will be skipped for a period of time that doubles with each consecutive failure.
+    */
+    pub(crate) fn mark_dead(&self) {
+        if let Ok(mut state) = self.inner().state.lock() {
+            state.failures = state.failures.saturating_add(1);
+            state.dead_until = Some(Instant::now() + dead_backoff(state.failures));
+        }
+    }
+}
+
+/**
+Whether or not an error sending a request means the node couldn't be reached.
+
+Only errors connecting to the node, or reading and writing on its connection, count.
+Timeouts, API errors and responses that can't be deserialised don't mean the node is dead.
+*/
+pub(crate) fn is_unreachable(err: &ElasticReqwestError) -> bool {
+    match *err {
+        ElasticReqwestError::Http(ref err) => err.is_http(),
+        _ => false,
+    }
+}
+
+fn dead_backoff(failures: u32) -> Duration {
+    let doublings = cmp::min(failures.saturating_sub(1), DEAD_BACKOFF_MAX_DOUBLINGS);
+
+    Duration::from_secs(DEAD_BACKOFF_BASE_SECS * 2u64.pow(doublings))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    fn addresses(pool: &NodeAddresses, n: usize) -> Vec<String> {
+        (0..n).map(|_| pool.next().unwrap().address().to_owned()).collect()
+    }
+
+    #[test]
+    fn default_has_no_nodes() {
+        let nodes = NodeAddresses::Default;
+
+        assert!(nodes.next().is_none());
+    }
+
+    #[test]
+    fn static_nodes_are_round_robin() {
+        let nodes = NodeAddresses::Static(NodePool::new(vec!["http://a:9200", "http://b:9200"]));
+
+        assert_eq!(vec!["http://a:9200", "http://b:9200", "http://a:9200"], addresses(&nodes, 3));
+    }
+
+    #[test]
+    fn dead_nodes_are_skipped() {
+        let nodes = NodeAddresses::Static(NodePool::new(vec!["http://a:9200", "http://b:9200"]));
+
+        nodes.next().unwrap().mark_dead();
+
+        assert_eq!(vec!["http://b:9200", "http://b:9200"], addresses(&nodes, 2));
+    }
+
+    #[test]
+    fn alive_nodes_are_resurrected() {
+        let nodes = NodeAddresses::Static(NodePool::new(vec!["http://a:9200", "http://b:9200"]));
+
+        let node = nodes.next().unwrap();
+        node.mark_dead();
+        node.mark_alive();
+
+        assert_eq!(vec!["http://b:9200", "http://a:9200"], addresses(&nodes, 2));
+    }
+
+    #[test]
+    fn all_dead_nodes_returns_first_to_resurrect() {
+        let nodes = NodeAddresses::Static(NodePool::new(vec!["http://a:9200", "http://b:9200"]));
+
+        let a = nodes.next().unwrap();
+        let b = nodes.next().unwrap();
+        b.mark_dead();
+        a.mark_dead();
+        a.mark_dead();
+
+        assert_eq!(vec!["http://b:9200"], addresses(&nodes, 1));
+    }
+
+    #[test]
+    fn sniffed_nodes_start_with_seed() {
+        let nodes = NodeAddresses::Sniffed(SniffedNodesBuilder::new("http://a:9200").build());
+
+        assert_eq!(vec!["http://a:9200"], addresses(&nodes, 1));
+    }
+
+    #[test]
+    fn sniffed_nodes_should_refresh_once() {
+        let nodes = NodeAddresses::Sniffed(SniffedNodesBuilder::new("http://a:9200").build());
+
+        let refresh = nodes.should_refresh();
+
+        assert!(refresh.is_some());
+        assert!(nodes.should_refresh().is_none());
+    }
+
+    #[test]
+    fn sniffed_nodes_should_refresh_after_guard_is_dropped() {
+        let nodes = NodeAddresses::Sniffed(SniffedNodesBuilder::new("http://a:9200").build());
+
+        drop(nodes.should_refresh());
+
+        assert!(nodes.should_refresh().is_some());
+    }
+
+    #[test]
+    fn sniff_params_only_keep_base_url() {
+        let nodes = NodeAddresses::Sniffed(SniffedNodesBuilder::new("http://a:9200").build());
+
+        let params = RequestParams::new("http://a:9200")
+            .url_param("refresh", true)
+            .url_param("pipeline", "logs");
+
+        let params = nodes.should_refresh().unwrap().sniff_params(&params);
+
+        assert_eq!("http://a:9200", params.get_base_url());
+        assert_eq!((0, None), params.get_url_qry());
+    }
+
+    #[test]
+    fn sniffed_nodes_keep_dead_state() {
+        let nodes = NodeAddresses::Sniffed(SniffedNodesBuilder::new("http://a:9200").build());
+
+        let res = |addresses: Vec<&str>| {
+            let nodes: ::serde_json::Map<String, ::serde_json::Value> = addresses
+                .into_iter()
+                .enumerate()
+                .map(|(i, address)| (i.to_string(), json!({ "http": { "publish_address": address } })))
+                .collect();
+
+            serde_json::from_value(json!({ "nodes": nodes })).unwrap()
+        };
+
+        nodes.should_refresh().unwrap().refresh(Ok(res(vec!["10.0.0.1:9200", "10.0.0.2:9200"])));
+
+        let dead = nodes.next().unwrap();
+        assert_eq!("http://10.0.0.1:9200", dead.address());
+        dead.mark_dead();
+
+        match nodes {
+            NodeAddresses::Sniffed(ref sniffed) => sniffed.refresh(Ok(res(vec!["10.0.0.2:9200", "10.0.0.1:9200"]))),
+            _ => unreachable!(),
+        }
+
+        assert_eq!(vec!["http://10.0.0.2:9200", "http://10.0.0.2:9200"], addresses(&nodes, 2));
+    }
+
+    #[test]
+    fn sniffed_nodes_are_refreshed() {
+        let nodes = NodeAddresses::Sniffed(SniffedNodesBuilder::new("https://a:9200").build());
+
+        let res = serde_json::from_value(json!({
+            "nodes": {
+                "1": { "http": { "publish_address": "10.0.0.1:9200" } },
+                "2": { "http": { "publish_address": "es-node-2/10.0.0.2:9201" } },
+                "3": { }
+            }
+        })).unwrap();
+
+        nodes.should_refresh().unwrap().refresh(Ok(res));
+
+        assert_eq!(vec!["https://10.0.0.1:9200", "https://es-node-2:9201"], addresses(&nodes, 2));
+        assert!(nodes.should_refresh().is_none());
+    }
+
+    #[test]
+    fn sniffed_nodes_are_kept_on_error() {
+        let nodes = NodeAddresses::Sniffed(SniffedNodesBuilder::new("http://a:9200").build());
+
+        let res = serde_json::from_value(json!({ "nodes": { } })).unwrap();
+
+        nodes.should_refresh().unwrap().refresh(Ok(res));
+
+        assert_eq!(vec!["http://a:9200"], addresses(&nodes, 1));
+    }
+
+    #[test]
+    fn response_errors_are_not_unreachable() {
+        use std::io;
+        use elastic_reqwest::res::error::{ParseResponseError, ResponseError};
+
+        let err = ParseResponseError::from(io::Error::new(io::ErrorKind::Other, "bad body"));
+        let err = ElasticReqwestError::Response(ResponseError::from(err));
+
+        assert!(!is_unreachable(&err));
+    }
+
+    #[test]
+    fn dead_backoff_is_capped() {
+        assert_eq!(Duration::from_secs(60), dead_backoff(1));
+        assert_eq!(Duration::from_secs(120), dead_backoff(2));
+        assert_eq!(Duration::from_secs(60 * 32), dead_backoff(100));
+    }
+}
@@ -0,0 +1,254 @@
+/*!
+Builders for [alias exists requests][docs-aliases].
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html#alias-retrieving
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Name};
+use client::requests::endpoints::IndicesExistsAliasRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::AliasExistsResponse;
+
+/**
+An [alias exists request][docs-aliases] builder that can be configured before sending.
+
+Call [`Client.alias_exists`][Client.alias_exists] to get an `AliasExistsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html#alias-retrieving
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.alias_exists]: ../../struct.Client.html#alias-exists-request
+*/
+pub type AliasExistsRequestBuilder<TSender> = RequestBuilder<TSender, AliasExistsRequestInner>;
+
+#[doc(hidden)]
+pub struct AliasExistsRequestInner {
+    index: Option<Index<'static>>,
+    name: Name<'static>,
+}
+
+/**
+# Alias exists request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`AliasExistsRequestBuilder`][AliasExistsRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether an alias called `myalias` exists:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.alias_exists(name("myalias")).send()?;
+
+    assert!(response.exists());
+    # Ok(())
+    # }
+    ```
+
+    [AliasExistsRequestBuilder]: requests/alias_exists/type.AliasExistsRequestBuilder.html
+    [builder-methods]: requests/alias_exists/type.AliasExistsRequestBuilder.html#builder-methods
+    [send-sync]: requests/alias_exists/type.AliasExistsRequestBuilder.html#send-synchronously
+    [send-async]: requests/alias_exists/type.AliasExistsRequestBuilder.html#send-asynchronously
+    */
+    pub fn alias_exists(&self, name: Name<'static>) -> AliasExistsRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            AliasExistsRequestInner {
+                index: None,
+                name: name,
+            },
+        )
+    }
+}
+
+impl AliasExistsRequestInner {
+    fn into_request(self) -> IndicesExistsAliasRequest<'static> {
+        match self.index {
+            Some(index) => IndicesExistsAliasRequest::for_index_name(index, self.name),
+            None => IndicesExistsAliasRequest::for_name(self.name),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `AliasExistsRequestBuilder` before sending it.
+*/
+impl<TSender> AliasExistsRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Only check whether the alias points to the given indices.
+
+    If no index is specified then all indices will be checked.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl AliasExistsRequestBuilder<SyncSender> {
+    /**
+    Send an `AliasExistsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Check whether an alias called `myalias` points to an index called `myindex`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.alias_exists(name("myalias"))
+                         .index("myindex")
+                         .send()?;
+
+    assert!(response.exists());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<AliasExistsResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl AliasExistsRequestBuilder<AsyncSender> {
+    /**
+    Send an `AliasExistsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised alias exists response.
+
+    # Examples
+
+    Check whether an alias called `myalias` exists:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.alias_exists(name("myalias")).send();
+
+    future.and_then(|response| {
+        assert!(response.exists());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = AliasExistsResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = AliasExistsResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = AliasExistsResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.alias_exists(name("test-alias")).inner.into_request();
+
+        assert_eq!("/_alias/test-alias", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .alias_exists(name("test-alias"))
+            .index("test-idx")
+            .inner
+            .into_request();
+
+        assert_eq!("/test-idx/_alias/test-alias", req.url.as_ref());
+    }
+}
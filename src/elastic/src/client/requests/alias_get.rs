@@ -0,0 +1,280 @@
+/*!
+Builders for [get alias requests][docs-aliases].
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html#alias-retrieving
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Name};
+use client::requests::endpoints::IndicesGetAliasRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::GetAliasesResponse;
+
+/**
+A [get alias request][docs-aliases] builder that can be configured before sending.
+
+Call [`Client.alias_get`][Client.alias_get] to get an `AliasGetRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html#alias-retrieving
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.alias_get]: ../../struct.Client.html#get-alias-request
+*/
+pub type AliasGetRequestBuilder<TSender> = RequestBuilder<TSender, AliasGetRequestInner>;
+
+#[doc(hidden)]
+pub struct AliasGetRequestInner {
+    index: Option<Index<'static>>,
+    name: Option<Name<'static>>,
+}
+
+/**
+# Get alias request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`AliasGetRequestBuilder`][AliasGetRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Find the indices an alias called `myalias` points to:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.alias_get()
+                         .name("myalias")
+                         .send()?;
+
+    for index in response.indices_for("myalias") {
+        println!("myalias points to {}", index);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [AliasGetRequestBuilder]: requests/alias_get/type.AliasGetRequestBuilder.html
+    [builder-methods]: requests/alias_get/type.AliasGetRequestBuilder.html#builder-methods
+    [send-sync]: requests/alias_get/type.AliasGetRequestBuilder.html#send-synchronously
+    [send-async]: requests/alias_get/type.AliasGetRequestBuilder.html#send-asynchronously
+    */
+    pub fn alias_get(&self) -> AliasGetRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            AliasGetRequestInner {
+                index: None,
+                name: None,
+            },
+        )
+    }
+}
+
+impl AliasGetRequestInner {
+    fn into_request(self) -> IndicesGetAliasRequest<'static> {
+        match (self.index, self.name) {
+            (Some(index), Some(name)) => IndicesGetAliasRequest::for_index_name(index, name),
+            (Some(index), None) => IndicesGetAliasRequest::for_index(index),
+            (None, Some(name)) => IndicesGetAliasRequest::for_name(name),
+            (None, None) => IndicesGetAliasRequest::new(),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `AliasGetRequestBuilder` before sending it.
+*/
+impl<TSender> AliasGetRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices to get aliases for.
+
+    If no index is specified then aliases for all indices will be returned.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /**
+    Set the names of the aliases to get.
+
+    If no name is specified then all aliases will be returned.
+    */
+    pub fn name<I>(mut self, name: I) -> Self
+    where
+        I: Into<Name<'static>>,
+    {
+        self.inner.name = Some(name.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl AliasGetRequestBuilder<SyncSender> {
+    /**
+    Send an `AliasGetRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the aliases for an index called `myindex`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.alias_get()
+                         .index("myindex")
+                         .send()?;
+
+    for alias in response.aliases_for("myindex") {
+        println!("myindex has alias {}", alias);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<GetAliasesResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl AliasGetRequestBuilder<AsyncSender> {
+    /**
+    Send an `AliasGetRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get alias response.
+
+    # Examples
+
+    Get the aliases for an index called `myindex`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.alias_get()
+                       .index("myindex")
+                       .send();
+
+    future.and_then(|response| {
+        for alias in response.aliases_for("myindex") {
+            println!("myindex has alias {}", alias);
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = GetAliasesResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = GetAliasesResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = GetAliasesResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.alias_get().inner.into_request();
+
+        assert_eq!("/_alias", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index_name() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .alias_get()
+            .index("new-idx")
+            .name("new-alias")
+            .inner
+            .into_request();
+
+        assert_eq!("/new-idx/_alias/new-alias", req.url.as_ref());
+    }
+}
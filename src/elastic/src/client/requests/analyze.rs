@@ -0,0 +1,428 @@
+/*!
+Builders for [analyze requests][docs-analyze].
+
+[docs-analyze]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-analyze.html
+*/
+
+use serde_json;
+use futures::{Future, IntoFuture, Poll};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Index;
+use client::requests::endpoints::IndicesAnalyzeRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::AnalyzeResponse;
+
+/**
+An [analyze request][docs-analyze] builder that can be configured before sending.
+
+Call [`Client.analyze`][Client.analyze] to get an `AnalyzeRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-analyze]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-analyze.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.analyze]: ../../struct.Client.html#analyze-request
+*/
+pub type AnalyzeRequestBuilder<TSender> = RequestBuilder<TSender, AnalyzeRequestInner>;
+
+#[doc(hidden)]
+pub struct AnalyzeRequestInner {
+    index: Option<Index<'static>>,
+    body: AnalyzeBody,
+}
+
+#[derive(Serialize)]
+struct AnalyzeBody {
+    text: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] field: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] analyzer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] tokenizer: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")] filter: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")] char_filter: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] explain: Option<bool>,
+}
+
+/**
+# Analyze request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`AnalyzeRequestBuilder`][AnalyzeRequestBuilder] with this `Client` that can be configured before sending.
+
+    The text can be analysed by a named analyzer, an explicit chain of a tokenizer and filters, or the analyzer mapped for a field on an index.
+    If none of these are given then the default `standard` analyzer is used.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Analyze some text with the `standard` analyzer:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.analyze("The quick brown fox")
+                         .analyzer("standard")
+                         .send()?;
+
+    for token in response.iter() {
+        println!("{} at position {}", token.token(), token.position());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [AnalyzeRequestBuilder]: requests/analyze/type.AnalyzeRequestBuilder.html
+    [builder-methods]: requests/analyze/type.AnalyzeRequestBuilder.html#builder-methods
+    [send-sync]: requests/analyze/type.AnalyzeRequestBuilder.html#send-synchronously
+    [send-async]: requests/analyze/type.AnalyzeRequestBuilder.html#send-asynchronously
+    */
+    pub fn analyze<I>(&self, text: I) -> AnalyzeRequestBuilder<TSender>
+    where
+        I: Into<String>,
+    {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            AnalyzeRequestInner {
+                index: None,
+                body: AnalyzeBody {
+                    text: vec![text.into()],
+                    field: None,
+                    analyzer: None,
+                    tokenizer: None,
+                    filter: Vec::new(),
+                    char_filter: Vec::new(),
+                    explain: None,
+                },
+            },
+        )
+    }
+}
+
+impl AnalyzeRequestInner {
+    fn into_request(self) -> Result<IndicesAnalyzeRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&self.body).map_err(error::request)?;
+
+        Ok(match self.index {
+            Some(index) => IndicesAnalyzeRequest::for_index(index, body),
+            None => IndicesAnalyzeRequest::new(body),
+        })
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `AnalyzeRequestBuilder` before sending it.
+*/
+impl<TSender> AnalyzeRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Add another text to analyse.
+
+    The tokens for each text are returned in a single token stream.
+    */
+    pub fn text<I>(mut self, text: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.body.text.push(text.into());
+        self
+    }
+
+    /**
+    Set the index to analyse the text in.
+
+    An index is needed to use a [`field`](#method.field) or any analysis components defined in the settings for that index.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /**
+    Analyse the text with the analyzer mapped for a field.
+
+    The field is resolved using the mapping for the [`index`](#method.index), so an index must also be set.
+    */
+    pub fn field<I>(mut self, field: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.body.field = Some(field.into());
+        self
+    }
+
+    /**
+    Analyse the text with a named analyzer.
+
+    The analyzer can be built in, like `standard`, or defined in the settings for the [`index`](#method.index).
+    That means the same names returned by `TextMapping::analyzer` can be used here.
+    */
+    pub fn analyzer<I>(mut self, analyzer: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.body.analyzer = Some(analyzer.into());
+        self
+    }
+
+    /** Analyse the text with a named tokenizer instead of an analyzer. */
+    pub fn tokenizer<I>(mut self, tokenizer: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.body.tokenizer = Some(tokenizer.into());
+        self
+    }
+
+    /** Add a named token filter to the end of the chain that's applied after the tokenizer. */
+    pub fn filter<I>(mut self, filter: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.body.filter.push(filter.into());
+        self
+    }
+
+    /** Add a named character filter to the end of the chain that's applied before the tokenizer. */
+    pub fn char_filter<I>(mut self, char_filter: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.body.char_filter.push(char_filter.into());
+        self
+    }
+
+    /**
+    Return the tokens produced by each step in the analysis chain.
+
+    The detail is available from [`AnalyzeResponse.detail`][AnalyzeResponse.detail].
+
+    [AnalyzeResponse.detail]: ../../responses/struct.AnalyzeResponse.html#method.detail
+    */
+    pub fn explain(mut self) -> Self {
+        self.inner.body.explain = Some(true);
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl AnalyzeRequestBuilder<SyncSender> {
+    /**
+    Send an `AnalyzeRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Analyze some text with the analyzer used by a custom `TextMapping`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    #[derive(Default)]
+    struct MyTextMapping;
+    impl TextMapping for MyTextMapping {
+        fn analyzer() -> Option<&'static str> {
+            Some("english_text")
+        }
+    }
+
+    let response = client.analyze("The quick brown foxes")
+                         .index("myindex")
+                         .analyzer(MyTextMapping::analyzer().unwrap())
+                         .send()?;
+
+    for token in response.iter() {
+        println!("{}", token.token());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<AnalyzeResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl AnalyzeRequestBuilder<AsyncSender> {
+    /**
+    Send an `AnalyzeRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised analyze response.
+
+    # Examples
+
+    Explain how a chain of filters analyses some text:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.analyze("<p>Quick Foxes</p>")
+                       .char_filter("html_strip")
+                       .tokenizer("standard")
+                       .filter("lowercase")
+                       .explain()
+                       .send();
+
+    future.and_then(|response| {
+        if let Some(detail) = response.detail() {
+            for filter in detail.token_filters() {
+                println!("{}: {:?}", filter.name(), filter.tokens());
+            }
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = AnalyzeResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = AnalyzeResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = AnalyzeResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.analyze("The quick brown fox").inner.into_request().unwrap();
+
+        let expected_body = json!({
+            "text": ["The quick brown fox"]
+        });
+
+        assert_eq!("/_analyze", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_index_field() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .analyze("The quick brown fox")
+            .index("myindex")
+            .field("title")
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "text": ["The quick brown fox"],
+            "field": "title"
+        });
+
+        assert_eq!("/myindex/_analyze", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_chain() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .analyze("<p>Quick Foxes</p>")
+            .text("Lazy Dogs")
+            .char_filter("html_strip")
+            .tokenizer("standard")
+            .filter("lowercase")
+            .filter("asciifolding")
+            .explain()
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "text": ["<p>Quick Foxes</p>", "Lazy Dogs"],
+            "tokenizer": "standard",
+            "filter": ["lowercase", "asciifolding"],
+            "char_filter": ["html_strip"],
+            "explain": true
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
@@ -0,0 +1,749 @@
+/*!
+Builders for [bulk requests][docs-bulk].
+
+[docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
+*/
+
+use std::marker::PhantomData;
+use futures::{Future, IntoFuture, Poll};
+use serde::ser::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json;
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Id, Index, Type};
+use client::requests::endpoints::BulkRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::document_update::{DefaultParams, Doc, Script, ScriptBuilder};
+use client::responses::{BulkErrorsResponse, BulkResponse};
+use client::responses::parse::IsOk;
+use types::document::DocumentType;
+
+/**
+A [bulk request][docs-bulk] builder that can be configured before sending.
+
+Call [`Client.bulk`][Client.bulk] to get a `BulkRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.bulk]: ../../struct.Client.html#bulk-request
+*/
+pub type BulkRequestBuilder<TSender, TResponse> = RequestBuilder<TSender, BulkRequestInner<TResponse>>;
+
+#[doc(hidden)]
+pub struct BulkRequestInner<TResponse> {
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    body: Vec<u8>,
+    err: Option<Error>,
+    _marker: PhantomData<TResponse>,
+}
+
+/**
+# Bulk request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`BulkRequestBuilder`][BulkRequestBuilder] with this `Client` that can be configured before sending.
+
+    Operations are added to the request with the [`push`][BulkRequestBuilder.push] and [`extend`][BulkRequestBuilder.extend] methods.
+    Each operation is serialised to the newline-delimited body as soon as it's added.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Index a set of documents of type `MyType` into an index called `myindex`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub title: String,
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    # let docs: Vec<MyType> = vec![];
+    let ops = docs.into_iter()
+                  .map(|doc| {
+                      let id = doc.id;
+                      bulk_index(doc).id(id)
+                  });
+
+    let response = client.bulk()
+                         .index("myindex")
+                         .extend(ops)
+                         .send()?;
+
+    for op in response {
+        match op {
+            Ok(op) => println!("ok: {:?}", op),
+            Err(op) => println!("err: {:?}", op),
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    Different kinds of operations can be mixed in the same request:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub title: String,
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    # let new_doc = MyType { id: 1, title: String::new() };
+    let response = client.bulk()
+                         .index("myindex")
+                         .push(bulk_create(new_doc).id(1))
+                         .push(bulk_update::<MyType>(id(2)).doc(json!({ "title": "New Title" })))
+                         .push(bulk_delete::<MyType>(id(3)))
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [BulkRequestBuilder]: requests/bulk/type.BulkRequestBuilder.html
+    [BulkRequestBuilder.push]: requests/bulk/type.BulkRequestBuilder.html#method.push
+    [BulkRequestBuilder.extend]: requests/bulk/type.BulkRequestBuilder.html#method.extend
+    [builder-methods]: requests/bulk/type.BulkRequestBuilder.html#builder-methods
+    [send-sync]: requests/bulk/type.BulkRequestBuilder.html#send-synchronously
+    [send-async]: requests/bulk/type.BulkRequestBuilder.html#send-asynchronously
+    */
+    pub fn bulk(&self) -> BulkRequestBuilder<TSender, BulkResponse> {
+        RequestBuilder::new(self.clone(), None, BulkRequestInner::new())
+    }
+}
+
+impl<TResponse> BulkRequestInner<TResponse> {
+    fn new() -> Self {
+        BulkRequestInner {
+            index: None,
+            ty: None,
+            body: Vec::new(),
+            err: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn push<TValue>(&mut self, op: BulkOperation<TValue>)
+    where
+        TValue: Serialize,
+    {
+        if self.err.is_some() {
+            return;
+        }
+
+        if let Err(e) = op.write(&mut self.body) {
+            self.err = Some(error::request(e));
+        }
+    }
+
+    fn into_request(self) -> Result<BulkRequest<'static, Vec<u8>>> {
+        if let Some(err) = self.err {
+            return Err(err);
+        }
+
+        let req = match (self.index, self.ty) {
+            (Some(index), Some(ty)) => BulkRequest::for_index_ty(index, ty, self.body),
+            (Some(index), None) => BulkRequest::for_index(index, self.body),
+            (None, _) => BulkRequest::new(self.body),
+        };
+
+        Ok(req)
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `BulkRequestBuilder` before sending it.
+*/
+impl<TSender, TResponse> BulkRequestBuilder<TSender, TResponse>
+where
+    TSender: Sender,
+{
+    /**
+    Set the default index for the bulk request.
+
+    Operations that don't specify their own index will use this one.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /**
+    Set the default type for the bulk request.
+
+    The default type is only used if a default index is also given.
+    */
+    pub fn ty<I>(mut self, ty: Option<I>) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.map(Into::into);
+        self
+    }
+
+    /**
+    Add an operation to the bulk request.
+
+    Operations can be created using the [`bulk_index`][bulk_index], [`bulk_create`][bulk_create], [`bulk_update`][bulk_update] and [`bulk_delete`][bulk_delete] functions.
+    If an operation fails to serialise then the error will be returned when the request is sent.
+
+    [bulk_index]: fn.bulk_index.html
+    [bulk_create]: fn.bulk_create.html
+    [bulk_update]: fn.bulk_update.html
+    [bulk_delete]: fn.bulk_delete.html
+    */
+    pub fn push<TValue>(mut self, op: BulkOperation<TValue>) -> Self
+    where
+        TValue: Serialize,
+    {
+        self.inner.push(op);
+        self
+    }
+
+    /** Add a sequence of operations to the bulk request. */
+    pub fn extend<I, TValue>(mut self, ops: I) -> Self
+    where
+        I: IntoIterator<Item = BulkOperation<TValue>>,
+        TValue: Serialize,
+    {
+        for op in ops {
+            self.inner.push(op);
+        }
+
+        self
+    }
+
+    /**
+    Set the id of an ingest pipeline to run documents through before they're indexed.
+
+    The pipeline must already exist on the cluster.
+    */
+    pub fn pipeline<I>(self, pipeline: I) -> Self
+    where
+        I: Into<Id<'static>>,
+    {
+        let pipeline: Id<'static> = pipeline.into();
+
+        self.params(move |p| p.url_param("pipeline", &*pipeline))
+    }
+
+    /**
+    Only return the operations that failed in the response.
+
+    The [`BulkErrorsResponse`][BulkErrorsResponse] doesn't allocate for operations that succeeded, so it's cheaper to use when only failures are interesting.
+
+    [BulkErrorsResponse]: ../../responses/struct.BulkErrorsResponse.html
+    */
+    pub fn errors_only(self) -> BulkRequestBuilder<TSender, BulkErrorsResponse> {
+        RequestBuilder::new(
+            self.client,
+            self.params,
+            BulkRequestInner {
+                index: self.inner.index,
+                ty: self.inner.ty,
+                body: self.inner.body,
+                err: self.inner.err,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TResponse> BulkRequestBuilder<SyncSender, TResponse>
+where
+    TResponse: IsOk + DeserializeOwned,
+{
+    /**
+    Send a `BulkRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Index a document and print any failed operations:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub title: String,
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    # let doc = MyType { id: 1, title: String::new() };
+    let response = client.bulk()
+                         .index("myindex")
+                         .push(bulk_index(doc).id(1))
+                         .errors_only()
+                         .send()?;
+
+    for op in response {
+        println!("err: {:?}", op);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<TResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TResponse> BulkRequestBuilder<AsyncSender, TResponse>
+where
+    TResponse: IsOk + DeserializeOwned + Send + 'static,
+{
+    /**
+    Send a `BulkRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised bulk response.
+
+    # Examples
+
+    Index a document and print any failed operations:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub title: String,
+    # }
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    # let doc = MyType { id: 1, title: String::new() };
+    let future = client.bulk()
+                       .index("myindex")
+                       .push(bulk_index(doc).id(1))
+                       .errors_only()
+                       .send();
+
+    future.and_then(|response| {
+        for op in response {
+            println!("err: {:?}", op);
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TResponse> {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending<TResponse> {
+    inner: Box<Future<Item = TResponse, Error = Error>>,
+}
+
+impl<TResponse> Pending<TResponse> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = TResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<TResponse> Future for Pending<TResponse>
+where
+    TResponse: IsOk + DeserializeOwned + Send + 'static,
+{
+    type Item = TResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+/**
+A single operation in a bulk request.
+
+Create operations using the [`bulk_index`][bulk_index], [`bulk_create`][bulk_create], [`bulk_update`][bulk_update] and [`bulk_delete`][bulk_delete] functions.
+The type of each operation is inferred from its document type, and its index is inferred from the bulk request.
+
+[bulk_index]: fn.bulk_index.html
+[bulk_create]: fn.bulk_create.html
+[bulk_update]: fn.bulk_update.html
+[bulk_delete]: fn.bulk_delete.html
+*/
+pub struct BulkOperation<TValue> {
+    action: BulkAction,
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    id: Option<Id<'static>>,
+    inner: Option<TValue>,
+}
+
+#[derive(Clone, Copy)]
+enum BulkAction {
+    Index,
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum BulkHeader<'a> {
+    Index(BulkHeaderInner<'a>),
+    Create(BulkHeaderInner<'a>),
+    Update(BulkHeaderInner<'a>),
+    Delete(BulkHeaderInner<'a>),
+}
+
+#[derive(Serialize)]
+struct BulkHeaderInner<'a> {
+    #[serde(rename = "_index", skip_serializing_if = "Option::is_none")] index: Option<&'a str>,
+    #[serde(rename = "_type", skip_serializing_if = "Option::is_none")] ty: Option<&'a str>,
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")] id: Option<&'a str>,
+}
+
+/**
+Index a document.
+
+The type of the operation is inferred from the document.
+If the document already exists then it will be replaced.
+*/
+pub fn bulk_index<TDocument>(doc: TDocument) -> BulkOperation<TDocument>
+where
+    TDocument: DocumentType,
+{
+    BulkOperation::new(BulkAction::Index, TDocument::name(), Some(doc))
+}
+
+/**
+Create a document.
+
+The type of the operation is inferred from the document.
+If the document already exists then the operation will fail.
+*/
+pub fn bulk_create<TDocument>(doc: TDocument) -> BulkOperation<TDocument>
+where
+    TDocument: DocumentType,
+{
+    BulkOperation::new(BulkAction::Create, TDocument::name(), Some(doc))
+}
+
+/**
+Update a document.
+
+The type of the operation is inferred from the `TDocument` generic parameter.
+Set the new value of the document with the [`doc`][BulkOperation.doc] or [`script`][BulkOperation.script] methods.
+
+[BulkOperation.doc]: struct.BulkOperation.html#method.doc
+[BulkOperation.script]: struct.BulkOperation.html#method.script
+*/
+pub fn bulk_update<TDocument>(id: Id<'static>) -> BulkOperation<Doc<TDocument>>
+where
+    TDocument: DocumentType,
+{
+    BulkOperation::new(BulkAction::Update, TDocument::name(), Some(Doc::empty())).id(id)
+}
+
+/**
+Delete a document.
+
+The type of the operation is inferred from the `TDocument` generic parameter.
+*/
+pub fn bulk_delete<TDocument>(id: Id<'static>) -> BulkOperation<()>
+where
+    TDocument: DocumentType,
+{
+    BulkOperation::new(BulkAction::Delete, TDocument::name(), None).id(id)
+}
+
+impl<TValue> BulkOperation<TValue> {
+    fn new(action: BulkAction, ty: &'static str, inner: Option<TValue>) -> Self {
+        BulkOperation {
+            action: action,
+            index: None,
+            ty: Some(ty.into()),
+            id: None,
+            inner: inner,
+        }
+    }
+
+    /**
+    Set the index for this operation.
+
+    If no index is specified then the default index of the bulk request will be used.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.index = Some(index.into());
+        self
+    }
+
+    /** Set the type for this operation. */
+    pub fn ty<I>(mut self, ty: I) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.ty = Some(ty.into());
+        self
+    }
+
+    /**
+    Set the id for this operation.
+
+    If no id is specified for an index or create operation then Elasticsearch will generate one.
+    */
+    pub fn id<I>(mut self, id: I) -> Self
+    where
+        I: Into<Id<'static>>,
+    {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+impl<TValue> BulkOperation<TValue>
+where
+    TValue: Serialize,
+{
+    pub(crate) fn write(&self, buf: &mut Vec<u8>) -> serde_json::Result<()> {
+        let inner = BulkHeaderInner {
+            index: self.index.as_ref().map(|index| &**index),
+            ty: self.ty.as_ref().map(|ty| &**ty),
+            id: self.id.as_ref().map(|id| &**id),
+        };
+
+        let header = match self.action {
+            BulkAction::Index => BulkHeader::Index(inner),
+            BulkAction::Create => BulkHeader::Create(inner),
+            BulkAction::Update => BulkHeader::Update(inner),
+            BulkAction::Delete => BulkHeader::Delete(inner),
+        };
+
+        serde_json::to_writer(&mut *buf, &header)?;
+        buf.push(b'\n');
+
+        if let Some(ref inner) = self.inner {
+            serde_json::to_writer(&mut *buf, inner)?;
+            buf.push(b'\n');
+        }
+
+        Ok(())
+    }
+}
+
+/**
+# Update operations
+
+Set the new value of a document for an update operation.
+*/
+impl<TDocument> BulkOperation<Doc<TDocument>> {
+    /**
+    Update the source using a document.
+
+    The document doesn't necessarily need to be of the same type so partial updates can be made.
+    */
+    pub fn doc<TNewDocument>(self, doc: TNewDocument) -> BulkOperation<Doc<TNewDocument>>
+    where
+        TNewDocument: Serialize,
+    {
+        BulkOperation {
+            action: self.action,
+            index: self.index,
+            ty: self.ty,
+            id: self.id,
+            inner: Some(Doc::value(doc)),
+        }
+    }
+
+    /** Update the source using a script. */
+    pub fn script<TScript, TParams>(self, builder: TScript) -> BulkOperation<Script<TParams>>
+    where
+        TScript: Into<ScriptBuilder<TParams>>,
+    {
+        BulkOperation {
+            action: self.action,
+            index: self.index,
+            ty: self.ty,
+            id: self.id,
+            inner: Some(builder.into().build()),
+        }
+    }
+
+    /** Update the source using a script configured by a fluent closure API. */
+    pub fn script_fluent<TScript, TBuilder, TParams>(self, source: TScript, builder: TBuilder) -> BulkOperation<Script<TParams>>
+    where
+        TScript: ToString,
+        TBuilder: Fn(ScriptBuilder<DefaultParams>) -> ScriptBuilder<TParams>,
+    {
+        let builder = builder(ScriptBuilder::new(source));
+
+        self.script(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+    use serde_json::Value;
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.bulk().inner.into_request().unwrap();
+
+        assert_eq!("/_bulk", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .bulk()
+            .index("new-idx")
+            .ty(Some("new-ty"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/new-idx/new-ty/_bulk", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_pipeline() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.bulk().pipeline("logs");
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+
+        assert_eq!("?pipeline=logs", query.unwrap());
+    }
+
+    #[test]
+    fn specify_ops() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .bulk()
+            .index("new-idx")
+            .push(bulk_index(json!({ "a": 1 })).id(1))
+            .push(bulk_create(json!({ "a": 2 })).index("other-idx"))
+            .push(bulk_update::<Value>(id(3)).doc(json!({ "a": 3 })))
+            .push(bulk_update::<Value>(id(4)).script("ctx._source.a = 4"))
+            .push(bulk_delete::<Value>(id(5)).ty("new-ty"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = vec![
+            json!({ "index": { "_type": "value", "_id": "1" } }),
+            json!({ "a": 1 }),
+            json!({ "create": { "_index": "other-idx", "_type": "value" } }),
+            json!({ "a": 2 }),
+            json!({ "update": { "_type": "value", "_id": "3" } }),
+            json!({ "doc": { "a": 3 } }),
+            json!({ "update": { "_type": "value", "_id": "4" } }),
+            json!({ "script": { "inline": "ctx._source.a = 4" } }),
+            json!({ "delete": { "_type": "new-ty", "_id": "5" } }),
+        ];
+
+        let body = str::from_utf8(&req.body).unwrap();
+        let actual_body: Vec<Value> = body.lines()
+            .map(|line| ::serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert!(body.ends_with('\n'));
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn specify_ops_iter() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let ops = (0..3).map(|i| bulk_index(json!({ "a": i })));
+
+        let req = client.bulk().extend(ops).inner.into_request().unwrap();
+
+        assert_eq!(6, str::from_utf8(&req.body).unwrap().lines().count());
+    }
+}
@@ -0,0 +1,868 @@
+/*!
+A processor that batches [bulk operations][docs-bulk] and sends them in the background.
+
+[docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
+*/
+
+use std::cmp;
+use std::io;
+use std::mem;
+use std::sync::Arc;
+use std::time::Duration;
+use futures::{Async, AsyncSink, Future, Poll, Sink, StartSend, Stream};
+use futures::stream::FuturesUnordered;
+use futures::sync::mpsc;
+use tokio_core::reactor::{Handle, Interval, Timeout};
+use serde::ser::Serialize;
+use serde_json;
+
+use error::{self, Error, Result};
+use client::{AsyncClient, AsyncSender, Client};
+use client::requests::{HttpMethod, RequestBuilder};
+use client::requests::bulk::BulkOperation;
+use client::requests::params::{Id, Index, Type};
+use client::requests::endpoints::BulkRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::BulkResponse;
+use client::responses::bulk::{ErrorItem, OkItem};
+use client::retry::{ExponentialBackoff, Failure, FailureKind, RetryPolicy};
+
+const DEFAULT_MAX_DOCUMENTS: usize = 1000;
+const DEFAULT_MAX_BYTES: usize = 5 * 1024 * 1024;
+const DEFAULT_MAX_IN_FLIGHT: usize = 1;
+const DEFAULT_MAX_ATTEMPTS: u32 = 4;
+const REJECTED_STATUS: u16 = 429;
+
+/**
+A builder for a [`BulkProcessor`][BulkProcessor].
+
+Call [`Client.bulk_processor`][Client.bulk_processor] to get a `BulkProcessorBuilder`.
+
+[BulkProcessor]: struct.BulkProcessor.html
+[Client.bulk_processor]: ../../struct.Client.html#bulk-processor
+*/
+pub struct BulkProcessorBuilder {
+    client: AsyncClient,
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    max_documents: usize,
+    max_bytes: usize,
+    pipeline: Option<Id<'static>>,
+    interval: Option<Duration>,
+    max_in_flight: usize,
+    retry: Arc<RetryPolicy>,
+}
+
+/**
+# Bulk processor
+*/
+impl Client<AsyncSender> {
+    /**
+    Create a [`BulkProcessorBuilder`][BulkProcessorBuilder] with this `Client` that can be configured before starting.
+
+    A bulk processor buffers operations and sends them as bulk requests when the number of buffered documents, their size in bytes or the time since the last request reaches a threshold.
+    The processor runs as a background task on the `Handle` the `AsyncClient` was built with.
+
+    # Examples
+
+    Index a stream of documents of type `MyType` and print any failures:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use std::time::Duration;
+    # use futures::{stream, Future, Sink, Stream};
+    # use elastic::prelude::*;
+    # use elastic::Error;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub title: String,
+    # }
+    # let mut core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    # let docs: Vec<MyType> = vec![];
+    let (processor, outcomes) = client.bulk_processor()
+                                      .index("myindex")
+                                      .max_documents(500)
+                                      .interval(Duration::from_secs(5))
+                                      .build()?;
+
+    let ops = stream::iter_ok::<_, Error>(docs.into_iter().map(|doc| {
+        let id = doc.id;
+        bulk_index(doc).id(id).into()
+    }));
+
+    // Send all operations to the processor, then drop it so the remaining operations are flushed
+    core.handle().spawn(processor.send_all(ops).map(|_| ()).map_err(|_| ()));
+
+    // Wait for the outcome of each operation
+    let outcomes = outcomes.for_each(|outcome| {
+        if let Err(item) = outcome {
+            println!("err: {:?}", item);
+        }
+
+        Ok(())
+    });
+
+    core.run(outcomes)?;
+    # Ok(())
+    # }
+    ```
+
+    [BulkProcessorBuilder]: requests/bulk_processor/struct.BulkProcessorBuilder.html
+    */
+    pub fn bulk_processor(&self) -> BulkProcessorBuilder {
+        let retry = ExponentialBackoff::new()
+            .max_attempts(DEFAULT_MAX_ATTEMPTS)
+            .retry_status(vec![REJECTED_STATUS]);
+
+        BulkProcessorBuilder {
+            client: self.clone(),
+            index: None,
+            ty: None,
+            max_documents: DEFAULT_MAX_DOCUMENTS,
+            max_bytes: DEFAULT_MAX_BYTES,
+            pipeline: None,
+            interval: None,
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+            retry: Arc::new(retry),
+        }
+    }
+}
+
+impl BulkProcessorBuilder {
+    /**
+    Set the default index for bulk requests.
+
+    Operations that don't specify their own index will use this one.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.index = Some(index.into());
+        self
+    }
+
+    /**
+    Set the default type for bulk requests.
+
+    The default type is only used if a default index is also given.
+    */
+    pub fn ty<I>(mut self, ty: Option<I>) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.ty = ty.map(Into::into);
+        self
+    }
+
+    /**
+    Set the id of an ingest pipeline to run documents through before they're indexed.
+
+    The pipeline must already exist on the cluster.
+    */
+    pub fn pipeline<I>(mut self, pipeline: I) -> Self
+    where
+        I: Into<Id<'static>>,
+    {
+        self.pipeline = Some(pipeline.into());
+        self
+    }
+
+    /**
+    Send a bulk request when this many operations are buffered.
+
+    The default is `1000` operations.
+    */
+    pub fn max_documents(mut self, max_documents: usize) -> Self {
+        self.max_documents = max_documents;
+        self
+    }
+
+    /**
+    Send a bulk request when the buffered operations are at least this many bytes.
+
+    The default is `5MiB`.
+    */
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /**
+    Send a bulk request for any buffered operations at this interval, even if no other threshold has been reached.
+
+    By default, buffered operations are only sent when a threshold is reached or the processor is dropped.
+    */
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /**
+    The maximum number of bulk requests that can be in flight at the same time.
+
+    Once this limit is reached, the processor will stop accepting new operations until a request completes.
+    The default is `1` request.
+    */
+    pub fn max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = max_in_flight;
+        self
+    }
+
+    /**
+    Retry operations that are rejected by Elasticsearch using the given policy.
+
+    Operations that fail with a `429` status are retried if the policy returns a back-off for a [`FailureKind::Status(429)`][FailureKind] failure.
+    Once the back-off has passed, the operations are added to the buffer and sent in the next bulk request.
+    If the policy doesn't return a back-off then the operation's failure is returned as an outcome.
+
+    Rejected operations weren't applied, so they're retried even though bulk requests aren't idempotent.
+    The default policy is an [`ExponentialBackoff`][ExponentialBackoff] that makes at most `4` attempts for each operation.
+
+    # Examples
+
+    Retry rejected operations up to `10` times, waiting at most a minute between attempts:
+
+    ```no_run
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    use elastic::client::ExponentialBackoff;
+
+    let (processor, outcomes) = client.bulk_processor()
+                                      .retry_policy(ExponentialBackoff::new()
+                                          .max_attempts(10)
+                                          .max_backoff(Duration::from_secs(60))
+                                          .retry_status(vec![429]))
+                                      .build()?;
+    # Ok(())
+    # }
+    ```
+
+    [FailureKind]: ../../enum.FailureKind.html
+    [ExponentialBackoff]: ../../struct.ExponentialBackoff.html
+    */
+    pub fn retry_policy<P>(mut self, policy: P) -> Self
+    where
+        P: RetryPolicy + 'static,
+    {
+        self.retry = Arc::new(policy);
+        self
+    }
+
+    /**
+    Start the bulk processor.
+
+    This method returns a [`BulkProcessor`][BulkProcessor] that operations can be sent to, and a [`BulkOutcomes`][BulkOutcomes] stream that produces the outcome of each operation.
+    Buffered operations are sent when all clones of the `BulkProcessor` are dropped, after which the `BulkOutcomes` stream will end.
+
+    This method will fail if the `AsyncClient` wasn't built with a reactor `Handle`.
+
+    [BulkProcessor]: struct.BulkProcessor.html
+    [BulkOutcomes]: struct.BulkOutcomes.html
+    */
+    pub fn build(self) -> Result<(BulkProcessor, BulkOutcomes)> {
+        let handle = self.client.sender.handle.clone().ok_or_else(|| {
+            error::build(io::Error::new(
+                io::ErrorKind::Other,
+                "the bulk processor requires an `AsyncClient` built with a reactor `Handle`",
+            ))
+        })?;
+
+        let interval = match self.interval {
+            Some(interval) => Some(Interval::new(interval, &handle).map_err(error::build)?),
+            None => None,
+        };
+
+        let max_documents = self.max_documents.max(1);
+
+        let (ops_tx, ops_rx) = mpsc::channel(max_documents);
+        let (outcomes_tx, outcomes_rx) = mpsc::unbounded();
+
+        let task = BulkProcessorTask {
+            client: self.client,
+            handle: handle.clone(),
+            index: self.index,
+            ty: self.ty,
+            pipeline: self.pipeline,
+            max_documents: max_documents,
+            max_bytes: self.max_bytes,
+            max_in_flight: self.max_in_flight.max(1),
+            retry: self.retry,
+            ops: Some(ops_rx),
+            interval: interval,
+            flush: false,
+            batch: Vec::new(),
+            batch_bytes: 0,
+            in_flight: FuturesUnordered::new(),
+            retrying: FuturesUnordered::new(),
+            outcomes: outcomes_tx,
+        };
+
+        handle.spawn(task);
+
+        let processor = BulkProcessor {
+            tx: ops_tx,
+            buffered: None,
+        };
+
+        let outcomes = BulkOutcomes { rx: outcomes_rx };
+
+        Ok((processor, outcomes))
+    }
+}
+
+/**
+A sink for bulk operations.
+
+The processor accepts an [`AnyBulkOperation`][AnyBulkOperation], which any `BulkOperation` can be converted into.
+That means operations for different document types, like index and delete operations, can be sent to the same processor.
+Operations sent to the processor are serialised straight away and buffered until they're sent in a bulk request.
+Clones of a `BulkProcessor` all send operations to the same background task.
+
+[AnyBulkOperation]: struct.AnyBulkOperation.html
+*/
+pub struct BulkProcessor {
+    tx: mpsc::Sender<PendingOp>,
+    buffered: Option<PendingOp>,
+}
+
+impl Clone for BulkProcessor {
+    fn clone(&self) -> Self {
+        BulkProcessor {
+            tx: self.tx.clone(),
+            buffered: None,
+        }
+    }
+}
+
+impl BulkProcessor {
+    fn poll_buffered(&mut self) -> Poll<(), Error> {
+        if let Some(op) = self.buffered.take() {
+            match self.tx.start_send(op) {
+                Ok(AsyncSink::Ready) => (),
+                Ok(AsyncSink::NotReady(op)) => {
+                    self.buffered = Some(op);
+                    return Ok(Async::NotReady);
+                }
+                Err(_) => return Err(stopped()),
+            }
+        }
+
+        Ok(Async::Ready(()))
+    }
+}
+
+impl Sink for BulkProcessor {
+    type SinkItem = AnyBulkOperation;
+    type SinkError = Error;
+
+    fn start_send(&mut self, op: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        if let Async::NotReady = self.poll_buffered()? {
+            return Ok(AsyncSink::NotReady(op));
+        }
+
+        let mut body = Vec::new();
+        op.inner.write_op(&mut body).map_err(error::request)?;
+
+        self.buffered = Some(PendingOp {
+            body: body,
+            attempts: 0,
+        });
+        self.poll_buffered()?;
+
+        Ok(AsyncSink::Ready)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        if let Async::NotReady = self.poll_buffered()? {
+            return Ok(Async::NotReady);
+        }
+
+        self.tx.poll_complete().map_err(|_| stopped())
+    }
+}
+
+/**
+A bulk operation for any type of document.
+
+Any [`BulkOperation`][BulkOperation] can be converted into an `AnyBulkOperation` so it can be sent to a [`BulkProcessor`][BulkProcessor].
+
+# Examples
+
+Send an index and a delete operation to the same processor:
+
+```no_run
+# extern crate futures;
+# extern crate tokio_core;
+# extern crate serde_json;
+# extern crate elastic;
+# use futures::{stream, Future, Sink};
+# use serde_json::Value;
+# use elastic::prelude::*;
+# use elastic::Error;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<::std::error::Error>> {
+# let core = tokio_core::reactor::Core::new()?;
+# let client = AsyncClientBuilder::new().build(&core.handle())?;
+# let doc = Value::Null;
+use elastic::client::requests::AnyBulkOperation;
+
+let (processor, outcomes) = client.bulk_processor()
+                                  .index("myindex")
+                                  .build()?;
+
+let ops: Vec<AnyBulkOperation> = vec![
+    bulk_index(doc).id(1).into(),
+    bulk_delete::<Value>(id(2)).into(),
+];
+
+core.handle().spawn(processor.send_all(stream::iter_ok::<_, Error>(ops)).map(|_| ()).map_err(|_| ()));
+# Ok(())
+# }
+```
+
+[BulkOperation]: struct.BulkOperation.html
+[BulkProcessor]: struct.BulkProcessor.html
+*/
+pub struct AnyBulkOperation {
+    inner: Box<WriteOp + Send>,
+}
+
+trait WriteOp {
+    fn write_op(&self, buf: &mut Vec<u8>) -> serde_json::Result<()>;
+}
+
+impl<TValue> WriteOp for BulkOperation<TValue>
+where
+    TValue: Serialize,
+{
+    fn write_op(&self, buf: &mut Vec<u8>) -> serde_json::Result<()> {
+        self.write(buf)
+    }
+}
+
+impl<TValue> From<BulkOperation<TValue>> for AnyBulkOperation
+where
+    TValue: Serialize + Send + 'static,
+{
+    fn from(op: BulkOperation<TValue>) -> Self {
+        AnyBulkOperation { inner: Box::new(op) }
+    }
+}
+
+/**
+A stream of outcomes for operations sent to a [`BulkProcessor`][BulkProcessor].
+
+Each successful or failed operation produces an item in the stream.
+If a bulk request fails entirely then the error is returned by the stream and the operations in that request won't produce items.
+The stream ends once all clones of the `BulkProcessor` have been dropped and the remaining operations have been sent.
+
+[BulkProcessor]: struct.BulkProcessor.html
+*/
+pub struct BulkOutcomes {
+    rx: mpsc::UnboundedReceiver<Result<BulkOutcome>>,
+}
+
+/** The outcome of a single bulk operation. */
+pub type BulkOutcome = ::std::result::Result<OkItem, ErrorItem>;
+
+impl Stream for BulkOutcomes {
+    type Item = BulkOutcome;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        match self.rx.poll() {
+            Ok(Async::Ready(Some(Ok(outcome)))) => Ok(Async::Ready(Some(outcome))),
+            Ok(Async::Ready(Some(Err(e)))) => Err(e),
+            Ok(Async::Ready(None)) | Err(()) => Ok(Async::Ready(None)),
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+        }
+    }
+}
+
+struct PendingOp {
+    body: Vec<u8>,
+    attempts: u32,
+}
+
+type PendingBatch = Box<Future<Item = (Vec<PendingOp>, Result<BulkResponse>), Error = ()>>;
+type PendingRetry = Box<Future<Item = Vec<PendingOp>, Error = ()>>;
+
+struct BulkProcessorTask {
+    client: AsyncClient,
+    handle: Handle,
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    pipeline: Option<Id<'static>>,
+    max_documents: usize,
+    max_bytes: usize,
+    max_in_flight: usize,
+    retry: Arc<RetryPolicy>,
+    ops: Option<mpsc::Receiver<PendingOp>>,
+    interval: Option<Interval>,
+    flush: bool,
+    batch: Vec<PendingOp>,
+    batch_bytes: usize,
+    in_flight: FuturesUnordered<PendingBatch>,
+    retrying: FuturesUnordered<PendingRetry>,
+    outcomes: mpsc::UnboundedSender<Result<BulkOutcome>>,
+}
+
+impl BulkProcessorTask {
+    fn batch_is_full(&self) -> bool {
+        self.batch.len() >= self.max_documents || self.batch_bytes >= self.max_bytes
+    }
+
+    fn buffer(&mut self, op: PendingOp) {
+        self.batch_bytes += op.body.len();
+        self.batch.push(op);
+    }
+
+    fn request(&self, body: Vec<u8>) -> RequestBuilder<AsyncSender, RawRequestInner<BulkRequest<'static, Vec<u8>>, Vec<u8>>> {
+        let req = match (self.index.clone(), self.ty.clone()) {
+            (Some(index), Some(ty)) => BulkRequest::for_index_ty(index, ty, body),
+            (Some(index), None) => BulkRequest::for_index(index, body),
+            (None, _) => BulkRequest::new(body),
+        };
+
+        let req = self.client.request(req);
+
+        match self.pipeline.clone() {
+            Some(pipeline) => req.params(move |p| p.url_param("pipeline", &*pipeline)),
+            None => req,
+        }
+    }
+
+    fn send_batch(&mut self) {
+        let ops = mem::replace(&mut self.batch, Vec::new());
+
+        let mut body = Vec::with_capacity(self.batch_bytes);
+        for op in &ops {
+            body.extend_from_slice(&op.body);
+        }
+
+        self.batch_bytes = 0;
+
+        let batch = self.request(body)
+            .send()
+            .and_then(|res| res.into_response::<BulkResponse>())
+            .then(move |res| Ok((ops, res)));
+
+        self.in_flight.push(Box::new(batch));
+    }
+
+    /**
+    Return the outcome of each operation in a completed bulk request.
+
+    Rejected operations are held back until their retry back-off has passed.
+    Operations rejected together are retried together after the longest of their back-offs.
+    */
+    fn complete_batch(&mut self, ops: Vec<PendingOp>, res: Result<BulkResponse>) {
+        let res = match res {
+            Ok(res) => res,
+            Err(e) => {
+                let _ = self.outcomes.unbounded_send(Err(e));
+                return;
+            }
+        };
+
+        let method = HttpMethod::Post;
+        let mut retries = Vec::new();
+        let mut backoff = Duration::from_millis(0);
+
+        for (mut op, item) in ops.into_iter().zip(res) {
+            if let Err(ref item) = item {
+                if item.status() == REJECTED_STATUS {
+                    op.attempts += 1;
+
+                    let failure = Failure::new(op.attempts, &method, FailureKind::Status(REJECTED_STATUS));
+
+                    if let Some(op_backoff) = self.retry.retry(&failure) {
+                        backoff = cmp::max(backoff, op_backoff);
+                        retries.push(op);
+
+                        continue;
+                    }
+                }
+            }
+
+            let _ = self.outcomes.unbounded_send(Ok(item));
+        }
+
+        if !retries.is_empty() {
+            self.retry_later(retries, backoff);
+        }
+    }
+
+    fn retry_later(&mut self, ops: Vec<PendingOp>, backoff: Duration) {
+        match Timeout::new(backoff, &self.handle) {
+            Ok(delay) => self.retrying.push(Box::new(delay.then(move |_| Ok(ops)))),
+            Err(_) => for op in ops {
+                self.buffer(op);
+            },
+        }
+    }
+}
+
+impl Future for BulkProcessorTask {
+    type Item = ();
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        loop {
+            let mut progress = false;
+
+            // Collect the results of any completed requests
+            while let Ok(Async::Ready(Some((ops, res)))) = self.in_flight.poll() {
+                self.complete_batch(ops, res);
+                progress = true;
+            }
+
+            // Buffer rejected operations once their back-off has passed and send them straight away
+            while let Ok(Async::Ready(Some(ops))) = self.retrying.poll() {
+                for op in ops {
+                    self.buffer(op);
+                }
+
+                self.flush = true;
+                progress = true;
+            }
+
+            // Flush buffered operations when the interval ticks
+            let tick = match self.interval {
+                Some(ref mut interval) => match interval.poll() {
+                    Ok(Async::Ready(Some(()))) => true,
+                    _ => false,
+                },
+                None => false,
+            };
+
+            if tick {
+                self.flush = true;
+                progress = true;
+            }
+
+            // Buffer new operations until the batch is full
+            while !self.batch_is_full() {
+                let op = match self.ops {
+                    Some(ref mut ops) => ops.poll(),
+                    None => break,
+                };
+
+                match op {
+                    Ok(Async::Ready(Some(op))) => {
+                        self.buffer(op);
+                        progress = true;
+                    }
+                    Ok(Async::NotReady) => break,
+                    Ok(Async::Ready(None)) | Err(()) => {
+                        self.ops = None;
+                        progress = true;
+                    }
+                }
+            }
+
+            // Send the batch if a threshold has been reached and there's capacity
+            let should_send = self.flush || self.ops.is_none() || self.batch_is_full();
+            if should_send && !self.batch.is_empty() && self.in_flight.len() < self.max_in_flight {
+                self.send_batch();
+                progress = true;
+            }
+
+            if self.batch.is_empty() {
+                self.flush = false;
+            }
+
+            if self.ops.is_none() && self.batch.is_empty() && self.in_flight.is_empty() && self.retrying.is_empty() {
+                return Ok(Async::Ready(()));
+            }
+
+            if !progress {
+                return Ok(Async::NotReady);
+            }
+        }
+    }
+}
+
+fn stopped() -> Error {
+    error::request(io::Error::new(
+        io::ErrorKind::BrokenPipe,
+        "the bulk processor has stopped",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+    use serde_json::{self, Value};
+    use futures::future;
+    use tokio_core::reactor::Core;
+    use prelude::*;
+    use super::*;
+    use tests::*;
+
+    fn task(core: &Core, retry: ExponentialBackoff) -> (BulkProcessorTask, mpsc::UnboundedReceiver<Result<BulkOutcome>>) {
+        let client = AsyncClientBuilder::new().build(&core.handle()).unwrap();
+        let (_, ops) = mpsc::channel(1);
+        let (outcomes_tx, outcomes_rx) = mpsc::unbounded();
+
+        let retry = retry
+            .retry_status(vec![REJECTED_STATUS])
+            .initial_backoff(Duration::from_millis(0));
+
+        let task = BulkProcessorTask {
+            client: client,
+            handle: core.handle(),
+            index: None,
+            ty: None,
+            pipeline: None,
+            max_documents: 2,
+            max_bytes: DEFAULT_MAX_BYTES,
+            max_in_flight: 1,
+            retry: Arc::new(retry),
+            ops: Some(ops),
+            interval: None,
+            flush: false,
+            batch: Vec::new(),
+            batch_bytes: 0,
+            in_flight: FuturesUnordered::new(),
+            retrying: FuturesUnordered::new(),
+            outcomes: outcomes_tx,
+        };
+
+        (task, outcomes_rx)
+    }
+
+    fn ops() -> Vec<PendingOp> {
+        vec![
+            PendingOp { body: b"1".to_vec(), attempts: 0 },
+            PendingOp { body: b"2".to_vec(), attempts: 0 },
+        ]
+    }
+
+    fn rejected_response() -> BulkResponse {
+        serde_json::from_value(json!({
+            "took": 1,
+            "errors": true,
+            "items": [
+                { "index": { "_index": "idx", "_type": "ty", "_id": "1", "status": 201, "_version": 1 } },
+                { "index": { "_index": "idx", "_type": "ty", "_id": "2", "status": 429, "error": { "type": "es_rejected_execution_exception", "reason": "rejected" } } }
+            ]
+        })).unwrap()
+    }
+
+    #[test]
+    fn processor_is_send() {
+        assert_send::<BulkProcessor>();
+        assert_send::<AnyBulkOperation>();
+    }
+
+    #[test]
+    fn any_op_writes_op() {
+        let ops: Vec<AnyBulkOperation> = vec![
+            bulk_index(json!({ "a": 1 })).id(1).into(),
+            bulk_delete::<Value>(id(2)).into(),
+        ];
+
+        let mut body = Vec::new();
+        for op in ops {
+            op.inner.write_op(&mut body).unwrap();
+        }
+
+        let expected_body = vec![
+            json!({ "index": { "_type": "value", "_id": "1" } }),
+            json!({ "a": 1 }),
+            json!({ "delete": { "_type": "value", "_id": "2" } }),
+        ];
+
+        let actual_body: Vec<Value> = str::from_utf8(&body)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn specify_pipeline() {
+        let core = Core::new().unwrap();
+        let (mut task, _) = task(&core, ExponentialBackoff::new());
+        task.pipeline = Some("logs".into());
+
+        let req = task.request(Vec::new());
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+        assert_eq!("?pipeline=logs", query.unwrap());
+    }
+
+    #[test]
+    fn batch_is_full_on_documents() {
+        let core = Core::new().unwrap();
+        let (mut task, _) = task(&core, ExponentialBackoff::new());
+
+        for op in ops() {
+            task.buffer(op);
+        }
+
+        assert!(task.batch_is_full());
+    }
+
+    #[test]
+    fn rejected_ops_are_retried_after_backoff() {
+        let mut core = Core::new().unwrap();
+        let (mut task, outcomes) = task(&core, ExponentialBackoff::new().max_attempts(2));
+
+        task.complete_batch(ops(), Ok(rejected_response()));
+
+        assert_eq!(0, task.batch.len());
+        assert_eq!(1, task.retrying.len());
+
+        let retried = core.run(future::poll_fn(|| task.retrying.poll())).unwrap().unwrap();
+
+        assert_eq!(1, retried.len());
+        assert_eq!(b"2".to_vec(), retried[0].body);
+        assert_eq!(1, retried[0].attempts);
+
+        drop(task);
+        let outcomes: Vec<_> = outcomes.wait().map(|outcome| outcome.unwrap().unwrap()).collect();
+
+        assert_eq!(1, outcomes.len());
+        assert!(outcomes[0].is_ok());
+    }
+
+    #[test]
+    fn rejected_ops_are_returned_after_max_attempts() {
+        let core = Core::new().unwrap();
+        let (mut task, outcomes) = task(&core, ExponentialBackoff::new().max_attempts(2));
+
+        let mut ops = ops();
+        ops[1].attempts = 1;
+
+        task.complete_batch(ops, Ok(rejected_response()));
+
+        assert_eq!(0, task.batch.len());
+        assert_eq!(0, task.retrying.len());
+
+        drop(task);
+        let outcomes: Vec<_> = outcomes.wait().map(|outcome| outcome.unwrap().unwrap()).collect();
+
+        assert_eq!(2, outcomes.len());
+        assert!(outcomes[1].is_err());
+    }
+}
@@ -0,0 +1,336 @@
+/*!
+Builders for [cluster health requests][docs-cluster-health].
+
+[docs-cluster-health]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html
+*/
+
+use std::time::Duration;
+use futures::{Future, IntoFuture, Poll};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Index;
+use client::requests::endpoints::ClusterHealthRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::{ClusterHealthResponse, ClusterHealthStatus};
+
+/**
+A [cluster health request][docs-cluster-health] builder that can be configured before sending.
+
+Call [`Client.cluster_health`][Client.cluster_health] to get a `ClusterHealthRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-cluster-health]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.cluster_health]: ../../struct.Client.html#cluster-health-request
+*/
+pub type ClusterHealthRequestBuilder<TSender> = RequestBuilder<TSender, ClusterHealthRequestInner>;
+
+#[doc(hidden)]
+pub struct ClusterHealthRequestInner {
+    index: Option<Index<'static>>,
+}
+
+/**
+# Cluster health request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ClusterHealthRequestBuilder`][ClusterHealthRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the health of the cluster:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster_health().send()?;
+
+    println!("{} is {}", response.cluster_name(), response.status());
+    # Ok(())
+    # }
+    ```
+
+    [ClusterHealthRequestBuilder]: requests/cluster_health/type.ClusterHealthRequestBuilder.html
+    [builder-methods]: requests/cluster_health/type.ClusterHealthRequestBuilder.html#builder-methods
+    [send-sync]: requests/cluster_health/type.ClusterHealthRequestBuilder.html#send-synchronously
+    [send-async]: requests/cluster_health/type.ClusterHealthRequestBuilder.html#send-asynchronously
+    */
+    pub fn cluster_health(&self) -> ClusterHealthRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, ClusterHealthRequestInner { index: None })
+    }
+}
+
+impl ClusterHealthRequestInner {
+    fn into_request(self) -> ClusterHealthRequest<'static> {
+        match self.index {
+            Some(index) => ClusterHealthRequest::for_index(index),
+            None => ClusterHealthRequest::new(),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ClusterHealthRequestBuilder` before sending it.
+*/
+impl<TSender> ClusterHealthRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices to get the health of.
+
+    If no index is specified then the health of the whole cluster will be returned.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /**
+    Wait until the cluster reaches at least the given status.
+
+    This sets the `wait_for_status` parameter.
+    If the status isn't reached before the [`wait_timeout`](#method.wait_timeout) then sending the request will return an error.
+    */
+    pub fn wait_for_status(self, status: ClusterHealthStatus) -> Self {
+        self.params(move |p| p.url_param("wait_for_status", status))
+    }
+
+    /**
+    Wait until the given number of nodes are available.
+
+    The number can also be an expression like `>=3` or `le(5)`.
+    This sets the `wait_for_nodes` parameter.
+    */
+    pub fn wait_for_nodes<T>(self, nodes: T) -> Self
+    where
+        T: ToString,
+    {
+        let nodes = nodes.to_string();
+
+        self.params(move |p| p.url_param("wait_for_nodes", nodes.clone()))
+    }
+
+    /**
+    Wait until the given number of shards are active.
+
+    The number can also be `all` to wait for every shard.
+    This sets the `wait_for_active_shards` parameter.
+    */
+    pub fn wait_for_active_shards<T>(self, shards: T) -> Self
+    where
+        T: ToString,
+    {
+        let shards = shards.to_string();
+
+        self.params(move |p| p.url_param("wait_for_active_shards", shards.clone()))
+    }
+
+    /**
+    Set how long Elasticsearch should wait for the requested conditions.
+
+    This sets the `timeout` parameter, which defaults to `30s`.
+    It's different from the [`timeout`](#method.timeout) for the whole request, which should be longer than the time spent waiting.
+    */
+    pub fn wait_timeout(self, timeout: Duration) -> Self {
+        let millis = timeout.as_secs() * 1_000 + timeout.subsec_nanos() as u64 / 1_000_000;
+
+        self.params(move |p| p.url_param("timeout", format!("{}ms", millis)))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl ClusterHealthRequestBuilder<SyncSender> {
+    /**
+    Send a `ClusterHealthRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+    If the request was waiting for a condition that wasn't met in time then the returned error will be a [wait timeout][ClientError.is_wait_timeout].
+
+    # Examples
+
+    Wait for the cluster to be at least `yellow`:
+
+    ```no_run
+    # extern crate elastic;
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster_health()
+                         .wait_for_status(ClusterHealthStatus::Yellow)
+                         .wait_timeout(Duration::from_secs(10))
+                         .send()?;
+
+    assert!(response.status() >= ClusterHealthStatus::Yellow);
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    [ClientError.is_wait_timeout]: ../../../error/struct.ClientError.html#method.is_wait_timeout
+    */
+    pub fn send(self) -> Result<ClusterHealthResponse> {
+        let req = self.inner.into_request();
+
+        let res: ClusterHealthResponse = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()?;
+
+        check_timed_out(res)
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl ClusterHealthRequestBuilder<AsyncSender> {
+    /**
+    Send a `ClusterHealthRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised cluster health response.
+    If the request was waiting for a condition that wasn't met in time then the future will resolve to a [wait timeout][ClientError.is_wait_timeout] error.
+
+    # Examples
+
+    Wait for the cluster to be at least `yellow`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use std::time::Duration;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.cluster_health()
+                       .wait_for_status(ClusterHealthStatus::Yellow)
+                       .wait_timeout(Duration::from_secs(10))
+                       .send();
+
+    future.and_then(|response| {
+        println!("{} is {}", response.cluster_name(), response.status());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [ClientError.is_wait_timeout]: ../../../error/struct.ClientError.html#method.is_wait_timeout
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response())
+            .and_then(|res| check_timed_out(res).into_future());
+
+        Pending::new(res_future)
+    }
+}
+
+fn check_timed_out(res: ClusterHealthResponse) -> Result<ClusterHealthResponse> {
+    if res.timed_out() {
+        Err(error::wait_timeout())
+    } else {
+        Ok(res)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = ClusterHealthResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = ClusterHealthResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = ClusterHealthResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cluster_health().inner.into_request();
+
+        assert_eq!("/_cluster/health", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cluster_health().index("new-idx").inner.into_request();
+
+        assert_eq!("/_cluster/health/new-idx", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_wait_params() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .cluster_health()
+            .wait_for_status(ClusterHealthStatus::Yellow)
+            .wait_for_nodes(">=3")
+            .wait_for_active_shards("all")
+            .wait_timeout(Duration::from_secs(5));
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+
+        assert_eq!(
+            "?timeout=5000ms&wait_for_active_shards=all&wait_for_nodes=%3E%3D3&wait_for_status=yellow",
+            query.unwrap()
+        );
+    }
+}
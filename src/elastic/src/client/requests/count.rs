@@ -0,0 +1,318 @@
+/*!
+Builders for [count requests][docs-count].
+
+[docs-count]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-count.html
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::{empty_body, DefaultBody, RequestBuilder};
+use client::requests::params::{Index, Type};
+use client::requests::endpoints::CountRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CountResponse;
+
+/**
+A [count request][docs-count] builder that can be configured before sending.
+
+Call [`Client.count`][Client.count] to get a `CountRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-count]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-count.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.count]: ../../struct.Client.html#count-request
+*/
+pub type CountRequestBuilder<TSender, TBody> = RequestBuilder<TSender, CountRequestInner<TBody>>;
+
+#[doc(hidden)]
+pub struct CountRequestInner<TBody> {
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    body: TBody,
+}
+
+/**
+# Count request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`CountRequestBuilder`][CountRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Count the documents in an index that match a simple [Query String][docs-querystring] query:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let query = "a query string";
+
+    let response = client.count()
+                         .index("myindex")
+                         .body(json!({
+                             "query": {
+                                 "query_string": {
+                                     "query": query
+                                 }
+                             }
+                         }))
+                         .send()?;
+
+    println!("matched {} documents", response.count());
+    # Ok(())
+    # }
+    ```
+
+    [CountRequestBuilder]: requests/count/type.CountRequestBuilder.html
+    [builder-methods]: requests/count/type.CountRequestBuilder.html#builder-methods
+    [send-sync]: requests/count/type.CountRequestBuilder.html#send-synchronously
+    [send-async]: requests/count/type.CountRequestBuilder.html#send-asynchronously
+    [docs-querystring]: https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-query-string-query.html
+    */
+    pub fn count(&self) -> CountRequestBuilder<TSender, DefaultBody> {
+        RequestBuilder::new(self.clone(), None, CountRequestInner::new(empty_body()))
+    }
+}
+
+impl<TBody> CountRequestInner<TBody> {
+    fn new(body: TBody) -> Self {
+        CountRequestInner {
+            index: None,
+            ty: None,
+            body: body,
+        }
+    }
+
+    fn into_request(self) -> CountRequest<'static, TBody> {
+        let index = self.index.unwrap_or_else(|| "_all".into());
+
+        match self.ty {
+            Some(ty) => CountRequest::for_index_ty(index, ty, self.body),
+            None => CountRequest::for_index(index, self.body),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `CountRequestBuilder` before sending it.
+*/
+impl<TSender, TBody> CountRequestBuilder<TSender, TBody>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices for the count request.
+
+    If no index is specified then `_all` will be used.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /** Set the types for the count request. */
+    pub fn ty<I>(mut self, ty: Option<I>) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.map(Into::into);
+        self
+    }
+
+    /**
+    Set the query body for the count request.
+
+    If no body is specified then all documents will be counted.
+    */
+    pub fn body<TNewBody>(self, body: TNewBody) -> CountRequestBuilder<TSender, TNewBody>
+    where
+        TNewBody: Into<TSender::Body>,
+    {
+        RequestBuilder::new(
+            self.client,
+            self.params,
+            CountRequestInner {
+                body: body,
+                index: self.inner.index,
+                ty: self.inner.ty,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TBody> CountRequestBuilder<SyncSender, TBody>
+where
+    TBody: Into<<SyncSender as Sender>::Body>,
+{
+    /**
+    Send a `CountRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Count all documents in an index called `myindex`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.count()
+                         .index("myindex")
+                         .send()?;
+
+    println!("myindex has {} documents", response.count());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CountResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TBody> CountRequestBuilder<AsyncSender, TBody>
+where
+    TBody: Into<<AsyncSender as Sender>::Body>,
+{
+    /**
+    Send a `CountRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised count response.
+
+    # Examples
+
+    Count all documents in an index called `myindex`:
+
+    ```no_run
+    # extern crate tokio_core;
+    # extern crate futures;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.count()
+                       .index("myindex")
+                       .send();
+
+    future.and_then(|response| {
+        println!("myindex has {} documents", response.count());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CountResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CountResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CountResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.count().inner.into_request();
+
+        assert_eq!("/_all/_count", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .count()
+            .index("new-idx")
+            .ty(Some("new-ty"))
+            .inner
+            .into_request();
+
+        assert_eq!("/new-idx/new-ty/_count", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_body() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.count().body("{}").inner.into_request();
+
+        assert_eq!("{}", req.body);
+    }
+}
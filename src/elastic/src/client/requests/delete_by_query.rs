@@ -0,0 +1,491 @@
+/*!
+Builders for [delete by query requests][docs-delete-by-query].
+
+[docs-delete-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html
+*/
+
+use std::marker::PhantomData;
+use futures::{Future, IntoFuture, Poll};
+use serde::ser::Serialize;
+use serde_json::{self, Map, Value};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Type};
+use client::requests::endpoints::DeleteByQueryRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::tasks::TaskHandle;
+use client::responses::{DeleteByQueryResponse, StartedTaskResponse};
+use client::responses::bulk_by_scroll::BulkByScrollStatus;
+
+/**
+A [delete by query request][docs-delete-by-query] builder that can be configured before sending.
+
+Call [`Client.delete_by_query`][Client.delete_by_query] to get a `DeleteByQueryRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-delete-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.delete_by_query]: ../../struct.Client.html#delete-by-query-request
+*/
+pub type DeleteByQueryRequestBuilder<TSender, TResponse> = RequestBuilder<TSender, DeleteByQueryRequestInner<TResponse>>;
+
+#[doc(hidden)]
+pub struct DeleteByQueryRequestInner<TResponse> {
+    index: Index<'static>,
+    ty: Option<Type<'static>>,
+    body: Map<String, Value>,
+    err: Option<Error>,
+    _marker: PhantomData<TResponse>,
+}
+
+/**
+# Delete by query request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`DeleteByQueryRequestBuilder`][DeleteByQueryRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Delete all documents in an index called `myindex` that match a query:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.delete_by_query(index("myindex"))
+                         .query(json!({
+                             "term": {
+                                 "archived": true
+                             }
+                         }))
+                         .send()?;
+
+    println!("deleted {} documents", response.deleted());
+
+    for failure in response.failures() {
+        println!("failed: {:?}", failure);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [DeleteByQueryRequestBuilder]: requests/delete_by_query/type.DeleteByQueryRequestBuilder.html
+    [builder-methods]: requests/delete_by_query/type.DeleteByQueryRequestBuilder.html#builder-methods
+    [send-sync]: requests/delete_by_query/type.DeleteByQueryRequestBuilder.html#send-synchronously
+    [send-async]: requests/delete_by_query/type.DeleteByQueryRequestBuilder.html#send-asynchronously
+    */
+    pub fn delete_by_query(&self, index: Index<'static>) -> DeleteByQueryRequestBuilder<TSender, DeleteByQueryResponse> {
+        RequestBuilder::new(self.clone(), None, DeleteByQueryRequestInner::new(index))
+    }
+}
+
+impl<TResponse> DeleteByQueryRequestInner<TResponse> {
+    fn new(index: Index<'static>) -> Self {
+        DeleteByQueryRequestInner {
+            index: index,
+            ty: None,
+            body: Map::new(),
+            err: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn set_body<TValue>(&mut self, key: &str, value: TValue)
+    where
+        TValue: Serialize,
+    {
+        if self.err.is_some() {
+            return;
+        }
+
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.body.insert(key.to_owned(), value);
+            }
+            Err(e) => self.err = Some(error::request(e)),
+        }
+    }
+
+    fn into_request(self) -> Result<DeleteByQueryRequest<'static, Vec<u8>>> {
+        if let Some(err) = self.err {
+            return Err(err);
+        }
+
+        let body = serde_json::to_vec(&self.body).map_err(error::request)?;
+
+        let req = match self.ty {
+            Some(ty) => DeleteByQueryRequest::for_index_ty(self.index, ty, body),
+            None => DeleteByQueryRequest::for_index(self.index, body),
+        };
+
+        Ok(req)
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `DeleteByQueryRequestBuilder` before sending it.
+*/
+impl<TSender, TResponse> DeleteByQueryRequestBuilder<TSender, TResponse>
+where
+    TSender: Sender,
+{
+    /** Set the types for the delete by query request. */
+    pub fn ty<I>(mut self, ty: Option<I>) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.map(Into::into);
+        self
+    }
+
+    /**
+    Set the query used to find documents to delete.
+
+    Elasticsearch requires a query, so to delete all documents use a `match_all` query.
+    If the query fails to serialise then the error will be returned when the request is sent.
+    */
+    pub fn query<TQuery>(mut self, query: TQuery) -> Self
+    where
+        TQuery: Serialize,
+    {
+        self.inner.set_body("query", query);
+        self
+    }
+
+    /**
+    Run the delete by query request in the background.
+
+    Instead of waiting for all documents to be deleted, sending the request will return a [`DeleteByQueryTask`][DeleteByQueryTask] that can be used to check on its progress.
+    This sets the `wait_for_completion` parameter to `false`.
+
+    # Examples
+
+    Start deleting documents in the background and wait for it to complete:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let task = client.delete_by_query(index("myindex"))
+                     .query(json!({ "match_all": {} }))
+                     .background()
+                     .send()?;
+
+    let response = task.wait_with_progress(|status| {
+        println!("deleted {} of {} documents", status.deleted(), status.total());
+    })?;
+    # Ok(())
+    # }
+    ```
+
+    [DeleteByQueryTask]: type.DeleteByQueryTask.html
+    */
+    pub fn background(self) -> DeleteByQueryRequestBuilder<TSender, StartedTaskResponse> {
+        let builder = self.params(|p| p.url_param("wait_for_completion", false));
+
+        RequestBuilder::new(
+            builder.client,
+            builder.params,
+            DeleteByQueryRequestInner {
+                index: builder.inner.index,
+                ty: builder.inner.ty,
+                body: builder.inner.body,
+                err: builder.inner.err,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl DeleteByQueryRequestBuilder<SyncSender, DeleteByQueryResponse> {
+    /**
+    Send a `DeleteByQueryRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Delete all documents in an index called `myindex` that match a query:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.delete_by_query(index("myindex"))
+                         .query(json!({ "term": { "archived": true } }))
+                         .send()?;
+
+    println!("deleted {} documents", response.deleted());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<DeleteByQueryResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send synchronously in the background
+*/
+impl DeleteByQueryRequestBuilder<SyncSender, StartedTaskResponse> {
+    /**
+    Start a `DeleteByQueryRequestBuilder` in the background using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until the delete by query task has started.
+    The returned [`DeleteByQueryTask`][DeleteByQueryTask] can be used to check on its progress.
+
+    [SyncClient]: ../../type.SyncClient.html
+    [DeleteByQueryTask]: type.DeleteByQueryTask.html
+    */
+    pub fn send(self) -> Result<DeleteByQueryTask<SyncSender>> {
+        let client = self.client;
+        let req = self.inner.into_request()?;
+
+        let res: StartedTaskResponse = RequestBuilder::new(client.clone(), self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()?;
+
+        Ok(client.task(res.task()))
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl DeleteByQueryRequestBuilder<AsyncSender, DeleteByQueryResponse> {
+    /**
+    Send a `DeleteByQueryRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised delete by query response.
+
+    # Examples
+
+    Delete all documents in an index called `myindex` that match a query:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.delete_by_query(index("myindex"))
+                       .query(json!({ "term": { "archived": true } }))
+                       .send();
+
+    future.and_then(|response| {
+        println!("deleted {} documents", response.deleted());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<DeleteByQueryResponse> {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/**
+# Send asynchronously in the background
+*/
+impl DeleteByQueryRequestBuilder<AsyncSender, StartedTaskResponse> {
+    /**
+    Start a `DeleteByQueryRequestBuilder` in the background using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to a [`DeleteByQueryTask`][DeleteByQueryTask] once the delete by query task has started.
+
+    # Examples
+
+    Start deleting documents in the background and wait for it to complete:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.delete_by_query(index("myindex"))
+                       .query(json!({ "match_all": {} }))
+                       .background()
+                       .send()
+                       .and_then(|task| task.wait());
+
+    future.and_then(|response| {
+        println!("deleted {} documents", response.deleted());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [DeleteByQueryTask]: type.DeleteByQueryTask.html
+    */
+    pub fn send(self) -> Pending<DeleteByQueryTask<AsyncSender>> {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client.clone(), params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response::<StartedTaskResponse>())
+                .map(move |res| client.task(res.task()))
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/**
+A handle to a delete by query request running in the background.
+
+Call [`DeleteByQueryRequestBuilder.background`][DeleteByQueryRequestBuilder.background] to get a `DeleteByQueryTask` when the request is sent.
+
+[DeleteByQueryRequestBuilder.background]: type.DeleteByQueryRequestBuilder.html#method.background
+*/
+pub type DeleteByQueryTask<TSender> = TaskHandle<TSender, DeleteByQueryResponse, BulkByScrollStatus>;
+
+/** A future returned by calling `send`. */
+pub struct Pending<T> {
+    inner: Box<Future<Item = T, Error = Error>>,
+}
+
+impl<T> Pending<T> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = T, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<T> Future for Pending<T> {
+    type Item = T;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .delete_by_query(index("test-idx"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/test-idx/_delete_by_query", req.url.as_ref());
+        assert_eq!(json!({}), serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .delete_by_query(index("test-idx"))
+            .ty(Some("new-ty"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/test-idx/new-ty/_delete_by_query", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_query() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .delete_by_query(index("test-idx"))
+            .query(json!({ "match_all": {} }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "query": {
+                "match_all": {}
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_background() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.delete_by_query(index("test-idx")).background();
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+
+        assert_eq!("?wait_for_completion=false", query.unwrap());
+    }
+}
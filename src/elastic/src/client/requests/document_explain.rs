@@ -0,0 +1,348 @@
+/*!
+Builders for [explain requests][docs-explain].
+
+[docs-explain]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html
+*/
+
+use std::marker::PhantomData;
+use futures::{Future, Poll};
+use serde::de::DeserializeOwned;
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::{empty_body, DefaultBody, RequestBuilder};
+use client::requests::params::{Id, Index, Type};
+use client::requests::endpoints::ExplainRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::ExplainResponse;
+use types::document::DocumentType;
+
+/**
+An [explain request][docs-explain] builder that can be configured before sending.
+
+Call [`Client.document_explain`][Client.document_explain] to get an `ExplainRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-explain]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document_explain]: ../../struct.Client.html#explain-request
+*/
+pub type ExplainRequestBuilder<TSender, TDocument, TBody> = RequestBuilder<TSender, ExplainRequestInner<TDocument, TBody>>;
+
+#[doc(hidden)]
+pub struct ExplainRequestInner<TDocument, TBody> {
+    index: Index<'static>,
+    ty: Type<'static>,
+    id: Id<'static>,
+    body: TBody,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Explain request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`ExplainRequestBuilder`][ExplainRequestBuilder] with this `Client` that can be configured before sending.
+
+    The request explains how the score for a single document is computed for a query.
+    The source of the document is returned along with the explanation.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Explain the score for a [`DocumentType`][documents-mod] called `MyType` with an id of `1`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub title: String,
+    #     pub timestamp: Date<DefaultDateMapping>
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document_explain::<MyType>(index("myindex"), id(1))
+                         .body(json!({
+                             "query": {
+                                 "match": {
+                                     "title": "a title"
+                                 }
+                             }
+                         }))
+                         .send()?;
+
+    if let Some(explanation) = response.explanation() {
+        println!("{}: {}", explanation.value(), explanation.description());
+    }
+
+    if let Some(doc) = response.into_document() {
+        println!("{:?}", doc);
+    }
+    # Ok(())
+    # }
+    ```
+
+    For more details on document types, see the [`types`][types-mod] module.
+
+    [ExplainRequestBuilder]: requests/document_explain/type.ExplainRequestBuilder.html
+    [builder-methods]: requests/document_explain/type.ExplainRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_explain/type.ExplainRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_explain/type.ExplainRequestBuilder.html#send-asynchronously
+    [types-mod]: ../types/index.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn document_explain<TDocument>(&self, index: Index<'static>, id: Id<'static>) -> ExplainRequestBuilder<TSender, TDocument, DefaultBody>
+    where
+        TDocument: DeserializeOwned + DocumentType,
+    {
+        let ty = TDocument::name().into();
+
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            ExplainRequestInner {
+                index: index,
+                ty: ty,
+                id: id,
+                body: empty_body(),
+                _marker: PhantomData,
+            },
+        ).params(|p| p.url_param("_source", true))
+    }
+}
+
+impl<TDocument, TBody> ExplainRequestInner<TDocument, TBody> {
+    fn into_request(self) -> ExplainRequest<'static, TBody> {
+        ExplainRequest::for_index_ty_id(self.index, self.ty, self.id, self.body)
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `ExplainRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument, TBody> ExplainRequestBuilder<TSender, TDocument, TBody>
+where
+    TSender: Sender,
+{
+    /** Set the type for the explain request. */
+    pub fn ty<I>(mut self, ty: I) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.into();
+        self
+    }
+
+    /** Set the query body for the explain request. */
+    pub fn body<TNewBody>(self, body: TNewBody) -> ExplainRequestBuilder<TSender, TDocument, TNewBody>
+    where
+        TNewBody: Into<TSender::Body>,
+    {
+        RequestBuilder::new(
+            self.client,
+            self.params,
+            ExplainRequestInner {
+                index: self.inner.index,
+                ty: self.inner.ty,
+                id: self.inner.id,
+                body: body,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument, TBody> ExplainRequestBuilder<SyncSender, TDocument, TBody>
+where
+    TDocument: DeserializeOwned,
+    TBody: Into<<SyncSender as Sender>::Body>,
+{
+    /**
+    Send an `ExplainRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Explain the score for a document in an index called `myindex` with an id of `1`:
+
+    ```no_run
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document_explain::<Value>(index("myindex"), id(1))
+                         .ty("mytype")
+                         .body(r#"{ "query": { "match_all": {} } }"#)
+                         .send()?;
+
+    println!("matched: {}", response.matched());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ExplainResponse<TDocument>> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument, TBody> ExplainRequestBuilder<AsyncSender, TDocument, TBody>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+    TBody: Into<<AsyncSender as Sender>::Body>,
+{
+    /**
+    Send an `ExplainRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised explain response.
+
+    # Examples
+
+    Explain the score for a document in an index called `myindex` with an id of `1`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.document_explain::<Value>(index("myindex"), id(1))
+                       .ty("mytype")
+                       .body(r#"{ "query": { "match_all": {} } }"#)
+                       .send();
+
+    future.and_then(|response| {
+        println!("matched: {}", response.matched());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TDocument> {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending<TDocument> {
+    inner: Box<Future<Item = ExplainResponse<TDocument>, Error = Error>>,
+}
+
+impl<TDocument> Pending<TDocument> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = ExplainResponse<TDocument>, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<TDocument> Future for Pending<TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    type Item = ExplainResponse<TDocument>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.document_explain::<Value>(index("test-idx"), id("1"));
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+        assert_eq!("?_source=true", query.unwrap());
+
+        let req = req.inner.into_request();
+
+        assert_eq!("/test-idx/value/1/_explain", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_explain::<Value>(index("test-idx"), id("1"))
+            .ty("new-ty")
+            .inner
+            .into_request();
+
+        assert_eq!("/test-idx/new-ty/1/_explain", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_body() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_explain::<Value>(index("test-idx"), id("1"))
+            .body("{}")
+            .inner
+            .into_request();
+
+        assert_eq!("{}", req.body);
+    }
+}
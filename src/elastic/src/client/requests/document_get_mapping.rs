@@ -0,0 +1,327 @@
+/*!
+Builders for [get mapping requests][docs-get-mapping].
+
+[docs-get-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html
+*/
+
+use std::marker::PhantomData;
+use serde_json;
+use futures::{Future, Poll};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Type};
+use client::requests::endpoints::IndicesGetMappingRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::GetMappingResponse;
+use client::responses::mapping::{FieldMapping, MappingDifference};
+use types::document::DocumentType;
+
+/**
+A [get mapping request][docs-get-mapping] builder that can be configured before sending.
+
+Call [`Client.document_get_mapping`][Client.document_get_mapping] to get a `GetMappingRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-get-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document_get_mapping]: ../../struct.Client.html#get-mapping-request
+*/
+pub type GetMappingRequestBuilder<TSender, TDocument> = RequestBuilder<TSender, GetMappingRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct GetMappingRequestInner<TDocument> {
+    index: Index<'static>,
+    ty: Type<'static>,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Get mapping request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`GetMappingRequestBuilder`][GetMappingRequestBuilder] with this `Client` that can be configured before sending.
+
+    The live mapping can be compared with the mapping for the document type using [`document_mapping_diff`][document_mapping_diff].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether the mapping for a [`DocumentType`][documents-mod] called `MyType` has drifted from the mapping in an index called `myindex`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document_get_mapping::<MyType>(index("myindex"))
+                         .send()?;
+
+    if let Some(mapping) = response.get("myindex", MyType::name()) {
+        for difference in document_mapping_diff::<MyType>(mapping)? {
+            println!("{}", difference);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    For more details on document types and mapping, see the [`types`][types-mod] module.
+
+    [GetMappingRequestBuilder]: requests/document_get_mapping/type.GetMappingRequestBuilder.html
+    [document_mapping_diff]: requests/document_get_mapping/fn.document_mapping_diff.html
+    [builder-methods]: requests/document_get_mapping/type.GetMappingRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_get_mapping/type.GetMappingRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_get_mapping/type.GetMappingRequestBuilder.html#send-asynchronously
+    [types-mod]: ../types/index.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn document_get_mapping<TDocument>(&self, index: Index<'static>) -> GetMappingRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DocumentType,
+    {
+        let ty = TDocument::name().into();
+
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            GetMappingRequestInner {
+                index: index,
+                ty: ty,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TDocument> GetMappingRequestInner<TDocument> {
+    fn into_request(self) -> IndicesGetMappingRequest<'static> {
+        IndicesGetMappingRequest::for_index_ty(self.index, self.ty)
+    }
+}
+
+/**
+Compare a live mapping with the mapping for a [`DocumentType`][documents-mod].
+
+The expected mapping is the serialised [`DocumentType::index_mapping`][DocumentType.index_mapping].
+Every field and parameter in the expected mapping must be in the live mapping with the same value, so any differences are returned.
+An empty result means the live mapping is compatible with the document type.
+
+If the document mapping fails to serialise then an error is returned.
+
+[documents-mod]: ../../../types/document/index.html
+[DocumentType.index_mapping]: ../../../types/document/trait.DocumentType.html#method.index_mapping
+*/
+pub fn document_mapping_diff<TDocument>(mapping: &FieldMapping) -> Result<Vec<MappingDifference>>
+where
+    TDocument: DocumentType,
+{
+    let expected = serde_json::to_value(&TDocument::index_mapping()).map_err(error::request)?;
+    let expected: FieldMapping = serde_json::from_value(expected).map_err(error::request)?;
+
+    Ok(mapping.diff(&expected))
+}
+
+/**
+# Builder methods
+
+Configure a `GetMappingRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> GetMappingRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Set the type for the get mapping request. */
+    pub fn ty<I>(mut self, ty: I) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.into();
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> GetMappingRequestBuilder<SyncSender, TDocument> {
+    /**
+    Send a `GetMappingRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the mapping for a document type called `MyType`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document_get_mapping::<MyType>(index("myindex"))
+                         .send()?;
+
+    assert!(response.get("myindex", MyType::name()).is_some());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<GetMappingResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> GetMappingRequestBuilder<AsyncSender, TDocument> {
+    /**
+    Send a `GetMappingRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get mapping response.
+
+    # Examples
+
+    Get the mapping for a document type called `MyType`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.document_get_mapping::<MyType>(index("myindex"))
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.get("myindex", MyType::name()).is_some());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = GetMappingResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = GetMappingResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = GetMappingResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+    use client::responses::mapping::FieldMapping;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_get_mapping::<Value>(index("test-idx"))
+            .inner
+            .into_request();
+
+        assert_eq!("/test-idx/_mapping/value", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_get_mapping::<Value>(index("test-idx"))
+            .ty("new-ty")
+            .inner
+            .into_request();
+
+        assert_eq!("/test-idx/_mapping/new-ty", req.url.as_ref());
+    }
+
+    #[test]
+    fn document_mapping_diff_ignores_live_fields() {
+        let live: FieldMapping = serde_json::from_value(json!({
+            "properties": {
+                "title": {
+                    "type": "text"
+                }
+            }
+        })).unwrap();
+
+        let differences = document_mapping_diff::<Value>(&live).unwrap();
+
+        assert!(differences.is_empty());
+    }
+}
@@ -148,6 +148,20 @@ where
         self.inner.ty = ty.into();
         self
     }
+
+    /**
+    Set the id of an ingest pipeline to run the document through before it's indexed.
+
+    The pipeline must already exist on the cluster.
+    */
+    pub fn pipeline<I>(self, pipeline: I) -> Self
+    where
+        I: Into<Id<'static>>,
+    {
+        let pipeline: Id<'static> = pipeline.into();
+
+        self.params(move |p| p.url_param("pipeline", &*pipeline))
+    }
 }
 
 /**
@@ -334,6 +348,19 @@ mod tests {
         assert_eq!("/test-idx/new-ty/1", req.url.as_ref());
     }
 
+    #[test]
+    fn specify_pipeline() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_index(index("test-idx"), id("1"), Value::Null)
+            .pipeline("logs");
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+
+        assert_eq!("?pipeline=logs", query.unwrap());
+    }
+
     #[test]
     fn document_borrow() {
         let client = SyncClientBuilder::new().build().unwrap();
@@ -0,0 +1,347 @@
+/*!
+Builders for [multi get document requests][docs-mget].
+
+[docs-mget]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-get.html
+*/
+
+use std::marker::PhantomData;
+use futures::{future, Future, Poll};
+use serde::de::DeserializeOwned;
+use serde_json;
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Id, Index, Type};
+use client::requests::endpoints::MgetRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::MgetResponse;
+
+/**
+A [multi get document request][docs-mget] builder that can be configured before sending.
+
+Call [`Client.document_mget`][Client.document_mget] to get an `MgetRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-mget]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-get.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document_mget]: ../../struct.Client.html#multi-get-document-request
+*/
+pub type MgetRequestBuilder<TSender, TDocument> = RequestBuilder<TSender, MgetRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct MgetRequestInner<TDocument> {
+    docs: Vec<(Index<'static>, Type<'static>, Id<'static>)>,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Multi get document request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`MgetRequestBuilder`][MgetRequestBuilder] with this `Client` that can be configured before sending.
+
+    The documents to get are added as a list of `(index, type, id)` and are fetched in a single request.
+    Each document in the response is either a `GetResponse`, which may not have been found, or an error.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get a few documents as `serde_json::Value`s:
+
+    ```no_run
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document_mget::<Value>()
+                         .docs(vec![
+                             (index("myindex"), ty("mytype"), id(1)),
+                             (index("myindex"), ty("mytype"), id(2)),
+                         ])
+                         .send()?;
+
+    for doc in response {
+        match doc {
+            Ok(doc) => println!("id: {}, found: {}", doc.id(), doc.found()),
+            Err(doc) => println!("err: {}", doc),
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [MgetRequestBuilder]: requests/document_mget/type.MgetRequestBuilder.html
+    [builder-methods]: requests/document_mget/type.MgetRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_mget/type.MgetRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_mget/type.MgetRequestBuilder.html#send-asynchronously
+    */
+    pub fn document_mget<TDocument>(&self) -> MgetRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DeserializeOwned,
+    {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            MgetRequestInner {
+                docs: Vec::new(),
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct MgetBody<'a> {
+    docs: Vec<MgetBodyDoc<'a>>,
+}
+
+#[derive(Serialize)]
+struct MgetBodyDoc<'a> {
+    #[serde(rename = "_index")] index: &'a str,
+    #[serde(rename = "_type")] ty: &'a str,
+    #[serde(rename = "_id")] id: &'a str,
+}
+
+impl<TDocument> MgetRequestInner<TDocument> {
+    fn into_request(self) -> Result<MgetRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&MgetBody {
+            docs: self.docs
+                .iter()
+                .map(|&(ref index, ref ty, ref id)| MgetBodyDoc {
+                    index: index.as_ref(),
+                    ty: ty.as_ref(),
+                    id: id.as_ref(),
+                })
+                .collect(),
+        }).map_err(error::request)?;
+
+        Ok(MgetRequest::new(body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `MgetRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> MgetRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Add a list of `(index, type, id)` documents to get. */
+    pub fn docs<I>(mut self, docs: I) -> Self
+    where
+        I: IntoIterator<Item = (Index<'static>, Type<'static>, Id<'static>)>,
+    {
+        self.inner.docs.extend(docs);
+        self
+    }
+
+    /** Add a document to get. */
+    pub fn doc(mut self, index: Index<'static>, ty: Type<'static>, id: Id<'static>) -> Self {
+        self.inner.docs.push((index, ty, id));
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> MgetRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    /**
+    Send an `MgetRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get a few documents from an index called `myindex`:
+
+    ```no_run
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document_mget::<Value>()
+                         .doc(index("myindex"), ty("mytype"), id(1))
+                         .doc(index("myindex"), ty("mytype"), id(2))
+                         .send()?;
+
+    let docs = response.into_iter()
+                       .filter_map(|doc| doc.ok())
+                       .filter_map(|doc| doc.into_document());
+
+    for doc in docs {
+        println!("{:?}", doc);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<MgetResponse<TDocument>> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> MgetRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    /**
+    Send an `MgetRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised multi get document response.
+
+    # Examples
+
+    Get a few documents from an index called `myindex`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.document_mget::<Value>()
+                       .doc(index("myindex"), ty("mytype"), id(1))
+                       .doc(index("myindex"), ty("mytype"), id(2))
+                       .send();
+
+    future.and_then(|response| {
+        for doc in response.iter().filter_map(|doc| doc.ok()) {
+            println!("{:?}", doc.document());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TDocument> {
+        let req = match self.inner.into_request() {
+            Ok(req) => req,
+            Err(e) => return Pending::new(future::err(e)),
+        };
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending<TDocument> {
+    inner: Box<Future<Item = MgetResponse<TDocument>, Error = Error>>,
+}
+
+impl<TDocument> Pending<TDocument> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = MgetResponse<TDocument>, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<TDocument> Future for Pending<TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    type Item = MgetResponse<TDocument>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_mget::<Value>()
+            .docs(vec![(index("test-idx"), ty("test-ty"), id("1"))])
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "docs": [
+                { "_index": "test-idx", "_type": "test-ty", "_id": "1" }
+            ]
+        });
+
+        assert_eq!("/_mget", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_doc() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_mget::<Value>()
+            .docs(vec![(index("test-idx"), ty("test-ty"), id("1"))])
+            .doc(index("test-idx"), ty("test-ty"), id("2"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "docs": [
+                { "_index": "test-idx", "_type": "test-ty", "_id": "1" },
+                { "_index": "test-idx", "_type": "test-ty", "_id": "2" }
+            ]
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
@@ -0,0 +1,426 @@
+/*!
+Builders for [multi term vectors requests][docs-mtermvectors].
+
+[docs-mtermvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-termvectors.html
+*/
+
+use futures::{Future, Poll};
+use serde::Serialize;
+use serde_json;
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Id, Index, Type};
+use client::requests::endpoints::MtermvectorsRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::MtermvectorsResponse;
+use types::document::DocumentType;
+
+/**
+A [multi term vectors request][docs-mtermvectors] builder that can be configured before sending.
+
+Call [`Client.document_mtermvectors`][Client.document_mtermvectors] to get an `MtermvectorsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-mtermvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-termvectors.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document_mtermvectors]: ../../struct.Client.html#multi-term-vectors-request
+*/
+pub type MtermvectorsRequestBuilder<TSender, TDocument> = RequestBuilder<TSender, MtermvectorsRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct MtermvectorsRequestInner<TDocument> {
+    docs: Vec<MtermvectorsDoc<TDocument>>,
+}
+
+struct MtermvectorsDoc<TDocument> {
+    index: Index<'static>,
+    ty: Type<'static>,
+    id: Option<Id<'static>>,
+    doc: Option<TDocument>,
+}
+
+/**
+# Multi term vectors request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`MtermvectorsRequestBuilder`][MtermvectorsRequestBuilder] with this `Client` that can be configured before sending.
+
+    The documents are added as a list of `(index, type, id)` or as artificial documents, and their term vectors are fetched in a single request.
+    The same options are used for every document.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the term vectors for a few documents, along with an artificial `serde_json::Value`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document_mtermvectors::<Value>()
+                         .docs(vec![
+                             (index("myindex"), ty("mytype"), id(1)),
+                             (index("myindex"), ty("mytype"), id(2)),
+                         ])
+                         .artificial_doc(index("myindex"), json!({ "title": "A title" }))
+                         .fields(vec!["title"])
+                         .send()?;
+
+    for doc in response {
+        println!("{:?}: {:?}", doc.id(), doc.field("title").map(|title| title.terms().len()));
+    }
+    # Ok(())
+    # }
+    ```
+
+    [MtermvectorsRequestBuilder]: requests/document_mtermvectors/type.MtermvectorsRequestBuilder.html
+    [builder-methods]: requests/document_mtermvectors/type.MtermvectorsRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_mtermvectors/type.MtermvectorsRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_mtermvectors/type.MtermvectorsRequestBuilder.html#send-asynchronously
+    */
+    pub fn document_mtermvectors<TDocument>(&self) -> MtermvectorsRequestBuilder<TSender, TDocument>
+    where
+        TDocument: Serialize,
+    {
+        RequestBuilder::new(self.clone(), None, MtermvectorsRequestInner { docs: Vec::new() })
+    }
+}
+
+#[derive(Serialize)]
+struct MtermvectorsBody<'a, TDocument: 'a> {
+    docs: Vec<MtermvectorsBodyDoc<'a, TDocument>>,
+}
+
+#[derive(Serialize)]
+struct MtermvectorsBodyDoc<'a, TDocument: 'a> {
+    #[serde(rename = "_index")] index: &'a str,
+    #[serde(rename = "_type")] ty: &'a str,
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")] id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")] doc: Option<&'a TDocument>,
+}
+
+impl<TDocument> MtermvectorsRequestInner<TDocument>
+where
+    TDocument: Serialize,
+{
+    fn into_request(self) -> Result<MtermvectorsRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&MtermvectorsBody {
+            docs: self.docs
+                .iter()
+                .map(|doc| MtermvectorsBodyDoc {
+                    index: doc.index.as_ref(),
+                    ty: doc.ty.as_ref(),
+                    id: doc.id.as_ref().map(|id| id.as_ref()),
+                    doc: doc.doc.as_ref(),
+                })
+                .collect(),
+        }).map_err(error::request)?;
+
+        Ok(MtermvectorsRequest::new(body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `MtermvectorsRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> MtermvectorsRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Add a list of `(index, type, id)` documents to get term vectors for. */
+    pub fn docs<I>(mut self, docs: I) -> Self
+    where
+        I: IntoIterator<Item = (Index<'static>, Type<'static>, Id<'static>)>,
+    {
+        self.inner.docs.extend(docs.into_iter().map(|(index, ty, id)| MtermvectorsDoc {
+            index: index,
+            ty: ty,
+            id: Some(id),
+            doc: None,
+        }));
+        self
+    }
+
+    /** Add a document to get term vectors for. */
+    pub fn doc(mut self, index: Index<'static>, ty: Type<'static>, id: Id<'static>) -> Self {
+        self.inner.docs.push(MtermvectorsDoc {
+            index: index,
+            ty: ty,
+            id: Some(id),
+            doc: None,
+        });
+        self
+    }
+
+    /**
+    Add an artificial document to get term vectors for.
+
+    The document isn't indexed.
+    Its fields are analysed using the mapping for the document type on the index.
+    */
+    pub fn artificial_doc(mut self, index: Index<'static>, doc: TDocument) -> Self
+    where
+        TDocument: DocumentType,
+    {
+        self.inner.docs.push(MtermvectorsDoc {
+            index: index,
+            ty: TDocument::name().into(),
+            id: None,
+            doc: Some(doc),
+        });
+        self
+    }
+
+    /**
+    Set the fields to return term vectors for.
+
+    If no fields are specified then all fields with stored term vectors are returned.
+    */
+    pub fn fields<I, S>(self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let fields = fields.into_iter().map(|field| field.as_ref().to_owned()).collect::<Vec<_>>().join(",");
+
+        self.params(move |p| p.url_param("fields", &*fields))
+    }
+
+    /** Set whether or not to return the start and end offsets of each token. The default is `true`. */
+    pub fn offsets(self, offsets: bool) -> Self {
+        self.params(move |p| p.url_param("offsets", offsets))
+    }
+
+    /** Set whether or not to return the position of each token. The default is `true`. */
+    pub fn positions(self, positions: bool) -> Self {
+        self.params(move |p| p.url_param("positions", positions))
+    }
+
+    /** Set whether or not to return the payload of each token. The default is `true`. */
+    pub fn payloads(self, payloads: bool) -> Self {
+        self.params(move |p| p.url_param("payloads", payloads))
+    }
+
+    /** Set whether or not to return the document and total term frequencies of each term. The default is `false`. */
+    pub fn term_statistics(self, term_statistics: bool) -> Self {
+        self.params(move |p| p.url_param("term_statistics", term_statistics))
+    }
+
+    /** Set whether or not to return the document count and frequency sums of each field. The default is `true`. */
+    pub fn field_statistics(self, field_statistics: bool) -> Self {
+        self.params(move |p| p.url_param("field_statistics", field_statistics))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> MtermvectorsRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: Serialize,
+{
+    /**
+    Send an `MtermvectorsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the term vectors for a few documents in an index called `myindex`:
+
+    ```no_run
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document_mtermvectors::<Value>()
+                         .doc(index("myindex"), ty("mytype"), id(1))
+                         .doc(index("myindex"), ty("mytype"), id(2))
+                         .send()?;
+
+    for doc in response.iter().filter(|doc| doc.found()) {
+        println!("{:?}", doc.fields().keys().collect::<Vec<_>>());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<MtermvectorsResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> MtermvectorsRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: Serialize + Send + 'static,
+{
+    /**
+    Send an `MtermvectorsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised multi term vectors response.
+
+    # Examples
+
+    Get the term vectors for a few documents in an index called `myindex`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.document_mtermvectors::<Value>()
+                       .doc(index("myindex"), ty("mytype"), id(1))
+                       .doc(index("myindex"), ty("mytype"), id(2))
+                       .send();
+
+    future.and_then(|response| {
+        for doc in response.iter().filter(|doc| doc.found()) {
+            println!("{:?}", doc.fields().keys().collect::<Vec<_>>());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = MtermvectorsResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = MtermvectorsResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = MtermvectorsResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_mtermvectors::<Value>()
+            .docs(vec![(index("test-idx"), ty("test-ty"), id("1"))])
+            .doc(index("test-idx"), ty("test-ty"), id("2"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "docs": [
+                { "_index": "test-idx", "_type": "test-ty", "_id": "1" },
+                { "_index": "test-idx", "_type": "test-ty", "_id": "2" }
+            ]
+        });
+
+        assert_eq!("/_mtermvectors", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_artificial_doc() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_mtermvectors()
+            .artificial_doc(index("test-idx"), json!({ "title": "A title" }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "docs": [
+                { "_index": "test-idx", "_type": "value", "doc": { "title": "A title" } }
+            ]
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_options() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_mtermvectors::<Value>()
+            .fields(vec!["title"])
+            .term_statistics(true);
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+        assert_eq!("?fields=title&term_statistics=true", query.unwrap());
+    }
+}
@@ -0,0 +1,463 @@
+/*!
+Builders for [term vectors requests][docs-termvectors].
+
+[docs-termvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html
+*/
+
+use futures::{Future, Poll};
+use serde::Serialize;
+use serde_json;
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Id, Index, Type};
+use client::requests::endpoints::TermvectorsRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::TermvectorsResponse;
+use types::document::DocumentType;
+
+/**
+A [term vectors request][docs-termvectors] builder that can be configured before sending.
+
+Call [`Client.document_termvectors`][Client.document_termvectors] or [`Client.document_termvectors_artificial`][Client.document_termvectors_artificial] to get a `TermvectorsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-termvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document_termvectors]: ../../struct.Client.html#term-vectors-request
+[Client.document_termvectors_artificial]: ../../struct.Client.html#method.document_termvectors_artificial
+*/
+pub type TermvectorsRequestBuilder<TSender, TDocument> = RequestBuilder<TSender, TermvectorsRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct TermvectorsRequestInner<TDocument> {
+    index: Index<'static>,
+    ty: Type<'static>,
+    id: Option<Id<'static>>,
+    doc: Option<TDocument>,
+}
+
+/**
+# Term vectors request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`TermvectorsRequestBuilder`][TermvectorsRequestBuilder] with this `Client` that can be configured before sending.
+
+    The request returns the terms in the fields of a stored document.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the term vectors for the `title` field of a [`DocumentType`][documents-mod] called `MyType` with an id of `1`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub title: String,
+    #     pub timestamp: Date<DefaultDateMapping>
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document_termvectors::<MyType>(index("myindex"), id(1))
+                         .fields(vec!["title"])
+                         .term_statistics(true)
+                         .send()?;
+
+    if let Some(title) = response.field("title") {
+        for (term, info) in title.iter() {
+            println!("{}: {:?}", term, info.doc_freq());
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    For more details on document types, see the [`types`][types-mod] module.
+
+    [TermvectorsRequestBuilder]: requests/document_termvectors/type.TermvectorsRequestBuilder.html
+    [builder-methods]: requests/document_termvectors/type.TermvectorsRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_termvectors/type.TermvectorsRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_termvectors/type.TermvectorsRequestBuilder.html#send-asynchronously
+    [types-mod]: ../types/index.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn document_termvectors<TDocument>(&self, index: Index<'static>, id: Id<'static>) -> TermvectorsRequestBuilder<TSender, TDocument>
+    where
+        TDocument: Serialize + DocumentType,
+    {
+        let ty = TDocument::name().into();
+
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            TermvectorsRequestInner {
+                index: index,
+                ty: ty,
+                id: Some(id),
+                doc: None,
+            },
+        )
+    }
+
+    /**
+    Create a [`TermvectorsRequestBuilder`][TermvectorsRequestBuilder] for an artificial document with this `Client` that can be configured before sending.
+
+    The document isn't indexed.
+    Its fields are analysed using the mapping for the document type on the index.
+
+    # Examples
+
+    Get the term vectors for an artificial [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub title: String,
+    #     pub timestamp: Date<DefaultDateMapping>
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    let doc = MyType {
+        id: 1,
+        title: String::from("A title"),
+        timestamp: Date::now()
+    };
+
+    let response = client.document_termvectors_artificial(index("myindex"), doc)
+                         .fields(vec!["title"])
+                         .send()?;
+
+    for (field, terms) in response.iter() {
+        println!("{}: {:?}", field, terms.terms().keys().collect::<Vec<_>>());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [TermvectorsRequestBuilder]: requests/document_termvectors/type.TermvectorsRequestBuilder.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn document_termvectors_artificial<TDocument>(&self, index: Index<'static>, doc: TDocument) -> TermvectorsRequestBuilder<TSender, TDocument>
+    where
+        TDocument: Serialize + DocumentType,
+    {
+        let ty = TDocument::name().into();
+
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            TermvectorsRequestInner {
+                index: index,
+                ty: ty,
+                id: None,
+                doc: Some(doc),
+            },
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct TermvectorsBody<'a, TDocument: 'a> {
+    #[serde(skip_serializing_if = "Option::is_none")] doc: Option<&'a TDocument>,
+}
+
+impl<TDocument> TermvectorsRequestInner<TDocument>
+where
+    TDocument: Serialize,
+{
+    fn into_request(self) -> Result<TermvectorsRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&TermvectorsBody { doc: self.doc.as_ref() }).map_err(error::request)?;
+
+        let req = match self.id {
+            Some(id) => TermvectorsRequest::for_index_ty_id(self.index, self.ty, id, body),
+            None => TermvectorsRequest::for_index_ty(self.index, self.ty, body),
+        };
+
+        Ok(req)
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `TermvectorsRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> TermvectorsRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Set the type for the term vectors request. */
+    pub fn ty<I>(mut self, ty: I) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.into();
+        self
+    }
+
+    /**
+    Set the fields to return term vectors for.
+
+    If no fields are specified then all fields with stored term vectors are returned.
+    */
+    pub fn fields<I, S>(self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let fields = fields.into_iter().map(|field| field.as_ref().to_owned()).collect::<Vec<_>>().join(",");
+
+        self.params(move |p| p.url_param("fields", &*fields))
+    }
+
+    /** Set whether or not to return the start and end offsets of each token. The default is `true`. */
+    pub fn offsets(self, offsets: bool) -> Self {
+        self.params(move |p| p.url_param("offsets", offsets))
+    }
+
+    /** Set whether or not to return the position of each token. The default is `true`. */
+    pub fn positions(self, positions: bool) -> Self {
+        self.params(move |p| p.url_param("positions", positions))
+    }
+
+    /** Set whether or not to return the payload of each token. The default is `true`. */
+    pub fn payloads(self, payloads: bool) -> Self {
+        self.params(move |p| p.url_param("payloads", payloads))
+    }
+
+    /** Set whether or not to return the document and total term frequencies of each term. The default is `false`. */
+    pub fn term_statistics(self, term_statistics: bool) -> Self {
+        self.params(move |p| p.url_param("term_statistics", term_statistics))
+    }
+
+    /** Set whether or not to return the document count and frequency sums of each field. The default is `true`. */
+    pub fn field_statistics(self, field_statistics: bool) -> Self {
+        self.params(move |p| p.url_param("field_statistics", field_statistics))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> TermvectorsRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: Serialize,
+{
+    /**
+    Send a `TermvectorsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the term vectors for a document in an index called `myindex` with an id of `1`:
+
+    ```no_run
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document_termvectors::<Value>(index("myindex"), id(1))
+                         .ty("mytype")
+                         .send()?;
+
+    println!("found: {}", response.found());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<TermvectorsResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> TermvectorsRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: Serialize + Send + 'static,
+{
+    /**
+    Send a `TermvectorsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised term vectors response.
+
+    # Examples
+
+    Get the term vectors for a document in an index called `myindex` with an id of `1`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.document_termvectors::<Value>(index("myindex"), id(1))
+                       .ty("mytype")
+                       .send();
+
+    future.and_then(|response| {
+        println!("found: {}", response.found());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = TermvectorsResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = TermvectorsResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = TermvectorsResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_termvectors::<Value>(index("test-idx"), id("1"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/test-idx/value/1/_termvectors", req.url.as_ref());
+        assert_eq!(json!({}), serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_termvectors::<Value>(index("test-idx"), id("1"))
+            .ty("new-ty")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/test-idx/new-ty/1/_termvectors", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_artificial_doc() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_termvectors_artificial(index("test-idx"), json!({ "title": "A title" }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "doc": {
+                "title": "A title"
+            }
+        });
+
+        assert_eq!("/test-idx/value/_termvectors", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_options() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document_termvectors::<Value>(index("test-idx"), id("1"))
+            .fields(vec!["title", "body"])
+            .offsets(false)
+            .positions(false)
+            .payloads(false)
+            .term_statistics(true)
+            .field_statistics(false);
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+        assert_eq!(
+            "?field_statistics=false&fields=title%2Cbody&offsets=false&payloads=false&positions=false&term_statistics=true",
+            query.unwrap()
+        );
+    }
+}
@@ -648,13 +648,13 @@ pub struct Doc<TDocument> {
 }
 
 impl<TDocument> Doc<TDocument> {
-    fn empty() -> Self {
+    pub(crate) fn empty() -> Self {
         Doc {
             doc: DocInner { inner: None },
         }
     }
 
-    fn value(doc: TDocument) -> Self {
+    pub(crate) fn value(doc: TDocument) -> Self {
         Doc {
             doc: DocInner { inner: Some(doc) },
         }
@@ -680,7 +680,7 @@ where
     }
 }
 
-type DefaultParams = Map<String, Value>;
+pub(crate) type DefaultParams = Map<String, Value>;
 
 /** Update an indexed document using a script. */
 #[derive(Serialize)]
@@ -748,7 +748,7 @@ impl<TParams> ScriptBuilder<TParams> {
         }
     }
 
-    fn build(self) -> Script<TParams> {
+    pub(crate) fn build(self) -> Script<TParams> {
         Script {
             script: ScriptInner {
                 source: self.source,
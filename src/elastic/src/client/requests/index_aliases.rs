@@ -0,0 +1,511 @@
+/*!
+Builders for [update aliases requests][docs-aliases].
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+*/
+
+use futures::{Future, IntoFuture, Poll};
+use serde::ser::Serialize;
+use serde_json::{self, Value};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Name};
+use client::requests::endpoints::IndicesUpdateAliasesRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+
+/**
+An [update aliases request][docs-aliases] builder that can be configured before sending.
+
+Call [`Client.index_aliases`][Client.index_aliases] to get an `IndexAliasesRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_aliases]: ../../struct.Client.html#update-aliases-request
+*/
+pub type IndexAliasesRequestBuilder<TSender> = RequestBuilder<TSender, IndexAliasesRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexAliasesRequestInner {
+    actions: Vec<Value>,
+    err: Option<Error>,
+}
+
+/**
+# Update aliases request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexAliasesRequestBuilder`][IndexAliasesRequestBuilder] with this `Client` that can be configured before sending.
+
+    Actions are added to the request with the [`push`][IndexAliasesRequestBuilder.push] and [`extend`][IndexAliasesRequestBuilder.extend] methods.
+    All actions are applied atomically, so an alias can be moved from one index to another without a gap where it doesn't resolve.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Move an alias called `myalias` from an index called `myindex-v1` to an index called `myindex-v2`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_aliases()
+                         .push(alias_remove(index("myindex-v1"), name("myalias")))
+                         .push(alias_add(index("myindex-v2"), name("myalias")))
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexAliasesRequestBuilder]: requests/index_aliases/type.IndexAliasesRequestBuilder.html
+    [IndexAliasesRequestBuilder.push]: requests/index_aliases/type.IndexAliasesRequestBuilder.html#method.push
+    [IndexAliasesRequestBuilder.extend]: requests/index_aliases/type.IndexAliasesRequestBuilder.html#method.extend
+    [builder-methods]: requests/index_aliases/type.IndexAliasesRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_aliases/type.IndexAliasesRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_aliases/type.IndexAliasesRequestBuilder.html#send-asynchronously
+    */
+    pub fn index_aliases(&self) -> IndexAliasesRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            IndexAliasesRequestInner {
+                actions: Vec::new(),
+                err: None,
+            },
+        )
+    }
+}
+
+impl IndexAliasesRequestInner {
+    fn push<TFilter>(&mut self, action: AliasAction<TFilter>)
+    where
+        TFilter: Serialize,
+    {
+        if self.err.is_some() {
+            return;
+        }
+
+        match action.to_value() {
+            Ok(action) => self.actions.push(action),
+            Err(e) => self.err = Some(error::request(e)),
+        }
+    }
+
+    fn into_request(self) -> Result<IndicesUpdateAliasesRequest<'static, Vec<u8>>> {
+        if let Some(err) = self.err {
+            return Err(err);
+        }
+
+        #[derive(Serialize)]
+        struct UpdateAliasesBody {
+            actions: Vec<Value>,
+        }
+
+        let body = serde_json::to_vec(&UpdateAliasesBody { actions: self.actions }).map_err(error::request)?;
+
+        Ok(IndicesUpdateAliasesRequest::new(body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexAliasesRequestBuilder` before sending it.
+*/
+impl<TSender> IndexAliasesRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Add an action to the update aliases request.
+
+    If the action fails to serialise then the error will be returned when the request is sent.
+    */
+    pub fn push<TFilter>(mut self, action: AliasAction<TFilter>) -> Self
+    where
+        TFilter: Serialize,
+    {
+        self.inner.push(action);
+        self
+    }
+
+    /** Add a sequence of actions to the update aliases request. */
+    pub fn extend<I, TFilter>(mut self, actions: I) -> Self
+    where
+        I: IntoIterator<Item = AliasAction<TFilter>>,
+        TFilter: Serialize,
+    {
+        for action in actions {
+            self.inner.push(action);
+        }
+
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexAliasesRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexAliasesRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Add an alias called `myalias` to an index called `myindex`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_aliases()
+                         .push(alias_add(index("myindex"), name("myalias")))
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexAliasesRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexAliasesRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Add an alias called `myalias` to an index called `myindex`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.index_aliases()
+                       .push(alias_add(index("myindex"), name("myalias")))
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+/**
+A single action in an update aliases request.
+
+Create actions using the [`alias_add`][alias_add], [`alias_remove`][alias_remove] and [`alias_remove_index`][alias_remove_index] functions.
+
+[alias_add]: fn.alias_add.html
+[alias_remove]: fn.alias_remove.html
+[alias_remove_index]: fn.alias_remove_index.html
+*/
+pub struct AliasAction<TFilter> {
+    action: AliasActionKind,
+    index: Index<'static>,
+    alias: Option<Name<'static>>,
+    filter: Option<TFilter>,
+    routing: Option<String>,
+    index_routing: Option<String>,
+    search_routing: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+enum AliasActionKind {
+    Add,
+    Remove,
+    RemoveIndex,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum AliasActionBody<'a, TFilter: 'a> {
+    Add(AliasActionInner<'a, TFilter>),
+    Remove(AliasActionInner<'a, TFilter>),
+    RemoveIndex(AliasActionInner<'a, TFilter>),
+}
+
+#[derive(Serialize)]
+struct AliasActionInner<'a, TFilter: 'a> {
+    index: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")] alias: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")] filter: Option<&'a TFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")] routing: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")] index_routing: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")] search_routing: Option<&'a str>,
+}
+
+/** Add an alias to an index. */
+pub fn alias_add(index: Index<'static>, alias: Name<'static>) -> AliasAction<Value> {
+    AliasAction::new(AliasActionKind::Add, index, Some(alias))
+}
+
+/** Remove an alias from an index. */
+pub fn alias_remove(index: Index<'static>, alias: Name<'static>) -> AliasAction<Value> {
+    AliasAction::new(AliasActionKind::Remove, index, Some(alias))
+}
+
+/**
+Delete an index.
+
+Removing an index in the same request as adding an alias with its name means there's no point where the name doesn't resolve.
+*/
+pub fn alias_remove_index(index: Index<'static>) -> AliasAction<Value> {
+    AliasAction::new(AliasActionKind::RemoveIndex, index, None)
+}
+
+impl AliasAction<Value> {
+    fn new(action: AliasActionKind, index: Index<'static>, alias: Option<Name<'static>>) -> Self {
+        AliasAction {
+            action: action,
+            index: index,
+            alias: alias,
+            filter: None,
+            routing: None,
+            index_routing: None,
+            search_routing: None,
+        }
+    }
+}
+
+impl<TFilter> AliasAction<TFilter> {
+    /**
+    Set a filter that's applied to searches through the alias.
+
+    The filter is only used when adding an alias.
+    */
+    pub fn filter<TNewFilter>(self, filter: TNewFilter) -> AliasAction<TNewFilter>
+    where
+        TNewFilter: Serialize,
+    {
+        AliasAction {
+            action: self.action,
+            index: self.index,
+            alias: self.alias,
+            filter: Some(filter),
+            routing: self.routing,
+            index_routing: self.index_routing,
+            search_routing: self.search_routing,
+        }
+    }
+
+    /**
+    Set the routing used when indexing and searching through the alias.
+
+    The routing is only used when adding an alias.
+    */
+    pub fn routing<TRouting>(mut self, routing: TRouting) -> Self
+    where
+        TRouting: ToString,
+    {
+        self.routing = Some(routing.to_string());
+        self
+    }
+
+    /**
+    Set the routing used when indexing through the alias.
+
+    The routing is only used when adding an alias.
+    */
+    pub fn index_routing<TRouting>(mut self, routing: TRouting) -> Self
+    where
+        TRouting: ToString,
+    {
+        self.index_routing = Some(routing.to_string());
+        self
+    }
+
+    /**
+    Set the routing used when searching through the alias.
+
+    The routing is only used when adding an alias.
+    */
+    pub fn search_routing<TRouting>(mut self, routing: TRouting) -> Self
+    where
+        TRouting: ToString,
+    {
+        self.search_routing = Some(routing.to_string());
+        self
+    }
+}
+
+impl<TFilter> AliasAction<TFilter>
+where
+    TFilter: Serialize,
+{
+    fn to_value(&self) -> serde_json::Result<Value> {
+        let inner = AliasActionInner {
+            index: &self.index,
+            alias: self.alias.as_ref().map(|alias| &**alias),
+            filter: self.filter.as_ref(),
+            routing: self.routing.as_ref().map(|routing| routing.as_ref()),
+            index_routing: self.index_routing.as_ref().map(|routing| routing.as_ref()),
+            search_routing: self.search_routing.as_ref().map(|routing| routing.as_ref()),
+        };
+
+        let body = match self.action {
+            AliasActionKind::Add => AliasActionBody::Add(inner),
+            AliasActionKind::Remove => AliasActionBody::Remove(inner),
+            AliasActionKind::RemoveIndex => AliasActionBody::RemoveIndex(inner),
+        };
+
+        serde_json::to_value(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index_aliases().inner.into_request().unwrap();
+
+        assert_eq!("/_aliases", req.url.as_ref());
+        assert_eq!(json!({ "actions": [] }), serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_actions() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_aliases()
+            .push(alias_remove(index("old-idx"), name("alias")))
+            .push(alias_add(index("new-idx"), name("alias")))
+            .push(alias_remove_index(index("tmp-idx")))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "actions": [
+                { "remove": { "index": "old-idx", "alias": "alias" } },
+                { "add": { "index": "new-idx", "alias": "alias" } },
+                { "remove_index": { "index": "tmp-idx" } }
+            ]
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_filter_routing() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_aliases()
+            .push(
+                alias_add(index("new-idx"), name("alias"))
+                    .filter(json!({ "term": { "user": "kimchy" } }))
+                    .routing(1)
+                    .search_routing("1,2"),
+            )
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "actions": [
+                {
+                    "add": {
+                        "index": "new-idx",
+                        "alias": "alias",
+                        "filter": { "term": { "user": "kimchy" } },
+                        "routing": "1",
+                        "search_routing": "1,2"
+                    }
+                }
+            ]
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
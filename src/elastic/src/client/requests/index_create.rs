@@ -77,18 +77,18 @@ where
     # #[macro_use] extern crate serde_json;
     # extern crate elastic;
     # use elastic::prelude::*;
+    # use elastic::types::settings::IndexSettings;
     # #[derive(Serialize, Deserialize, ElasticType)]
     # struct MyType { }
     # fn main() { run().unwrap() }
     # fn run() -> Result<(), Box<::std::error::Error>> {
     # let client = SyncClientBuilder::new().build()?;
+    let settings = IndexSettings::new()
+        .number_of_shards(3)
+        .number_of_replicas(2);
+
     let body = json!({
-        "settings": {
-            "index": {
-                "number_of_shards": 3,
-                "number_of_replicas": 2
-            }
-        },
+        "settings": settings,
         "mappings": {
             MyType::name(): MyType::index_mapping()
         }
@@ -104,6 +104,7 @@ where
     ```
 
     For more details on document types and mapping, see the [`types`][types-mod] module.
+    For more details on index settings and analysis, see the [`settings`][settings-mod] module.
 
     [IndexCreateRequestBuilder]: requests/index_create/type.IndexCreateRequestBuilder.html
     [builder-methods]: requests/index_create/type.IndexCreateRequestBuilder.html#builder-methods
@@ -111,6 +112,7 @@ where
     [send-async]: requests/index_create/type.IndexCreateRequestBuilder.html#send-asynchronously
     [types-mod]: ../types/index.html
     [documents-mod]: ../types/document/index.html
+    [settings-mod]: ../types/settings/index.html
     */
     pub fn index_create(&self, index: Index<'static>) -> IndexCreateRequestBuilder<TSender, DefaultBody> {
         RequestBuilder::new(
@@ -278,6 +280,7 @@ impl Future for Pending {
 #[cfg(test)]
 mod tests {
     use prelude::*;
+    use types::settings::IndexSettings;
 
     #[test]
     fn default_request() {
@@ -300,4 +303,21 @@ mod tests {
 
         assert_eq!("{}", req.body);
     }
+
+    #[test]
+    fn specify_settings_body() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let body = json!({
+            "settings": IndexSettings::new().number_of_shards(3)
+        });
+
+        let req = client
+            .index_create(index("testindex"))
+            .body(body.to_string())
+            .inner
+            .into_request();
+
+        assert_eq!(r#"{"settings":{"number_of_shards":3}}"#, req.body);
+    }
 }
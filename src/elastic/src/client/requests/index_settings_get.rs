@@ -0,0 +1,217 @@
+/*!
+Builders for [get index settings requests][docs-get-settings].
+
+[docs-get-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Index;
+use client::requests::endpoints::IndicesGetSettingsRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::GetIndexSettingsResponse;
+use types::settings::IndexSettings;
+
+/**
+A [get index settings request][docs-get-settings] builder that can be configured before sending.
+
+Call [`Client.index_settings_get`][Client.index_settings_get] to get an `IndexSettingsGetRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-get-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_settings_get]: ../../struct.Client.html#get-index-settings-request
+*/
+pub type IndexSettingsGetRequestBuilder<TSender> = RequestBuilder<TSender, IndexSettingsGetRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexSettingsGetRequestInner {
+    index: Index<'static>,
+}
+
+/**
+# Get index settings request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexSettingsGetRequestBuilder`][IndexSettingsGetRequestBuilder] with this `Client` that can be configured before sending.
+
+    The settings for each index are deserialised as [`IndexSettings`][IndexSettings].
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the settings for an index called `myindex`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_settings_get(index("myindex")).send()?;
+
+    if let Some(settings) = response.get("myindex") {
+        println!("myindex has {:?} shards", settings.get_number_of_shards());
+
+        for name in settings.get_analysis().analyzers().keys() {
+            println!("myindex has analyzer {}", name);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IndexSettingsGetRequestBuilder]: requests/index_settings_get/type.IndexSettingsGetRequestBuilder.html
+    [send-sync]: requests/index_settings_get/type.IndexSettingsGetRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_settings_get/type.IndexSettingsGetRequestBuilder.html#send-asynchronously
+    [IndexSettings]: ../types/settings/struct.IndexSettings.html
+    */
+    pub fn index_settings_get(&self, index: Index<'static>) -> IndexSettingsGetRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, IndexSettingsGetRequestInner { index: index })
+    }
+}
+
+impl IndexSettingsGetRequestInner {
+    fn into_request(self) -> IndicesGetSettingsRequest<'static> {
+        IndicesGetSettingsRequest::for_index(self.index)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexSettingsGetRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexSettingsGetRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the settings for an index called `myindex`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_settings_get(index("myindex")).send()?;
+
+    if let Some(settings) = response.get("myindex") {
+        println!("myindex has {:?} replicas", settings.get_number_of_replicas());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<GetIndexSettingsResponse<IndexSettings>> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexSettingsGetRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexSettingsGetRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get index settings response.
+
+    # Examples
+
+    Get the settings for an index called `myindex`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.index_settings_get(index("myindex")).send();
+
+    future.and_then(|response| {
+        if let Some(settings) = response.get("myindex") {
+            println!("myindex has {:?} replicas", settings.get_number_of_replicas());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = GetIndexSettingsResponse<IndexSettings>, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = GetIndexSettingsResponse<IndexSettings>, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = GetIndexSettingsResponse<IndexSettings>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index_settings_get(index("testindex")).inner.into_request();
+
+        assert_eq!("/testindex/_settings", req.url.as_ref());
+    }
+}
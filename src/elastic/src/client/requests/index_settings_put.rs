@@ -0,0 +1,241 @@
+/*!
+Builders for [update index settings requests][docs-update-settings].
+
+[docs-update-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-update-settings.html
+*/
+
+use serde_json;
+use futures::{Future, IntoFuture, Poll};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Index;
+use client::requests::endpoints::IndicesPutSettingsRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+use types::settings::IndexSettings;
+
+/**
+An [update index settings request][docs-update-settings] builder that can be configured before sending.
+
+Call [`Client.index_settings_put`][Client.index_settings_put] to get an `IndexSettingsPutRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-update-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-update-settings.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_settings_put]: ../../struct.Client.html#update-index-settings-request
+*/
+pub type IndexSettingsPutRequestBuilder<TSender> = RequestBuilder<TSender, IndexSettingsPutRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexSettingsPutRequestInner {
+    index: Index<'static>,
+    settings: IndexSettings,
+}
+
+/**
+# Update index settings request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexSettingsPutRequestBuilder`][IndexSettingsPutRequestBuilder] with this `Client` that can be configured before sending.
+
+    Only the settings that are set in the given [`IndexSettings`][IndexSettings] are changed.
+    Some settings, like the number of shards, can't be changed after an index is created, and analysis components can only be changed while the index is closed.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Change the number of replicas for an index called `myindex`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # use elastic::types::settings::IndexSettings;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let settings = IndexSettings::new().number_of_replicas(2);
+
+    let response = client.index_settings_put(index("myindex"), settings).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexSettingsPutRequestBuilder]: requests/index_settings_put/type.IndexSettingsPutRequestBuilder.html
+    [send-sync]: requests/index_settings_put/type.IndexSettingsPutRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_settings_put/type.IndexSettingsPutRequestBuilder.html#send-asynchronously
+    [IndexSettings]: ../types/settings/struct.IndexSettings.html
+    */
+    pub fn index_settings_put(&self, index: Index<'static>, settings: IndexSettings) -> IndexSettingsPutRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            IndexSettingsPutRequestInner {
+                index: index,
+                settings: settings,
+            },
+        )
+    }
+}
+
+impl IndexSettingsPutRequestInner {
+    fn into_request(self) -> Result<IndicesPutSettingsRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&self.settings).map_err(error::request)?;
+
+        Ok(IndicesPutSettingsRequest::for_index(self.index, body))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexSettingsPutRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexSettingsPutRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Change the refresh interval for an index called `myindex`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # use elastic::types::settings::IndexSettings;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let settings = IndexSettings::new().refresh_interval("30s");
+
+    let response = client.index_settings_put(index("myindex"), settings).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexSettingsPutRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexSettingsPutRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Change the refresh interval for an index called `myindex`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # use elastic::types::settings::IndexSettings;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let settings = IndexSettings::new().refresh_interval("30s");
+
+    let future = client.index_settings_put(index("myindex"), settings).send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+    use types::settings::IndexSettings;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_settings_put(index("testindex"), IndexSettings::new().number_of_replicas(2))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "number_of_replicas": 2
+        });
+
+        assert_eq!("/testindex/_settings", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
@@ -0,0 +1,203 @@
+/*!
+Builders for [delete index template requests][docs-templates].
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Name;
+use client::requests::endpoints::IndicesDeleteTemplateRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+
+/**
+A [delete index template request][docs-templates] builder that can be configured before sending.
+
+Call [`Client.index_template_delete`][Client.index_template_delete] to get an `IndexTemplateDeleteRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_template_delete]: ../../struct.Client.html#delete-index-template-request
+*/
+pub type IndexTemplateDeleteRequestBuilder<TSender> = RequestBuilder<TSender, IndexTemplateDeleteRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexTemplateDeleteRequestInner {
+    name: Name<'static>,
+}
+
+/**
+# Delete index template request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexTemplateDeleteRequestBuilder`][IndexTemplateDeleteRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Delete an index template called `logs`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template_delete(name("logs")).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexTemplateDeleteRequestBuilder]: requests/index_template_delete/type.IndexTemplateDeleteRequestBuilder.html
+    [send-sync]: requests/index_template_delete/type.IndexTemplateDeleteRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_template_delete/type.IndexTemplateDeleteRequestBuilder.html#send-asynchronously
+    */
+    pub fn index_template_delete(&self, name: Name<'static>) -> IndexTemplateDeleteRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, IndexTemplateDeleteRequestInner { name: name })
+    }
+}
+
+impl IndexTemplateDeleteRequestInner {
+    fn into_request(self) -> IndicesDeleteTemplateRequest<'static> {
+        IndicesDeleteTemplateRequest::for_name(self.name)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexTemplateDeleteRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexTemplateDeleteRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Delete an index template called `logs`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template_delete(name("logs")).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexTemplateDeleteRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexTemplateDeleteRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Delete an index template called `logs`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.index_template_delete(name("logs")).send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index_template_delete(name("test-template")).inner.into_request();
+
+        assert_eq!("/_template/test-template", req.url.as_ref());
+    }
+}
@@ -0,0 +1,203 @@
+/*!
+Builders for [index template exists requests][docs-templates].
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Name;
+use client::requests::endpoints::IndicesExistsTemplateRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::IndexTemplateExistsResponse;
+
+/**
+An [index template exists request][docs-templates] builder that can be configured before sending.
+
+Call [`Client.index_template_exists`][Client.index_template_exists] to get an `IndexTemplateExistsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_template_exists]: ../../struct.Client.html#index-template-exists-request
+*/
+pub type IndexTemplateExistsRequestBuilder<TSender> = RequestBuilder<TSender, IndexTemplateExistsRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexTemplateExistsRequestInner {
+    name: Name<'static>,
+}
+
+/**
+# Index template exists request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexTemplateExistsRequestBuilder`][IndexTemplateExistsRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether an index template called `logs` exists:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template_exists(name("logs")).send()?;
+
+    assert!(response.exists());
+    # Ok(())
+    # }
+    ```
+
+    [IndexTemplateExistsRequestBuilder]: requests/index_template_exists/type.IndexTemplateExistsRequestBuilder.html
+    [send-sync]: requests/index_template_exists/type.IndexTemplateExistsRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_template_exists/type.IndexTemplateExistsRequestBuilder.html#send-asynchronously
+    */
+    pub fn index_template_exists(&self, name: Name<'static>) -> IndexTemplateExistsRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, IndexTemplateExistsRequestInner { name: name })
+    }
+}
+
+impl IndexTemplateExistsRequestInner {
+    fn into_request(self) -> IndicesExistsTemplateRequest<'static> {
+        IndicesExistsTemplateRequest::for_name(self.name)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexTemplateExistsRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexTemplateExistsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Check whether an index template called `logs` exists:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template_exists(name("logs")).send()?;
+
+    assert!(response.exists());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndexTemplateExistsResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexTemplateExistsRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexTemplateExistsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised index template exists response.
+
+    # Examples
+
+    Check whether an index template called `logs` exists:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.index_template_exists(name("logs")).send();
+
+    future.and_then(|response| {
+        assert!(response.exists());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = IndexTemplateExistsResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = IndexTemplateExistsResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = IndexTemplateExistsResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index_template_exists(name("test-template")).inner.into_request();
+
+        assert_eq!("/_template/test-template", req.url.as_ref());
+    }
+}
@@ -0,0 +1,254 @@
+/*!
+Builders for [get index template requests][docs-templates].
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Name;
+use client::requests::endpoints::IndicesGetTemplateRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::GetIndexTemplatesResponse;
+
+/**
+A [get index template request][docs-templates] builder that can be configured before sending.
+
+Call [`Client.index_template_get`][Client.index_template_get] to get an `IndexTemplateGetRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_template_get]: ../../struct.Client.html#get-index-template-request
+*/
+pub type IndexTemplateGetRequestBuilder<TSender> = RequestBuilder<TSender, IndexTemplateGetRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexTemplateGetRequestInner {
+    name: Option<Name<'static>>,
+}
+
+/**
+# Get index template request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexTemplateGetRequestBuilder`][IndexTemplateGetRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get all index templates:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template_get().send()?;
+
+    for (name, template) in response.iter() {
+        println!("{} applies to indices matching {}", name, template.template());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IndexTemplateGetRequestBuilder]: requests/index_template_get/type.IndexTemplateGetRequestBuilder.html
+    [builder-methods]: requests/index_template_get/type.IndexTemplateGetRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_template_get/type.IndexTemplateGetRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_template_get/type.IndexTemplateGetRequestBuilder.html#send-asynchronously
+    */
+    pub fn index_template_get(&self) -> IndexTemplateGetRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, IndexTemplateGetRequestInner { name: None })
+    }
+}
+
+impl IndexTemplateGetRequestInner {
+    fn into_request(self) -> IndicesGetTemplateRequest<'static> {
+        match self.name {
+            Some(name) => IndicesGetTemplateRequest::for_name(name),
+            None => IndicesGetTemplateRequest::new(),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexTemplateGetRequestBuilder` before sending it.
+*/
+impl<TSender> IndexTemplateGetRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the names of the templates to get.
+
+    If no name is specified then all templates will be returned.
+    */
+    pub fn name<I>(mut self, name: I) -> Self
+    where
+        I: Into<Name<'static>>,
+    {
+        self.inner.name = Some(name.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexTemplateGetRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexTemplateGetRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get an index template called `logs`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template_get()
+                         .name("logs")
+                         .send()?;
+
+    if let Some(template) = response.get("logs") {
+        println!("logs has order {}", template.order());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<GetIndexTemplatesResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexTemplateGetRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexTemplateGetRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get index template response.
+
+    # Examples
+
+    Get an index template called `logs`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.index_template_get()
+                       .name("logs")
+                       .send();
+
+    future.and_then(|response| {
+        if let Some(template) = response.get("logs") {
+            println!("logs has order {}", template.order());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = GetIndexTemplatesResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = GetIndexTemplatesResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = GetIndexTemplatesResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index_template_get().inner.into_request();
+
+        assert_eq!("/_template", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_name() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template_get()
+            .name("test-template")
+            .inner
+            .into_request();
+
+        assert_eq!("/_template/test-template", req.url.as_ref());
+    }
+}
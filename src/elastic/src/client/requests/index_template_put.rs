@@ -0,0 +1,390 @@
+/*!
+Builders for [put index template requests][docs-templates].
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+*/
+
+use futures::{Future, IntoFuture, Poll};
+use serde::ser::Serialize;
+use serde_json::{self, Map, Value};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Name;
+use client::requests::endpoints::IndicesPutTemplateRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+use types::document::DocumentType;
+
+/**
+A [put index template request][docs-templates] builder that can be configured before sending.
+
+Call [`Client.index_template_put`][Client.index_template_put] to get an `IndexTemplatePutRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_template_put]: ../../struct.Client.html#put-index-template-request
+*/
+pub type IndexTemplatePutRequestBuilder<TSender> = RequestBuilder<TSender, IndexTemplatePutRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexTemplatePutRequestInner {
+    name: Name<'static>,
+    body: Map<String, Value>,
+    mappings: Map<String, Value>,
+    err: Option<Error>,
+}
+
+/**
+# Put index template request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexTemplatePutRequestBuilder`][IndexTemplatePutRequestBuilder] with this `Client` that can be configured before sending.
+
+    The template will be applied to any new index whose name matches the `template` pattern.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Put a template called `logs` with the mapping for a [`DocumentType`][documents-mod] called `MyType` for indices matching `logs-*`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate serde_json;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template_put(name("logs"), "logs-*")
+                         .order(1)
+                         .settings(json!({ "number_of_shards": 1 }))
+                         .document_mapping::<MyType>()
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    For more details on document types and mapping, see the [`types`][types-mod] module.
+
+    [IndexTemplatePutRequestBuilder]: requests/index_template_put/type.IndexTemplatePutRequestBuilder.html
+    [builder-methods]: requests/index_template_put/type.IndexTemplatePutRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_template_put/type.IndexTemplatePutRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_template_put/type.IndexTemplatePutRequestBuilder.html#send-asynchronously
+    [types-mod]: ../types/index.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn index_template_put(&self, name: Name<'static>, template: &str) -> IndexTemplatePutRequestBuilder<TSender> {
+        let mut body = Map::new();
+        body.insert("template".to_owned(), Value::String(template.to_owned()));
+
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            IndexTemplatePutRequestInner {
+                name: name,
+                body: body,
+                mappings: Map::new(),
+                err: None,
+            },
+        )
+    }
+}
+
+impl IndexTemplatePutRequestInner {
+    fn set_body<TValue>(&mut self, key: &str, value: TValue)
+    where
+        TValue: Serialize,
+    {
+        if self.err.is_some() {
+            return;
+        }
+
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.body.insert(key.to_owned(), value);
+            }
+            Err(e) => self.err = Some(error::request(e)),
+        }
+    }
+
+    fn set_mapping<TDocument>(&mut self)
+    where
+        TDocument: DocumentType,
+    {
+        if self.err.is_some() {
+            return;
+        }
+
+        match serde_json::to_value(TDocument::index_mapping()) {
+            Ok(mapping) => {
+                self.mappings.insert(TDocument::name().to_owned(), mapping);
+            }
+            Err(e) => self.err = Some(error::request(e)),
+        }
+    }
+
+    fn into_request(self) -> Result<IndicesPutTemplateRequest<'static, Vec<u8>>> {
+        if let Some(err) = self.err {
+            return Err(err);
+        }
+
+        let mut body = self.body;
+
+        if !self.mappings.is_empty() {
+            body.insert("mappings".to_owned(), Value::Object(self.mappings));
+        }
+
+        let body = serde_json::to_vec(&body).map_err(error::request)?;
+
+        Ok(IndicesPutTemplateRequest::for_name(self.name, body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexTemplatePutRequestBuilder` before sending it.
+*/
+impl<TSender> IndexTemplatePutRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the order for the template.
+
+    When multiple templates match an index, templates with a higher order are merged over templates with a lower order.
+    */
+    pub fn order(mut self, order: i32) -> Self {
+        self.inner.set_body("order", order);
+        self
+    }
+
+    /**
+    Set the index settings applied by the template.
+
+    If the settings fail to serialise then the error will be returned when the request is sent.
+    */
+    pub fn settings<TSettings>(mut self, settings: TSettings) -> Self
+    where
+        TSettings: Serialize,
+    {
+        self.inner.set_body("settings", settings);
+        self
+    }
+
+    /**
+    Add the mapping for a [`DocumentType`][documents-mod] to the template.
+
+    The mapping is added under the name of the document type.
+    This method can be called more than once to add mappings for several document types.
+
+    [documents-mod]: ../../../types/document/index.html
+    */
+    pub fn document_mapping<TDocument>(mut self) -> Self
+    where
+        TDocument: DocumentType,
+    {
+        self.inner.set_mapping::<TDocument>();
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexTemplatePutRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexTemplatePutRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Put a template called `logs` for indices matching `logs-*`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template_put(name("logs"), "logs-*").send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexTemplatePutRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexTemplatePutRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Put a template called `logs` for indices matching `logs-*`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.index_template_put(name("logs"), "logs-*").send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template_put(name("test-template"), "test-*")
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "template": "test-*"
+        });
+
+        assert_eq!("/_template/test-template", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_order_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template_put(name("test-template"), "test-*")
+            .order(2)
+            .settings(json!({ "number_of_shards": 1 }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "template": "test-*",
+            "order": 2,
+            "settings": {
+                "number_of_shards": 1
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_document_mapping() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template_put(name("test-template"), "test-*")
+            .document_mapping::<Value>()
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "template": "test-*",
+            "mappings": {
+                "value": {
+                    "properties": {}
+                }
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
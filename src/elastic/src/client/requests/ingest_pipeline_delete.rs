@@ -0,0 +1,203 @@
+/*!
+Builders for [delete pipeline requests][docs-delete-pipeline].
+
+[docs-delete-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/delete-pipeline-api.html
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Id;
+use client::requests::endpoints::IngestDeletePipelineRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+
+/**
+A [delete pipeline request][docs-delete-pipeline] builder that can be configured before sending.
+
+Call [`Client.ingest_pipeline_delete`][Client.ingest_pipeline_delete] to get an `IngestPipelineDeleteRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-delete-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/delete-pipeline-api.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.ingest_pipeline_delete]: ../../struct.Client.html#delete-pipeline-request
+*/
+pub type IngestPipelineDeleteRequestBuilder<TSender> = RequestBuilder<TSender, IngestPipelineDeleteRequestInner>;
+
+#[doc(hidden)]
+pub struct IngestPipelineDeleteRequestInner {
+    id: Id<'static>,
+}
+
+/**
+# Delete pipeline request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IngestPipelineDeleteRequestBuilder`][IngestPipelineDeleteRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Delete a pipeline called `logs`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.ingest_pipeline_delete(id("logs")).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IngestPipelineDeleteRequestBuilder]: requests/ingest_pipeline_delete/type.IngestPipelineDeleteRequestBuilder.html
+    [send-sync]: requests/ingest_pipeline_delete/type.IngestPipelineDeleteRequestBuilder.html#send-synchronously
+    [send-async]: requests/ingest_pipeline_delete/type.IngestPipelineDeleteRequestBuilder.html#send-asynchronously
+    */
+    pub fn ingest_pipeline_delete(&self, id: Id<'static>) -> IngestPipelineDeleteRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, IngestPipelineDeleteRequestInner { id: id })
+    }
+}
+
+impl IngestPipelineDeleteRequestInner {
+    fn into_request(self) -> IngestDeletePipelineRequest<'static> {
+        IngestDeletePipelineRequest::for_id(self.id)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IngestPipelineDeleteRequestBuilder<SyncSender> {
+    /**
+    Send an `IngestPipelineDeleteRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Delete a pipeline called `logs`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.ingest_pipeline_delete(id("logs")).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IngestPipelineDeleteRequestBuilder<AsyncSender> {
+    /**
+    Send an `IngestPipelineDeleteRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Delete a pipeline called `logs`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.ingest_pipeline_delete(id("logs")).send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.ingest_pipeline_delete(id("logs")).inner.into_request();
+
+        assert_eq!("/_ingest/pipeline/logs", req.url.as_ref());
+    }
+}
@@ -0,0 +1,255 @@
+/*!
+Builders for [get pipeline requests][docs-get-pipeline].
+
+[docs-get-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/get-pipeline-api.html
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Id;
+use client::requests::endpoints::IngestGetPipelineRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::GetPipelinesResponse;
+use types::ingest::Pipeline;
+
+/**
+A [get pipeline request][docs-get-pipeline] builder that can be configured before sending.
+
+Call [`Client.ingest_pipeline_get`][Client.ingest_pipeline_get] to get an `IngestPipelineGetRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-get-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/get-pipeline-api.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.ingest_pipeline_get]: ../../struct.Client.html#get-pipeline-request
+*/
+pub type IngestPipelineGetRequestBuilder<TSender> = RequestBuilder<TSender, IngestPipelineGetRequestInner>;
+
+#[doc(hidden)]
+pub struct IngestPipelineGetRequestInner {
+    id: Option<Id<'static>>,
+}
+
+/**
+# Get pipeline request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IngestPipelineGetRequestBuilder`][IngestPipelineGetRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get all pipelines:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.ingest_pipeline_get().send()?;
+
+    for (id, pipeline) in response.iter() {
+        println!("{} has {} processors", id, pipeline.get_processors().len());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IngestPipelineGetRequestBuilder]: requests/ingest_pipeline_get/type.IngestPipelineGetRequestBuilder.html
+    [builder-methods]: requests/ingest_pipeline_get/type.IngestPipelineGetRequestBuilder.html#builder-methods
+    [send-sync]: requests/ingest_pipeline_get/type.IngestPipelineGetRequestBuilder.html#send-synchronously
+    [send-async]: requests/ingest_pipeline_get/type.IngestPipelineGetRequestBuilder.html#send-asynchronously
+    */
+    pub fn ingest_pipeline_get(&self) -> IngestPipelineGetRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, IngestPipelineGetRequestInner { id: None })
+    }
+}
+
+impl IngestPipelineGetRequestInner {
+    fn into_request(self) -> IngestGetPipelineRequest<'static> {
+        match self.id {
+            Some(id) => IngestGetPipelineRequest::for_id(id),
+            None => IngestGetPipelineRequest::new(),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IngestPipelineGetRequestBuilder` before sending it.
+*/
+impl<TSender> IngestPipelineGetRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the ids of the pipelines to get.
+
+    If no id is specified then all pipelines will be returned.
+    */
+    pub fn id<I>(mut self, id: I) -> Self
+    where
+        I: Into<Id<'static>>,
+    {
+        self.inner.id = Some(id.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IngestPipelineGetRequestBuilder<SyncSender> {
+    /**
+    Send an `IngestPipelineGetRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get a pipeline called `logs`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.ingest_pipeline_get()
+                         .id("logs")
+                         .send()?;
+
+    if let Some(pipeline) = response.get("logs") {
+        println!("{:?}", pipeline.get_description());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<GetPipelinesResponse<Pipeline>> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IngestPipelineGetRequestBuilder<AsyncSender> {
+    /**
+    Send an `IngestPipelineGetRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get pipeline response.
+
+    # Examples
+
+    Get a pipeline called `logs`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.ingest_pipeline_get()
+                       .id("logs")
+                       .send();
+
+    future.and_then(|response| {
+        if let Some(pipeline) = response.get("logs") {
+            println!("{:?}", pipeline.get_description());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = GetPipelinesResponse<Pipeline>, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = GetPipelinesResponse<Pipeline>, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = GetPipelinesResponse<Pipeline>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.ingest_pipeline_get().inner.into_request();
+
+        assert_eq!("/_ingest/pipeline", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_id() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .ingest_pipeline_get()
+            .id("logs")
+            .inner
+            .into_request();
+
+        assert_eq!("/_ingest/pipeline/logs", req.url.as_ref());
+    }
+}
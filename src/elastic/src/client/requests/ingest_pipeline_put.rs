@@ -0,0 +1,300 @@
+/*!
+Builders for [put pipeline requests][docs-put-pipeline].
+
+[docs-put-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/put-pipeline-api.html
+*/
+
+use serde_json;
+use futures::{Future, IntoFuture, Poll};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Id;
+use client::requests::endpoints::IngestPutPipelineRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+use types::ingest::Pipeline;
+
+/**
+A [put pipeline request][docs-put-pipeline] builder that can be configured before sending.
+
+Call [`Client.ingest_pipeline_put`][Client.ingest_pipeline_put] to get an `IngestPipelinePutRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-put-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/put-pipeline-api.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.ingest_pipeline_put]: ../../struct.Client.html#put-pipeline-request
+*/
+pub type IngestPipelinePutRequestBuilder<TSender> = RequestBuilder<TSender, IngestPipelinePutRequestInner>;
+
+#[doc(hidden)]
+pub struct IngestPipelinePutRequestInner {
+    id: Id<'static>,
+    pipeline: Pipeline,
+}
+
+/**
+# Put pipeline request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IngestPipelinePutRequestBuilder`][IngestPipelinePutRequestBuilder] with this `Client` that can be configured before sending.
+
+    If a pipeline with the same id already exists then it's replaced.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Put a pipeline called `logs` that parses log lines:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # use elastic::types::ingest::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let pipeline = Pipeline::new()
+        .description("parse apache logs")
+        .processor(Processor::grok("message", vec!["%{COMMONAPACHELOG}"]))
+        .processor(Processor::remove("message"));
+
+    let response = client.ingest_pipeline_put(id("logs"), pipeline)
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IngestPipelinePutRequestBuilder]: requests/ingest_pipeline_put/type.IngestPipelinePutRequestBuilder.html
+    [builder-methods]: requests/ingest_pipeline_put/type.IngestPipelinePutRequestBuilder.html#builder-methods
+    [send-sync]: requests/ingest_pipeline_put/type.IngestPipelinePutRequestBuilder.html#send-synchronously
+    [send-async]: requests/ingest_pipeline_put/type.IngestPipelinePutRequestBuilder.html#send-asynchronously
+    */
+    pub fn ingest_pipeline_put(&self, id: Id<'static>, pipeline: Pipeline) -> IngestPipelinePutRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            IngestPipelinePutRequestInner {
+                id: id,
+                pipeline: pipeline,
+            },
+        )
+    }
+}
+
+impl IngestPipelinePutRequestInner {
+    fn into_request(self) -> Result<IngestPutPipelineRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&self.pipeline).map_err(error::request)?;
+
+        Ok(IngestPutPipelineRequest::for_id(self.id, body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IngestPipelinePutRequestBuilder` before sending it.
+*/
+impl<TSender> IngestPipelinePutRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /** Set the pipeline to put, replacing the pipeline the builder was created with. */
+    pub fn pipeline(mut self, pipeline: Pipeline) -> Self {
+        self.inner.pipeline = pipeline;
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IngestPipelinePutRequestBuilder<SyncSender> {
+    /**
+    Send an `IngestPipelinePutRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Put a pipeline called `status` that sets a default status:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # use elastic::types::ingest::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let pipeline = Pipeline::new().processor(Processor::set("status", "new"));
+
+    let response = client.ingest_pipeline_put(id("status"), pipeline)
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IngestPipelinePutRequestBuilder<AsyncSender> {
+    /**
+    Send an `IngestPipelinePutRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Put a pipeline called `status` that sets a default status:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # use elastic::types::ingest::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let pipeline = Pipeline::new().processor(Processor::set("status", "new"));
+
+    let future = client.ingest_pipeline_put(id("status"), pipeline)
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+    use types::ingest::prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let pipeline = Pipeline::new()
+            .description("set status")
+            .processor(Processor::set("status", "new").tag("set_status"));
+
+        let req = client
+            .ingest_pipeline_put(id("status"), pipeline)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "description": "set status",
+            "processors": [
+                {
+                    "set": {
+                        "field": "status",
+                        "value": "new",
+                        "tag": "set_status"
+                    }
+                }
+            ]
+        });
+
+        assert_eq!("/_ingest/pipeline/status", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_pipeline() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .ingest_pipeline_put(id("status"), Pipeline::new())
+            .pipeline(Pipeline::new().processor(Processor::remove("status")))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "processors": [
+                {
+                    "remove": {
+                        "field": "status"
+                    }
+                }
+            ]
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
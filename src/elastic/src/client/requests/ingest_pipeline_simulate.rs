@@ -0,0 +1,457 @@
+/*!
+Builders for [simulate pipeline requests][docs-simulate-pipeline].
+
+[docs-simulate-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/simulate-pipeline-api.html
+*/
+
+use serde_json;
+use futures::{Future, Poll};
+use serde::Serialize;
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Type};
+use client::requests::endpoints::IngestSimulateRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::SimulatePipelineResponse;
+use types::document::DocumentType;
+use types::ingest::Pipeline;
+
+/**
+A [simulate pipeline request][docs-simulate-pipeline] builder that can be configured before sending.
+
+Call [`Client.ingest_pipeline_simulate`][Client.ingest_pipeline_simulate] to get an `IngestPipelineSimulateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-simulate-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/simulate-pipeline-api.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.ingest_pipeline_simulate]: ../../struct.Client.html#simulate-pipeline-request
+*/
+pub type IngestPipelineSimulateRequestBuilder<TSender, TDocument> = RequestBuilder<TSender, IngestPipelineSimulateRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct IngestPipelineSimulateRequestInner<TDocument> {
+    pipeline: Pipeline,
+    index: Option<Index<'static>>,
+    ty: Type<'static>,
+    docs: Vec<TDocument>,
+}
+
+#[derive(Serialize)]
+struct SimulateBody<'a, TDocument: 'a> {
+    pipeline: &'a Pipeline,
+    docs: Vec<SimulateDoc<'a, TDocument>>,
+}
+
+#[derive(Serialize)]
+struct SimulateDoc<'a, TDocument: 'a> {
+    #[serde(rename = "_index", skip_serializing_if = "Option::is_none")] index: Option<&'a str>,
+    #[serde(rename = "_type")] ty: &'a str,
+    #[serde(rename = "_source")] source: &'a TDocument,
+}
+
+/**
+# Simulate pipeline request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IngestPipelineSimulateRequestBuilder`][IngestPipelineSimulateRequestBuilder] with this `Client` that can be configured before sending.
+
+    The pipeline is run against each sample document without indexing anything.
+    The request is always `verbose`, so the response contains the result of each processor in the pipeline.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Simulate a pipeline against a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # use elastic::types::ingest::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub title: String,
+    #     pub status: Option<String>
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    let pipeline = Pipeline::new().processor(Processor::set("status", "new").tag("set_status"));
+
+    let doc = MyType {
+        id: 1,
+        title: String::from("A title"),
+        status: None
+    };
+
+    let response = client.ingest_pipeline_simulate(pipeline)
+                         .doc(doc)
+                         .send()?;
+
+    for doc in response.iter() {
+        for result in doc.processor_results() {
+            println!("{:?}: {:?}", result.tag(), result.doc().map(|doc| doc.source()));
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IngestPipelineSimulateRequestBuilder]: requests/ingest_pipeline_simulate/type.IngestPipelineSimulateRequestBuilder.html
+    [builder-methods]: requests/ingest_pipeline_simulate/type.IngestPipelineSimulateRequestBuilder.html#builder-methods
+    [send-sync]: requests/ingest_pipeline_simulate/type.IngestPipelineSimulateRequestBuilder.html#send-synchronously
+    [send-async]: requests/ingest_pipeline_simulate/type.IngestPipelineSimulateRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn ingest_pipeline_simulate<TDocument>(&self, pipeline: Pipeline) -> IngestPipelineSimulateRequestBuilder<TSender, TDocument>
+    where
+        TDocument: Serialize + DocumentType,
+    {
+        let ty = TDocument::name().into();
+
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            IngestPipelineSimulateRequestInner {
+                pipeline: pipeline,
+                index: None,
+                ty: ty,
+                docs: Vec::new(),
+            },
+        ).params(|p| p.url_param("verbose", true))
+    }
+}
+
+impl<TDocument> IngestPipelineSimulateRequestInner<TDocument>
+where
+    TDocument: Serialize,
+{
+    fn into_request(self) -> Result<IngestSimulateRequest<'static, Vec<u8>>> {
+        let index = self.index.as_ref().map(|index| &**index);
+        let ty = &*self.ty;
+
+        let body = SimulateBody {
+            pipeline: &self.pipeline,
+            docs: self.docs
+                .iter()
+                .map(|doc| SimulateDoc {
+                    index: index,
+                    ty: ty,
+                    source: doc,
+                })
+                .collect(),
+        };
+
+        let body = serde_json::to_vec(&body).map_err(error::request)?;
+
+        Ok(IngestSimulateRequest::new(body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IngestPipelineSimulateRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> IngestPipelineSimulateRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Add a sample document to run through the pipeline. */
+    pub fn doc(mut self, doc: TDocument) -> Self {
+        self.inner.docs.push(doc);
+        self
+    }
+
+    /** Add a collection of sample documents to run through the pipeline. */
+    pub fn docs<I>(mut self, docs: I) -> Self
+    where
+        I: IntoIterator<Item = TDocument>,
+    {
+        self.inner.docs.extend(docs);
+        self
+    }
+
+    /**
+    Set the index for the sample documents.
+
+    Processors that read the `_index` metadata field will see this value.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /** Set the type for the sample documents. */
+    pub fn ty<I>(mut self, ty: I) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.into();
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> IngestPipelineSimulateRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: Serialize,
+{
+    /**
+    Send an `IngestPipelineSimulateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Check whether a pipeline fails for any sample documents:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # use elastic::types::ingest::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub timestamp: String
+    # }
+    # fn get_docs() -> Vec<MyType> { unimplemented!() }
+    # let client = SyncClientBuilder::new().build()?;
+    let pipeline = Pipeline::new().processor(Processor::date("timestamp", vec!["dd/MM/yyyy"]));
+
+    let response = client.ingest_pipeline_simulate(pipeline)
+                         .docs(get_docs())
+                         .send()?;
+
+    for doc in response.iter() {
+        if let Some(error) = doc.error() {
+            println!("pipeline failed: {}", error);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<SimulatePipelineResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> IngestPipelineSimulateRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: Serialize + Send + 'static,
+{
+    /**
+    Send an `IngestPipelineSimulateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised simulate pipeline response.
+
+    # Examples
+
+    Check whether a pipeline fails for any sample documents:
+
+    ```no_run
+    # extern crate serde;
+    # extern crate futures;
+    # extern crate tokio_core;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # use elastic::types::ingest::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub timestamp: String
+    # }
+    # fn get_docs() -> Vec<MyType> { unimplemented!() }
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let pipeline = Pipeline::new().processor(Processor::date("timestamp", vec!["dd/MM/yyyy"]));
+
+    let future = client.ingest_pipeline_simulate(pipeline)
+                       .docs(get_docs())
+                       .send();
+
+    future.and_then(|response| {
+        for doc in response.iter() {
+            if let Some(error) = doc.error() {
+                println!("pipeline failed: {}", error);
+            }
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = SimulatePipelineResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = SimulatePipelineResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = SimulatePipelineResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+    use types::ingest::prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let pipeline = Pipeline::new().processor(Processor::set("status", "new"));
+
+        let req = client
+            .ingest_pipeline_simulate(pipeline)
+            .doc(json!({ "title": "A title" }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "pipeline": {
+                "processors": [
+                    {
+                        "set": {
+                            "field": "status",
+                            "value": "new"
+                        }
+                    }
+                ]
+            },
+            "docs": [
+                {
+                    "_type": "value",
+                    "_source": {
+                        "title": "A title"
+                    }
+                }
+            ]
+        });
+
+        assert_eq!("/_ingest/pipeline/_simulate", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn default_request_is_verbose() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.ingest_pipeline_simulate::<Value>(Pipeline::new());
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+
+        assert_eq!("?verbose=true", query.unwrap());
+    }
+
+    #[test]
+    fn specify_docs() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .ingest_pipeline_simulate(Pipeline::new())
+            .index("myindex")
+            .ty("mytype")
+            .docs(vec![json!({ "id": 1 }), json!({ "id": 2 })])
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_docs = json!([
+            {
+                "_index": "myindex",
+                "_type": "mytype",
+                "_source": {
+                    "id": 1
+                }
+            },
+            {
+                "_index": "myindex",
+                "_type": "mytype",
+                "_source": {
+                    "id": 2
+                }
+            }
+        ]);
+
+        let body = serde_json::from_slice::<Value>(&req.body).unwrap();
+
+        assert_eq!(expected_docs, body["docs"]);
+    }
+}
@@ -4,6 +4,7 @@ Request types for the Elasticsearch REST API.
 This module contains implementation details that are useful if you want to customise the request process, but aren't generally important for sending requests.
 */
 
+use std::time::Duration;
 use futures_cpupool::CpuPool;
 
 use client::{AsyncSender, Client, RequestParams, Sender};
@@ -21,19 +22,61 @@ pub use self::raw::RawRequestBuilder;
 
 // Search requests
 pub mod search;
+pub mod scroll;
+pub mod msearch;
+pub mod msearch_template;
+pub mod count;
+pub mod validate_query;
+pub mod search_template;
+pub mod search_template_render;
+pub mod search_template_put;
+pub mod search_template_get;
+pub mod search_template_delete;
 pub use self::search::SearchRequestBuilder;
+pub use self::scroll::{ScrollIter, ScrollRequestBuilder, ScrollStream};
+pub use self::msearch::{msearch_query, MsearchQuery, MsearchRequestBuilder};
+pub use self::msearch_template::{msearch_template_query, MsearchTemplateQuery, MsearchTemplateRequestBuilder};
+pub use self::count::CountRequestBuilder;
+pub use self::validate_query::ValidateQueryRequestBuilder;
+pub use self::search_template::{SearchTemplateBuilder, SearchTemplateRequestBuilder};
+pub use self::search_template_render::SearchTemplateRenderRequestBuilder;
+pub use self::search_template_put::SearchTemplatePutRequestBuilder;
+pub use self::search_template_get::SearchTemplateGetRequestBuilder;
+pub use self::search_template_delete::SearchTemplateDeleteRequestBuilder;
 
 // Document requests
 pub mod document_get;
+pub mod document_explain;
+pub mod document_termvectors;
+pub mod document_mtermvectors;
+pub mod document_mget;
 pub mod document_index;
 pub mod document_update;
 pub mod document_delete;
 pub mod document_put_mapping;
+pub mod document_get_mapping;
+pub mod delete_by_query;
+pub mod update_by_query;
+pub mod reindex;
 pub use self::document_get::GetRequestBuilder;
+pub use self::document_explain::ExplainRequestBuilder;
+pub use self::document_termvectors::TermvectorsRequestBuilder;
+pub use self::document_mtermvectors::MtermvectorsRequestBuilder;
+pub use self::document_mget::MgetRequestBuilder;
 pub use self::document_index::IndexRequestBuilder;
 pub use self::document_update::UpdateRequestBuilder;
 pub use self::document_delete::DeleteRequestBuilder;
 pub use self::document_put_mapping::PutMappingRequestBuilder;
+pub use self::document_get_mapping::{document_mapping_diff, GetMappingRequestBuilder};
+pub use self::delete_by_query::DeleteByQueryRequestBuilder;
+pub use self::update_by_query::UpdateByQueryRequestBuilder;
+pub use self::reindex::ReindexRequestBuilder;
+
+// Bulk requests
+pub mod bulk;
+pub mod bulk_processor;
+pub use self::bulk::{bulk_create, bulk_delete, bulk_index, bulk_update, BulkOperation, BulkRequestBuilder};
+pub use self::bulk_processor::{AnyBulkOperation, BulkOutcome, BulkOutcomes, BulkProcessor, BulkProcessorBuilder};
 
 // Index requests
 pub mod index_create;
@@ -41,15 +84,71 @@ pub mod index_open;
 pub mod index_close;
 pub mod index_delete;
 pub mod index_exists;
+pub mod index_aliases;
+pub mod alias_get;
+pub mod alias_exists;
+pub mod index_template_put;
+pub mod index_template_get;
+pub mod index_template_delete;
+pub mod index_template_exists;
+pub mod index_settings_get;
+pub mod index_settings_put;
 pub use self::index_create::IndexCreateRequestBuilder;
 pub use self::index_open::IndexOpenRequestBuilder;
 pub use self::index_close::IndexCloseRequestBuilder;
 pub use self::index_delete::IndexDeleteRequestBuilder;
 pub use self::index_exists::IndexExistsRequestBuilder;
+pub use self::index_aliases::{alias_add, alias_remove, alias_remove_index, AliasAction, IndexAliasesRequestBuilder};
+pub use self::alias_get::AliasGetRequestBuilder;
+pub use self::alias_exists::AliasExistsRequestBuilder;
+pub use self::index_template_put::IndexTemplatePutRequestBuilder;
+pub use self::index_template_get::IndexTemplateGetRequestBuilder;
+pub use self::index_template_delete::IndexTemplateDeleteRequestBuilder;
+pub use self::index_template_exists::IndexTemplateExistsRequestBuilder;
+pub use self::index_settings_get::IndexSettingsGetRequestBuilder;
+pub use self::index_settings_put::IndexSettingsPutRequestBuilder;
+
+// Snapshot requests
+pub mod snapshot_repository_put;
+pub mod snapshot_repository_get;
+pub mod snapshot_repository_delete;
+pub mod snapshot_create;
+pub mod snapshot_get;
+pub mod snapshot_delete;
+pub mod snapshot_restore;
+pub mod snapshot_status;
+pub use self::snapshot_repository_put::{SnapshotRepository, SnapshotRepositoryPutRequestBuilder};
+pub use self::snapshot_repository_get::SnapshotRepositoryGetRequestBuilder;
+pub use self::snapshot_repository_delete::SnapshotRepositoryDeleteRequestBuilder;
+pub use self::snapshot_create::SnapshotCreateRequestBuilder;
+pub use self::snapshot_get::SnapshotGetRequestBuilder;
+pub use self::snapshot_delete::SnapshotDeleteRequestBuilder;
+pub use self::snapshot_restore::SnapshotRestoreRequestBuilder;
+pub use self::snapshot_status::SnapshotStatusRequestBuilder;
+
+// Ingest requests
+pub mod ingest_pipeline_put;
+pub mod ingest_pipeline_get;
+pub mod ingest_pipeline_delete;
+pub mod ingest_pipeline_simulate;
+pub use self::ingest_pipeline_put::IngestPipelinePutRequestBuilder;
+pub use self::ingest_pipeline_get::IngestPipelineGetRequestBuilder;
+pub use self::ingest_pipeline_delete::IngestPipelineDeleteRequestBuilder;
+pub use self::ingest_pipeline_simulate::IngestPipelineSimulateRequestBuilder;
+
+// Task requests
+pub mod tasks;
+pub use self::tasks::{TaskHandle, TasksListRequestBuilder};
+
+// Cluster requests
+pub mod cluster_health;
+pub use self::cluster_health::ClusterHealthRequestBuilder;
 
 // Misc requests
 pub mod ping;
+pub mod analyze;
 pub use self::ping::PingRequestBuilder;
+pub use self::analyze::AnalyzeRequestBuilder;
 
 /**
 A builder for a request.
@@ -124,6 +223,41 @@ where
 
         self
     }
+
+    /**
+    Override the timeout for this request.
+
+    If a response isn't received before the timeout expires then the request
+    will fail with a client error where [`is_timeout`][ClientError.is_timeout] is `true`.
+    This overrides the default timeout set on the client builder.
+    The timeout applies to each attempt when requests are retried.
+
+    Timeouts for an `AsyncClient` are scheduled on the reactor.
+    If the `AsyncClient` wasn't built with a reactor `Handle` then sending the request will fail.
+
+    # Examples
+
+    Wait at most 5 seconds for a response:
+
+    ```no_run
+    # extern crate elastic;
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    # fn get_req() -> PingRequest<'static> { PingRequest::new() }
+    let builder = client.request(get_req())
+                        .timeout(Duration::from_secs(5));
+    # Ok(())
+    # }
+    ```
+
+    [ClientError.is_timeout]: ../../error/struct.ClientError.html#method.is_timeout
+    */
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.params(move |p| p.timeout(timeout))
+    }
 }
 
 /**
@@ -193,25 +327,88 @@ pub mod prelude {
     pub use super::endpoints::*;
 
     pub use super::{
+        alias_add,
+        alias_remove,
+        alias_remove_index,
+        bulk_create,
+        bulk_delete,
+        bulk_index,
+        bulk_update,
+        document_mapping_diff,
         empty_body,
+        msearch_query,
+        msearch_template_query,
+        AliasAction,
+        AnalyzeRequestBuilder,
+        AliasExistsRequestBuilder,
+        AliasGetRequestBuilder,
+        BulkOperation,
+        BulkRequestBuilder,
+        ClusterHealthRequestBuilder,
+        CountRequestBuilder,
         DefaultBody,
+        DeleteByQueryRequestBuilder,
         DeleteRequestBuilder,
+        ExplainRequestBuilder,
+        GetMappingRequestBuilder,
         GetRequestBuilder,
+        IndexAliasesRequestBuilder,
         IndexCreateRequestBuilder,
         IndexDeleteRequestBuilder,
         IndexOpenRequestBuilder,
         IndexCloseRequestBuilder,
         IndexRequestBuilder,
+        IndexSettingsGetRequestBuilder,
+        IndexSettingsPutRequestBuilder,
+        IndexTemplateDeleteRequestBuilder,
+        IndexTemplateExistsRequestBuilder,
+        IndexTemplateGetRequestBuilder,
+        IndexTemplatePutRequestBuilder,
+        IngestPipelineDeleteRequestBuilder,
+        IngestPipelineGetRequestBuilder,
+        IngestPipelinePutRequestBuilder,
+        IngestPipelineSimulateRequestBuilder,
+        MgetRequestBuilder,
+        MsearchQuery,
+        MsearchRequestBuilder,
+        MsearchTemplateQuery,
+        MsearchTemplateRequestBuilder,
+        MtermvectorsRequestBuilder,
         PutMappingRequestBuilder,
         RawRequestBuilder,
+        ReindexRequestBuilder,
+        ScrollRequestBuilder,
         SearchRequestBuilder,
+        SearchTemplateBuilder,
+        SearchTemplateDeleteRequestBuilder,
+        SearchTemplateGetRequestBuilder,
+        SearchTemplatePutRequestBuilder,
+        SearchTemplateRenderRequestBuilder,
+        SearchTemplateRequestBuilder,
+        SnapshotCreateRequestBuilder,
+        SnapshotDeleteRequestBuilder,
+        SnapshotGetRequestBuilder,
+        SnapshotRepository,
+        SnapshotRepositoryDeleteRequestBuilder,
+        SnapshotRepositoryGetRequestBuilder,
+        SnapshotRepositoryPutRequestBuilder,
+        SnapshotRestoreRequestBuilder,
+        SnapshotStatusRequestBuilder,
+        TaskHandle,
+        TasksListRequestBuilder,
+        TermvectorsRequestBuilder,
+        UpdateByQueryRequestBuilder,
         PingRequestBuilder,
         UpdateRequestBuilder,
+        ValidateQueryRequestBuilder,
     };
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+    use tokio_core::reactor::Core;
+    use reqwest::unstable::async::Client as AsyncHttpClient;
     use super::RequestBuilder;
     use prelude::*;
 
@@ -233,4 +430,32 @@ mod tests {
         assert_eq!("http://eshost:9200", params.get_base_url());
         assert_eq!("?pretty=true&refresh=true", query.unwrap());
     }
+
+    #[test]
+    fn request_builder_timeout() {
+        let client = SyncClientBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap();
+
+        let req = RequestBuilder::new(client.clone(), None, PingRequest::new()).timeout(Duration::from_secs(5));
+
+        let params = &req.params.unwrap();
+
+        assert_eq!(Some(Duration::from_secs(30)), client.params.get_timeout());
+        assert_eq!(Some(Duration::from_secs(5)), params.get_timeout());
+    }
+
+    #[test]
+    fn async_request_builder_timeout_requires_handle() {
+        let mut core = Core::new().unwrap();
+
+        let without_handle = AsyncClientBuilder::new()
+            .build(AsyncHttpClient::new(&core.handle()))
+            .unwrap();
+
+        let res = core.run(without_handle.request(PingRequest::new()).timeout(Duration::from_secs(5)).send());
+
+        assert!(res.is_err());
+    }
 }
@@ -0,0 +1,510 @@
+/*!
+Builders for [multi search requests][docs-msearch].
+
+[docs-msearch]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html
+*/
+
+use std::marker::PhantomData;
+use futures::{Future, IntoFuture, Poll};
+use serde::ser::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json;
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Type};
+use client::requests::endpoints::MsearchRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::MsearchResponse;
+
+/**
+A [multi search request][docs-msearch] builder that can be configured before sending.
+
+Call [`Client.msearch`][Client.msearch] to get an `MsearchRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-msearch]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.msearch]: ../../struct.Client.html#multi-search-request
+*/
+pub type MsearchRequestBuilder<TSender, TDocument> = RequestBuilder<TSender, MsearchRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct MsearchRequestInner<TDocument> {
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    body: Vec<u8>,
+    err: Option<Error>,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Multi search request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`MsearchRequestBuilder`][MsearchRequestBuilder] with this `Client` that can be configured before sending.
+
+    Searches are added to the request with the [`push`][MsearchRequestBuilder.push] and [`extend`][MsearchRequestBuilder.extend] methods.
+    Each search is serialised to the newline-delimited body as soon as it's added.
+    All searches are sent to Elasticsearch in a single round trip.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Run a few searches for a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.msearch::<MyType>()
+                         .index("myindex")
+                         .push(msearch_query(json!({
+                             "query": {
+                                 "query_string": {
+                                     "query": "a query string"
+                                 }
+                             }
+                         })))
+                         .push(msearch_query(json!({})).index("otherindex"))
+                         .send()?;
+
+    // Iterate through the responses in the same order as the searches
+    for search in response {
+        match search {
+            Ok(search) => println!("total hits: {}", search.total()),
+            Err(err) => println!("err: {:?}", err),
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [MsearchRequestBuilder]: requests/msearch/type.MsearchRequestBuilder.html
+    [MsearchRequestBuilder.push]: requests/msearch/type.MsearchRequestBuilder.html#method.push
+    [MsearchRequestBuilder.extend]: requests/msearch/type.MsearchRequestBuilder.html#method.extend
+    [builder-methods]: requests/msearch/type.MsearchRequestBuilder.html#builder-methods
+    [send-sync]: requests/msearch/type.MsearchRequestBuilder.html#send-synchronously
+    [send-async]: requests/msearch/type.MsearchRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../../types/document/index.html
+    */
+    pub fn msearch<TDocument>(&self) -> MsearchRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DeserializeOwned,
+    {
+        RequestBuilder::new(self.clone(), None, MsearchRequestInner::new())
+    }
+}
+
+impl<TDocument> MsearchRequestInner<TDocument> {
+    fn new() -> Self {
+        MsearchRequestInner {
+            index: None,
+            ty: None,
+            body: Vec::new(),
+            err: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn push<TBody>(&mut self, query: MsearchQuery<TBody>)
+    where
+        TBody: Serialize,
+    {
+        if self.err.is_some() {
+            return;
+        }
+
+        if let Err(e) = query.write(&mut self.body) {
+            self.err = Some(error::request(e));
+        }
+    }
+
+    fn into_request(self) -> Result<MsearchRequest<'static, Vec<u8>>> {
+        if let Some(err) = self.err {
+            return Err(err);
+        }
+
+        let req = match (self.index, self.ty) {
+            (Some(index), Some(ty)) => MsearchRequest::for_index_ty(index, ty, self.body),
+            (Some(index), None) => MsearchRequest::for_index(index, self.body),
+            (None, _) => MsearchRequest::new(self.body),
+        };
+
+        Ok(req)
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `MsearchRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> MsearchRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Set the default index for the multi search request.
+
+    Searches that don't specify their own index will use this one.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /**
+    Set the default type for the multi search request.
+
+    The default type is only used if a default index is also given.
+    */
+    pub fn ty<I>(mut self, ty: Option<I>) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.map(Into::into);
+        self
+    }
+
+    /**
+    Add a search to the multi search request.
+
+    Searches can be created using the [`msearch_query`][msearch_query] function.
+    If a search fails to serialise then the error will be returned when the request is sent.
+
+    [msearch_query]: fn.msearch_query.html
+    */
+    pub fn push<TBody>(mut self, query: MsearchQuery<TBody>) -> Self
+    where
+        TBody: Serialize,
+    {
+        self.inner.push(query);
+        self
+    }
+
+    /** Add a sequence of searches to the multi search request. */
+    pub fn extend<I, TBody>(mut self, queries: I) -> Self
+    where
+        I: IntoIterator<Item = MsearchQuery<TBody>>,
+        TBody: Serialize,
+    {
+        for query in queries {
+            self.inner.push(query);
+        }
+
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> MsearchRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    /**
+    Send an `MsearchRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Run a couple of searches on an index called `myindex`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.msearch::<Value>()
+                         .index("myindex")
+                         .push(msearch_query(json!({ "query": { "term": { "title": "a" } } })))
+                         .push(msearch_query(json!({ "query": { "term": { "title": "b" } } })))
+                         .send()?;
+
+    for search in response.iter().filter_map(|search| search.ok()) {
+        for hit in search.hits() {
+            println!("{:?}", hit);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<MsearchResponse<TDocument>> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> MsearchRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    /**
+    Send an `MsearchRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised multi search response.
+
+    # Examples
+
+    Run a couple of searches on an index called `myindex`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.msearch::<Value>()
+                       .index("myindex")
+                       .push(msearch_query(json!({ "query": { "term": { "title": "a" } } })))
+                       .push(msearch_query(json!({ "query": { "term": { "title": "b" } } })))
+                       .send();
+
+    future.and_then(|response| {
+        for search in response.iter().filter_map(|search| search.ok()) {
+            println!("total hits: {}", search.total());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TDocument> {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending<TDocument> {
+    inner: Box<Future<Item = MsearchResponse<TDocument>, Error = Error>>,
+}
+
+impl<TDocument> Pending<TDocument> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = MsearchResponse<TDocument>, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<TDocument> Future for Pending<TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    type Item = MsearchResponse<TDocument>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+/**
+A single search in a multi search request.
+
+Create searches using the [`msearch_query`][msearch_query] function.
+The index and type of each search are inferred from the multi search request unless they're set on the search itself.
+
+[msearch_query]: fn.msearch_query.html
+*/
+pub struct MsearchQuery<TBody> {
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    body: TBody,
+}
+
+#[derive(Serialize)]
+struct MsearchHeader<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")] index: Option<&'a str>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")] ty: Option<&'a str>,
+}
+
+/**
+Search with the given body.
+
+The body is the same as the body of a regular search request.
+*/
+pub fn msearch_query<TBody>(body: TBody) -> MsearchQuery<TBody>
+where
+    TBody: Serialize,
+{
+    MsearchQuery {
+        index: None,
+        ty: None,
+        body: body,
+    }
+}
+
+impl<TBody> MsearchQuery<TBody> {
+    /**
+    Set the indices for this search.
+
+    If no index is specified then the default index of the multi search request will be used.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.index = Some(index.into());
+        self
+    }
+
+    /** Set the types for this search. */
+    pub fn ty<I>(mut self, ty: I) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.ty = Some(ty.into());
+        self
+    }
+}
+
+impl<TBody> MsearchQuery<TBody>
+where
+    TBody: Serialize,
+{
+    fn write(&self, buf: &mut Vec<u8>) -> serde_json::Result<()> {
+        write_search(buf, self.index.as_ref(), self.ty.as_ref(), &self.body)
+    }
+}
+
+/** Write a header line and a body line for a single search to a multi search body. */
+pub(crate) fn write_search<TBody>(buf: &mut Vec<u8>, index: Option<&Index<'static>>, ty: Option<&Type<'static>>, body: &TBody) -> serde_json::Result<()>
+where
+    TBody: Serialize,
+{
+    let header = MsearchHeader {
+        index: index.map(|index| &**index),
+        ty: ty.map(|ty| &**ty),
+    };
+
+    serde_json::to_writer(&mut *buf, &header)?;
+    buf.push(b'\n');
+
+    serde_json::to_writer(&mut *buf, body)?;
+    buf.push(b'\n');
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+    use serde_json::Value;
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.msearch::<Value>().inner.into_request().unwrap();
+
+        assert_eq!("/_msearch", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .msearch::<Value>()
+            .index("new-idx")
+            .ty(Some("new-ty"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/new-idx/new-ty/_msearch", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_queries() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .msearch::<Value>()
+            .push(msearch_query(json!({ "query": { "match_all": {} } })))
+            .push(msearch_query(json!({ "size": 1 })).index("other-idx").ty("other-ty"))
+            .extend((0..2).map(|i| msearch_query(json!({ "from": i })).index("new-idx")))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = vec![
+            json!({}),
+            json!({ "query": { "match_all": {} } }),
+            json!({ "index": "other-idx", "type": "other-ty" }),
+            json!({ "size": 1 }),
+            json!({ "index": "new-idx" }),
+            json!({ "from": 0 }),
+            json!({ "index": "new-idx" }),
+            json!({ "from": 1 }),
+        ];
+
+        let body = str::from_utf8(&req.body).unwrap();
+        let actual_body: Vec<Value> = body.lines()
+            .map(|line| ::serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert!(body.ends_with('\n'));
+        assert_eq!(expected_body, actual_body);
+    }
+}
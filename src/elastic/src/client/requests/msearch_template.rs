@@ -0,0 +1,475 @@
+/*!
+Builders for [multi search template requests][docs-msearch-template].
+
+[docs-msearch-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/multi-search-template.html
+*/
+
+use std::marker::PhantomData;
+use futures::{Future, IntoFuture, Poll};
+use serde::ser::Serialize;
+use serde::de::DeserializeOwned;
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Type};
+use client::requests::endpoints::MsearchTemplateRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::msearch::write_search;
+use client::requests::search_template::SearchTemplateBuilder;
+use client::responses::MsearchResponse;
+
+/**
+A [multi search template request][docs-msearch-template] builder that can be configured before sending.
+
+Call [`Client.msearch_template`][Client.msearch_template] to get an `MsearchTemplateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-msearch-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/multi-search-template.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.msearch_template]: ../../struct.Client.html#multi-search-template-request
+*/
+pub type MsearchTemplateRequestBuilder<TSender, TDocument> = RequestBuilder<TSender, MsearchTemplateRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct MsearchTemplateRequestInner<TDocument> {
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    body: Vec<u8>,
+    err: Option<Error>,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Multi search template request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`MsearchTemplateRequestBuilder`][MsearchTemplateRequestBuilder] with this `Client` that can be configured before sending.
+
+    Templated searches are added to the request with the [`push`][MsearchTemplateRequestBuilder.push] and [`extend`][MsearchTemplateRequestBuilder.extend] methods.
+    Each template is the same as the template for a [search template request][Client.search_template].
+    The response is the same as the response for a [multi search request][Client.msearch].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Run a stored template called `title_search` a few times for a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.msearch_template::<MyType>()
+                         .index("myindex")
+                         .push(msearch_template_query(SearchTemplateBuilder::id("title_search").param("query_string", "a title")))
+                         .push(msearch_template_query(SearchTemplateBuilder::id("title_search").param("query_string", "another title")).index("otherindex"))
+                         .send()?;
+
+    // Iterate through the responses in the same order as the searches
+    for search in response {
+        match search {
+            Ok(search) => println!("total hits: {}", search.total()),
+            Err(err) => println!("err: {:?}", err),
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [MsearchTemplateRequestBuilder]: requests/msearch_template/type.MsearchTemplateRequestBuilder.html
+    [MsearchTemplateRequestBuilder.push]: requests/msearch_template/type.MsearchTemplateRequestBuilder.html#method.push
+    [MsearchTemplateRequestBuilder.extend]: requests/msearch_template/type.MsearchTemplateRequestBuilder.html#method.extend
+    [Client.search_template]: #search-template-request
+    [Client.msearch]: #multi-search-request
+    [builder-methods]: requests/msearch_template/type.MsearchTemplateRequestBuilder.html#builder-methods
+    [send-sync]: requests/msearch_template/type.MsearchTemplateRequestBuilder.html#send-synchronously
+    [send-async]: requests/msearch_template/type.MsearchTemplateRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../../types/document/index.html
+    */
+    pub fn msearch_template<TDocument>(&self) -> MsearchTemplateRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DeserializeOwned,
+    {
+        RequestBuilder::new(self.clone(), None, MsearchTemplateRequestInner::new())
+    }
+}
+
+impl<TDocument> MsearchTemplateRequestInner<TDocument> {
+    fn new() -> Self {
+        MsearchTemplateRequestInner {
+            index: None,
+            ty: None,
+            body: Vec::new(),
+            err: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn push<TParams>(&mut self, query: MsearchTemplateQuery<TParams>)
+    where
+        TParams: Serialize,
+    {
+        if self.err.is_some() {
+            return;
+        }
+
+        if let Err(e) = write_search(&mut self.body, query.index.as_ref(), query.ty.as_ref(), &query.template) {
+            self.err = Some(error::request(e));
+        }
+    }
+
+    fn into_request(self) -> Result<MsearchTemplateRequest<'static, Vec<u8>>> {
+        if let Some(err) = self.err {
+            return Err(err);
+        }
+
+        let req = match (self.index, self.ty) {
+            (Some(index), Some(ty)) => MsearchTemplateRequest::for_index_ty(index, ty, self.body),
+            (Some(index), None) => MsearchTemplateRequest::for_index(index, self.body),
+            (None, _) => MsearchTemplateRequest::new(self.body),
+        };
+
+        Ok(req)
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `MsearchTemplateRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> MsearchTemplateRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Set the default index for the multi search template request.
+
+    Searches that don't specify their own index will use this one.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /**
+    Set the default type for the multi search template request.
+
+    The default type is only used if a default index is also given.
+    */
+    pub fn ty<I>(mut self, ty: Option<I>) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.map(Into::into);
+        self
+    }
+
+    /**
+    Add a templated search to the multi search template request.
+
+    Searches can be created using the [`msearch_template_query`][msearch_template_query] function.
+    If a search fails to serialise then the error will be returned when the request is sent.
+
+    [msearch_template_query]: fn.msearch_template_query.html
+    */
+    pub fn push<TParams>(mut self, query: MsearchTemplateQuery<TParams>) -> Self
+    where
+        TParams: Serialize,
+    {
+        self.inner.push(query);
+        self
+    }
+
+    /** Add a sequence of templated searches to the multi search template request. */
+    pub fn extend<I, TParams>(mut self, queries: I) -> Self
+    where
+        I: IntoIterator<Item = MsearchTemplateQuery<TParams>>,
+        TParams: Serialize,
+    {
+        for query in queries {
+            self.inner.push(query);
+        }
+
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> MsearchTemplateRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    /**
+    Send an `MsearchTemplateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Run a stored template called `title_search` a couple of times on an index called `myindex`:
+
+    ```no_run
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.msearch_template::<Value>()
+                         .index("myindex")
+                         .extend(vec!["a", "b"].into_iter().map(|title| {
+                             msearch_template_query(SearchTemplateBuilder::id("title_search").param("query_string", title))
+                         }))
+                         .send()?;
+
+    for search in response.iter().filter_map(|search| search.ok()) {
+        for hit in search.hits() {
+            println!("{:?}", hit);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<MsearchResponse<TDocument>> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> MsearchTemplateRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    /**
+    Send an `MsearchTemplateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised multi search response.
+
+    # Examples
+
+    Run a stored template called `title_search` a couple of times on an index called `myindex`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.msearch_template::<Value>()
+                       .index("myindex")
+                       .extend(vec!["a", "b"].into_iter().map(|title| {
+                           msearch_template_query(SearchTemplateBuilder::id("title_search").param("query_string", title))
+                       }))
+                       .send();
+
+    future.and_then(|response| {
+        for search in response.iter().filter_map(|search| search.ok()) {
+            println!("total hits: {}", search.total());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TDocument> {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending<TDocument> {
+    inner: Box<Future<Item = MsearchResponse<TDocument>, Error = Error>>,
+}
+
+impl<TDocument> Pending<TDocument> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = MsearchResponse<TDocument>, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<TDocument> Future for Pending<TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    type Item = MsearchResponse<TDocument>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+/**
+A single templated search in a multi search template request.
+
+Create searches using the [`msearch_template_query`][msearch_template_query] function.
+The index and type of each search are inferred from the multi search template request unless they're set on the search itself.
+
+[msearch_template_query]: fn.msearch_template_query.html
+*/
+pub struct MsearchTemplateQuery<TParams> {
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    template: SearchTemplateBuilder<TParams>,
+}
+
+/**
+Search with the given template.
+
+The template is the same as the template for a regular search template request.
+A string is treated as the id of a stored template.
+*/
+pub fn msearch_template_query<TTemplate, TParams>(template: TTemplate) -> MsearchTemplateQuery<TParams>
+where
+    TTemplate: Into<SearchTemplateBuilder<TParams>>,
+{
+    MsearchTemplateQuery {
+        index: None,
+        ty: None,
+        template: template.into(),
+    }
+}
+
+impl<TParams> MsearchTemplateQuery<TParams> {
+    /**
+    Set the indices for this search.
+
+    If no index is specified then the default index of the multi search template request will be used.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.index = Some(index.into());
+        self
+    }
+
+    /** Set the types for this search. */
+    pub fn ty<I>(mut self, ty: I) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.ty = Some(ty.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+    use serde_json::Value;
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.msearch_template::<Value>().inner.into_request().unwrap();
+
+        assert_eq!("/_msearch/template", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .msearch_template::<Value>()
+            .index("new-idx")
+            .ty(Some("new-ty"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/new-idx/new-ty/_msearch/template", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_queries() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .msearch_template::<Value>()
+            .push(msearch_template_query("title_search"))
+            .push(msearch_template_query(SearchTemplateBuilder::id("title_search").param("query_string", "a title")).index("other-idx").ty("other-ty"))
+            .extend((0..2).map(|i| msearch_template_query(SearchTemplateBuilder::inline(json!({ "from": "{{from}}" })).param("from", i)).index("new-idx")))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = vec![
+            json!({}),
+            json!({ "id": "title_search" }),
+            json!({ "index": "other-idx", "type": "other-ty" }),
+            json!({ "id": "title_search", "params": { "query_string": "a title" } }),
+            json!({ "index": "new-idx" }),
+            json!({ "inline": { "from": "{{from}}" }, "params": { "from": 0 } }),
+            json!({ "index": "new-idx" }),
+            json!({ "inline": { "from": "{{from}}" }, "params": { "from": 1 } }),
+        ];
+
+        let body = str::from_utf8(&req.body).unwrap();
+        let actual_body: Vec<Value> = body.lines()
+            .map(|line| ::serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert!(body.ends_with('\n'));
+        assert_eq!(expected_body, actual_body);
+    }
+}
@@ -0,0 +1,710 @@
+/*!
+Builders for [reindex requests][docs-reindex].
+
+[docs-reindex]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html
+*/
+
+use std::marker::PhantomData;
+use futures::{Future, IntoFuture, Poll};
+use serde::ser::Serialize;
+use serde_json::{self, Value};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::document_update::{DefaultParams, ScriptBuilder};
+use client::requests::params::{Index, Type};
+use client::requests::endpoints::ReindexRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::tasks::TaskHandle;
+use client::responses::{ReindexResponse, StartedTaskResponse};
+use client::responses::bulk_by_scroll::BulkByScrollStatus;
+
+/**
+A [reindex request][docs-reindex] builder that can be configured before sending.
+
+Call [`Client.reindex`][Client.reindex] to get a `ReindexRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-reindex]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.reindex]: ../../struct.Client.html#reindex-request
+*/
+pub type ReindexRequestBuilder<TSender, TResponse> = RequestBuilder<TSender, ReindexRequestInner<TResponse>>;
+
+#[doc(hidden)]
+pub struct ReindexRequestInner<TResponse> {
+    source: Index<'static>,
+    source_ty: Option<Type<'static>>,
+    source_query: Option<Value>,
+    source_size: Option<u64>,
+    dest: Index<'static>,
+    dest_op_type: Option<OpType>,
+    dest_version_type: Option<VersionType>,
+    script: Option<Value>,
+    err: Option<Error>,
+    _marker: PhantomData<TResponse>,
+}
+
+/** The operation to use when writing documents to the destination index. */
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OpType {
+    /** Index documents, replacing any that already exist. */
+    Index,
+    /** Only create documents that are missing from the destination index. */
+    Create,
+}
+
+/** The versioning to use when writing documents to the destination index. */
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionType {
+    /** Overwrite documents in the destination index, ignoring their versions. */
+    Internal,
+    /** Preserve the version from the source index, only updating documents that have an older version. */
+    External,
+}
+
+/**
+# Reindex request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ReindexRequestBuilder`][ReindexRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Copy all documents from an index called `myindex` to an index called `myindex-v2`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.reindex(index("myindex"), index("myindex-v2"))
+                         .send()?;
+
+    println!("reindexed {} documents", response.created());
+    # Ok(())
+    # }
+    ```
+
+    [ReindexRequestBuilder]: requests/reindex/type.ReindexRequestBuilder.html
+    [builder-methods]: requests/reindex/type.ReindexRequestBuilder.html#builder-methods
+    [send-sync]: requests/reindex/type.ReindexRequestBuilder.html#send-synchronously
+    [send-async]: requests/reindex/type.ReindexRequestBuilder.html#send-asynchronously
+    */
+    pub fn reindex(&self, source: Index<'static>, dest: Index<'static>) -> ReindexRequestBuilder<TSender, ReindexResponse> {
+        RequestBuilder::new(self.clone(), None, ReindexRequestInner::new(source, dest))
+    }
+}
+
+impl<TResponse> ReindexRequestInner<TResponse> {
+    fn new(source: Index<'static>, dest: Index<'static>) -> Self {
+        ReindexRequestInner {
+            source: source,
+            source_ty: None,
+            source_query: None,
+            source_size: None,
+            dest: dest,
+            dest_op_type: None,
+            dest_version_type: None,
+            script: None,
+            err: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn to_value<TValue>(&mut self, value: TValue) -> Option<Value>
+    where
+        TValue: Serialize,
+    {
+        if self.err.is_some() {
+            return None;
+        }
+
+        match serde_json::to_value(value) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.err = Some(error::request(e));
+                None
+            }
+        }
+    }
+
+    fn with_response<TNewResponse>(self) -> ReindexRequestInner<TNewResponse> {
+        ReindexRequestInner {
+            source: self.source,
+            source_ty: self.source_ty,
+            source_query: self.source_query,
+            source_size: self.source_size,
+            dest: self.dest,
+            dest_op_type: self.dest_op_type,
+            dest_version_type: self.dest_version_type,
+            script: self.script,
+            err: self.err,
+            _marker: PhantomData,
+        }
+    }
+
+    fn into_request(self) -> Result<ReindexRequest<'static, Vec<u8>>> {
+        if let Some(err) = self.err {
+            return Err(err);
+        }
+
+        let body = serde_json::to_vec(&ReindexBody {
+            source: SourceBody {
+                index: &self.source,
+                ty: self.source_ty.as_ref().map(|ty| ty.as_ref()),
+                query: self.source_query.as_ref(),
+                size: self.source_size,
+            },
+            dest: DestBody {
+                index: &self.dest,
+                op_type: self.dest_op_type,
+                version_type: self.dest_version_type,
+            },
+            script: self.script.as_ref(),
+        }).map_err(error::request)?;
+
+        Ok(ReindexRequest::new(body))
+    }
+}
+
+#[derive(Serialize)]
+struct ReindexBody<'a> {
+    source: SourceBody<'a>,
+    dest: DestBody<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")] script: Option<&'a Value>,
+}
+
+#[derive(Serialize)]
+struct SourceBody<'a> {
+    index: &'a str,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")] ty: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")] query: Option<&'a Value>,
+    #[serde(skip_serializing_if = "Option::is_none")] size: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct DestBody<'a> {
+    index: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")] op_type: Option<OpType>,
+    #[serde(skip_serializing_if = "Option::is_none")] version_type: Option<VersionType>,
+}
+
+/**
+# Builder methods
+
+Configure a `ReindexRequestBuilder` before sending it.
+*/
+impl<TSender, TResponse> ReindexRequestBuilder<TSender, TResponse>
+where
+    TSender: Sender,
+{
+    /** Set the types to copy documents from. */
+    pub fn source_ty<I>(mut self, ty: Option<I>) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.source_ty = ty.map(Into::into);
+        self
+    }
+
+    /**
+    Set the query used to find documents to copy.
+
+    If no query is specified then all documents will be copied.
+    If the query fails to serialise then the error will be returned when the request is sent.
+    */
+    pub fn source_query<TQuery>(mut self, query: TQuery) -> Self
+    where
+        TQuery: Serialize,
+    {
+        self.inner.source_query = self.inner.to_value(query);
+        self
+    }
+
+    /** Set the number of documents to copy in each batch. */
+    pub fn source_size(mut self, size: u64) -> Self {
+        self.inner.source_size = Some(size);
+        self
+    }
+
+    /** Set the operation to use when writing documents to the destination index. */
+    pub fn dest_op_type(mut self, op_type: OpType) -> Self {
+        self.inner.dest_op_type = Some(op_type);
+        self
+    }
+
+    /** Set the versioning to use when writing documents to the destination index. */
+    pub fn dest_version_type(mut self, version_type: VersionType) -> Self {
+        self.inner.dest_version_type = Some(version_type);
+        self
+    }
+
+    /**
+    Transform each document using [a script][painless-lang] before it's written to the destination index.
+
+    Subsequent calls to `script` will override any previous script properties.
+
+    # Examples
+
+    Rename a field while reindexing:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::client::requests::document_update::ScriptBuilder;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let script = ScriptBuilder::new("ctx._source.title = ctx._source.remove(params.field)")
+        .param("field", "name");
+
+    let response = client.reindex(index("myindex"), index("myindex-v2"))
+                         .script(script)
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [painless-lang]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-painless.html
+    */
+    pub fn script<TScript, TParams>(mut self, builder: TScript) -> Self
+    where
+        TScript: Into<ScriptBuilder<TParams>>,
+        TParams: Serialize,
+    {
+        self.inner.script = match self.inner.to_value(builder.into().build()) {
+            Some(Value::Object(mut script)) => script.remove("script"),
+            _ => None,
+        };
+
+        self
+    }
+
+    /**
+    Transform each document using [a script][painless-lang] configured by a fluent closure API.
+
+    The fluent API can be more ergonomic to work with than constructing builders directly.
+
+    # Examples
+
+    Rename a field while reindexing:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.reindex(index("myindex"), index("myindex-v2"))
+                         .script_fluent("ctx._source.title = ctx._source.remove(params.field)", |script| script
+                            .param("field", "name"))
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [painless-lang]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-painless.html
+    */
+    pub fn script_fluent<TScript, TBuilder, TParams>(self, source: TScript, builder: TBuilder) -> Self
+    where
+        TScript: ToString,
+        TBuilder: Fn(ScriptBuilder<DefaultParams>) -> ScriptBuilder<TParams>,
+        TParams: Serialize,
+    {
+        let builder = builder(ScriptBuilder::new(source));
+
+        self.script(builder)
+    }
+
+    /**
+    Split the reindex request into a number of slices that are processed in parallel.
+
+    This sets the `slices` parameter.
+    */
+    pub fn slices(self, slices: u32) -> Self {
+        self.params(move |p| p.url_param("slices", slices))
+    }
+
+    /**
+    Run the reindex request in the background.
+
+    Instead of waiting for all documents to be copied, sending the request will return a [`ReindexTask`][ReindexTask] that can be used to check on its progress.
+    This sets the `wait_for_completion` parameter to `false`.
+
+    # Examples
+
+    Start reindexing in the background and wait for it to complete:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let task = client.reindex(index("myindex"), index("myindex-v2"))
+                     .background()
+                     .send()?;
+
+    let response = task.wait_with_progress(|status| {
+        println!("processed {} of {} documents", status.processed(), status.total());
+    })?;
+    # Ok(())
+    # }
+    ```
+
+    [ReindexTask]: type.ReindexTask.html
+    */
+    pub fn background(self) -> ReindexRequestBuilder<TSender, StartedTaskResponse> {
+        let builder = self.params(|p| p.url_param("wait_for_completion", false));
+
+        RequestBuilder::new(builder.client, builder.params, builder.inner.with_response())
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl ReindexRequestBuilder<SyncSender, ReindexResponse> {
+    /**
+    Send a `ReindexRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until all documents have been copied and the response is deserialised.
+
+    # Examples
+
+    Copy documents that match a query from an index called `myindex` to an index called `myindex-v2`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.reindex(index("myindex"), index("myindex-v2"))
+                         .source_query(json!({ "term": { "archived": false } }))
+                         .send()?;
+
+    println!("reindexed {} documents", response.created());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ReindexResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send synchronously in the background
+*/
+impl ReindexRequestBuilder<SyncSender, StartedTaskResponse> {
+    /**
+    Start a `ReindexRequestBuilder` in the background using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until the reindex task has started.
+    The returned [`ReindexTask`][ReindexTask] can be used to check on its progress.
+
+    [SyncClient]: ../../type.SyncClient.html
+    [ReindexTask]: type.ReindexTask.html
+    */
+    pub fn send(self) -> Result<ReindexTask<SyncSender>> {
+        let client = self.client;
+        let req = self.inner.into_request()?;
+
+        let res: StartedTaskResponse = RequestBuilder::new(client.clone(), self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()?;
+
+        Ok(client.task(res.task()))
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl ReindexRequestBuilder<AsyncSender, ReindexResponse> {
+    /**
+    Send a `ReindexRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised reindex response once all documents have been copied.
+
+    # Examples
+
+    Copy all documents from an index called `myindex` to an index called `myindex-v2`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.reindex(index("myindex"), index("myindex-v2"))
+                       .send();
+
+    future.and_then(|response| {
+        println!("reindexed {} documents", response.created());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<ReindexResponse> {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/**
+# Send asynchronously in the background
+*/
+impl ReindexRequestBuilder<AsyncSender, StartedTaskResponse> {
+    /**
+    Start a `ReindexRequestBuilder` in the background using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to a [`ReindexTask`][ReindexTask] once the reindex task has started.
+
+    # Examples
+
+    Start reindexing in the background and wait for it to complete:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.reindex(index("myindex"), index("myindex-v2"))
+                       .background()
+                       .send()
+                       .and_then(|task| task.wait());
+
+    future.and_then(|response| {
+        println!("reindexed {} documents", response.created());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [ReindexTask]: type.ReindexTask.html
+    */
+    pub fn send(self) -> Pending<ReindexTask<AsyncSender>> {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client.clone(), params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response::<StartedTaskResponse>())
+                .map(move |res| client.task(res.task()))
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/**
+A handle to a reindex request running in the background.
+
+Call [`ReindexRequestBuilder.background`][ReindexRequestBuilder.background] to get a `ReindexTask` when the request is sent.
+
+[ReindexRequestBuilder.background]: type.ReindexRequestBuilder.html#method.background
+*/
+pub type ReindexTask<TSender> = TaskHandle<TSender, ReindexResponse, BulkByScrollStatus>;
+
+/** A future returned by calling `send`. */
+pub struct Pending<T> {
+    inner: Box<Future<Item = T, Error = Error>>,
+}
+
+impl<T> Pending<T> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = T, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<T> Future for Pending<T> {
+    type Item = T;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+    use super::{OpType, VersionType};
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .reindex(index("old-idx"), index("new-idx"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "source": {
+                "index": "old-idx"
+            },
+            "dest": {
+                "index": "new-idx"
+            }
+        });
+
+        assert_eq!("/_reindex", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_source() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .reindex(index("old-idx"), index("new-idx"))
+            .source_ty(Some("old-ty"))
+            .source_query(json!({ "match_all": {} }))
+            .source_size(100)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "source": {
+                "index": "old-idx",
+                "type": "old-ty",
+                "query": {
+                    "match_all": {}
+                },
+                "size": 100
+            },
+            "dest": {
+                "index": "new-idx"
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_dest() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .reindex(index("old-idx"), index("new-idx"))
+            .dest_op_type(OpType::Create)
+            .dest_version_type(VersionType::External)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "source": {
+                "index": "old-idx"
+            },
+            "dest": {
+                "index": "new-idx",
+                "op_type": "create",
+                "version_type": "external"
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_script() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .reindex(index("old-idx"), index("new-idx"))
+            .script_fluent("ctx._source.a = params.str", |script| script.param("str", "some value"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "source": {
+                "index": "old-idx"
+            },
+            "dest": {
+                "index": "new-idx"
+            },
+            "script": {
+                "inline": "ctx._source.a = params.str",
+                "params": {
+                    "str": "some value"
+                }
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_slices_background() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .reindex(index("old-idx"), index("new-idx"))
+            .slices(5)
+            .background();
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+
+        assert_eq!("?slices=5&wait_for_completion=false", query.unwrap());
+    }
+}
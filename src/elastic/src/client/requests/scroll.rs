@@ -0,0 +1,575 @@
+/*!
+Builders for [scrolling][docs-scroll] through search hits.
+
+[docs-scroll]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-request-scroll.html
+*/
+
+use futures::{future, Async, Future, Poll, Stream};
+use futures::future::Either;
+use serde::de::DeserializeOwned;
+use serde_json;
+
+use error::{self, Error, Result};
+use client::{AsyncClient, AsyncSender, RequestParams, Sender, SyncClient, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::endpoints::{ClearScrollRequest, ScrollRequest, SearchRequest};
+use client::requests::raw::RawRequestInner;
+use client::requests::search::SearchRequestInner;
+use client::responses::SearchResponse;
+use client::responses::search::{Hit, IntoHits};
+
+const DEFAULT_KEEP_ALIVE: &'static str = "1m";
+
+/**
+A [scroll request][docs-scroll] builder that can be configured before sending.
+
+Call [`SearchRequestBuilder.scroll`][SearchRequestBuilder.scroll] to get a `ScrollRequestBuilder`.
+The `send` method will either return an [iterator][send-sync] or a [stream][send-async] of hits, depending on the `Client` it was created from.
+
+Each page of hits is fetched using the `_scroll_id` returned by the previous page.
+The scroll is always cleared once all hits have been returned, the scroll fails, or the iterator or stream is dropped.
+
+[docs-scroll]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-request-scroll.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[SearchRequestBuilder.scroll]: ../search/type.SearchRequestBuilder.html#method.scroll
+*/
+pub type ScrollRequestBuilder<TSender, TDocument, TBody> = RequestBuilder<TSender, ScrollRequestInner<TDocument, TBody>>;
+
+#[doc(hidden)]
+pub struct ScrollRequestInner<TDocument, TBody> {
+    search: SearchRequestInner<TDocument, TBody>,
+    keep_alive: String,
+}
+
+impl<TDocument, TBody> ScrollRequestInner<TDocument, TBody> {
+    pub(crate) fn new(search: SearchRequestInner<TDocument, TBody>) -> Self {
+        ScrollRequestInner {
+            search: search,
+            keep_alive: DEFAULT_KEEP_ALIVE.into(),
+        }
+    }
+}
+
+impl<TDocument, TBody> ScrollRequestInner<TDocument, TBody>
+where
+    TDocument: DeserializeOwned,
+{
+    fn into_request(self) -> (SearchRequest<'static, TBody>, String) {
+        (self.search.into_request(), self.keep_alive)
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ScrollRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument, TBody> ScrollRequestBuilder<TSender, TDocument, TBody>
+where
+    TSender: Sender,
+{
+    /**
+    Set how long Elasticsearch should keep the search context alive between pages.
+
+    The keep-alive uses Elasticsearch's [time units][docs-units], like `30s` or `5m`.
+    If no keep-alive is specified then `1m` will be used.
+
+    [docs-units]: https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#time-units
+    */
+    pub fn keep_alive<K>(mut self, keep_alive: K) -> Self
+    where
+        K: Into<String>,
+    {
+        self.inner.keep_alive = keep_alive.into();
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument, TBody> ScrollRequestBuilder<SyncSender, TDocument, TBody>
+where
+    TDocument: DeserializeOwned,
+    TBody: Into<<SyncSender as Sender>::Body>,
+{
+    /**
+    Send a `ScrollRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until the first page of hits is returned.
+    The returned [`ScrollIter`][ScrollIter] will block the current thread each time it needs to fetch the next page.
+
+    # Examples
+
+    Iterate through all documents of type `MyType` in an index:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let hits = client.search::<MyType>()
+                     .index("myindex")
+                     .scroll()
+                     .keep_alive("5m")
+                     .send()?;
+
+    for hit in hits {
+        let hit = hit?;
+
+        println!("{}: {:?}", hit.id(), hit.document());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    [ScrollIter]: struct.ScrollIter.html
+    */
+    pub fn send(self) -> Result<ScrollIter<TDocument>> {
+        let client = self.client;
+        let params = self.params.unwrap_or_else(|| client.params.clone());
+        let (req, keep_alive) = self.inner.into_request();
+        let search_params = params.clone().url_param("scroll", keep_alive.clone());
+        let params = scroll_params(&params);
+
+        let res = RequestBuilder::new(client.clone(), Some(search_params), RawRequestInner::new(req))
+            .send()?
+            .into_response::<SearchResponse<TDocument>>()?;
+
+        let mut hits = ScrollIter {
+            client: client,
+            params: params,
+            keep_alive: keep_alive,
+            scroll_id: None,
+            hits: None,
+        };
+
+        hits.set_page(res);
+
+        Ok(hits)
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument, TBody> ScrollRequestBuilder<AsyncSender, TDocument, TBody>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+    TBody: Into<<AsyncSender as Sender>::Body>,
+{
+    /**
+    Send a `ScrollRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a [`ScrollStream`][ScrollStream] that sends the search request when it's first polled.
+
+    If the stream is dropped before all hits have been returned then the scroll is cleared in the background on the `Handle` the `AsyncClient` was built with.
+
+    # Examples
+
+    Iterate through all documents of type `MyType` in an index:
+
+    ```no_run
+    # extern crate tokio_core;
+    # extern crate futures;
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use futures::Stream;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let mut core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let hits = client.search::<MyType>()
+                     .index("myindex")
+                     .scroll()
+                     .keep_alive("5m")
+                     .send();
+
+    let future = hits.for_each(|hit| {
+        println!("{}: {:?}", hit.id(), hit.document());
+
+        Ok(())
+    });
+
+    core.run(future)?;
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [ScrollStream]: struct.ScrollStream.html
+    */
+    pub fn send(self) -> ScrollStream<TDocument> {
+        let client = self.client;
+        let params = self.params.unwrap_or_else(|| client.params.clone());
+        let (req, keep_alive) = self.inner.into_request();
+        let search_params = params.clone().url_param("scroll", keep_alive.clone());
+        let params = scroll_params(&params);
+
+        let res_future = RequestBuilder::new(client.clone(), Some(search_params), RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response::<SearchResponse<TDocument>>());
+
+        ScrollStream {
+            client: client,
+            params: params,
+            keep_alive: keep_alive,
+            scroll_id: None,
+            hits: None,
+            pending: Some(Box::new(res_future)),
+            clearing: None,
+        }
+    }
+}
+
+/**
+An iterator over all of the hits matched by a search request.
+
+The iterator returns an error if a page of hits can't be fetched, after which it won't return any more hits.
+The scroll is cleared when the iterator is exhausted or dropped.
+*/
+pub struct ScrollIter<TDocument> {
+    client: SyncClient,
+    params: RequestParams,
+    keep_alive: String,
+    scroll_id: Option<String>,
+    hits: Option<IntoHits<TDocument>>,
+}
+
+impl<TDocument> ScrollIter<TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    fn set_page(&mut self, res: SearchResponse<TDocument>) {
+        let has_hits = res.hits().next().is_some();
+
+        self.scroll_id = res.scroll_id().map(|scroll_id| scroll_id.to_owned());
+        self.hits = Some(res.into_hits());
+
+        if !has_hits {
+            self.clear();
+        }
+    }
+
+    fn next_page(&self, scroll_id: &str) -> Result<SearchResponse<TDocument>> {
+        let req = scroll_request(scroll_id, &self.keep_alive)?;
+
+        RequestBuilder::new(self.client.clone(), Some(self.params.clone()), RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+impl<TDocument> ScrollIter<TDocument> {
+    fn clear(&mut self) {
+        if let Some(scroll_id) = self.scroll_id.take() {
+            if let Ok(req) = clear_scroll_request(&scroll_id) {
+                let _ = RequestBuilder::new(self.client.clone(), Some(self.params.clone()), RawRequestInner::new(req)).send();
+            }
+        }
+    }
+}
+
+impl<TDocument> Iterator for ScrollIter<TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    type Item = Result<Hit<TDocument>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(hit) = self.hits.as_mut().and_then(|hits| hits.next()) {
+                return Some(Ok(hit));
+            }
+
+            let res = match self.scroll_id {
+                Some(ref scroll_id) => self.next_page(scroll_id),
+                None => return None,
+            };
+
+            match res {
+                Ok(res) => self.set_page(res),
+                Err(e) => {
+                    self.clear();
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+impl<TDocument> Drop for ScrollIter<TDocument> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+type PendingPage<TDocument> = Box<Future<Item = SearchResponse<TDocument>, Error = Error>>;
+type PendingClear = Box<Future<Item = (), Error = ()>>;
+
+/**
+A stream of all of the hits matched by a search request.
+
+The stream returns an error if a page of hits can't be fetched, after which it won't return any more hits.
+The scroll is cleared when the stream ends or is dropped.
+*/
+pub struct ScrollStream<TDocument>
+where
+    TDocument: 'static,
+{
+    client: AsyncClient,
+    params: RequestParams,
+    keep_alive: String,
+    scroll_id: Option<String>,
+    hits: Option<IntoHits<TDocument>>,
+    pending: Option<PendingPage<TDocument>>,
+    clearing: Option<PendingClear>,
+}
+
+impl<TDocument> ScrollStream<TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    fn set_page(&mut self, res: SearchResponse<TDocument>) {
+        let has_hits = res.hits().next().is_some();
+
+        self.scroll_id = res.scroll_id().map(|scroll_id| scroll_id.to_owned());
+        self.hits = Some(res.into_hits());
+
+        if !has_hits {
+            self.clear();
+        }
+    }
+
+    fn next_page(&self, scroll_id: &str) -> PendingPage<TDocument> {
+        let req = match scroll_request(scroll_id, &self.keep_alive) {
+            Ok(req) => req,
+            Err(e) => return Box::new(future::err(e)),
+        };
+
+        let res_future = RequestBuilder::new(self.client.clone(), Some(self.params.clone()), RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Box::new(res_future)
+    }
+}
+
+impl<TDocument> ScrollStream<TDocument>
+where
+    TDocument: 'static,
+{
+    fn clear(&mut self) {
+        self.clearing = self.scroll_id.take().and_then(|scroll_id| clear_scroll(&self.client, &self.params, &scroll_id));
+    }
+}
+
+impl<TDocument> Stream for ScrollStream<TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    type Item = Hit<TDocument>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            if let Some(hit) = self.hits.as_mut().and_then(|hits| hits.next()) {
+                return Ok(Async::Ready(Some(hit)));
+            }
+
+            if let Some(mut clearing) = self.clearing.take() {
+                if let Ok(Async::NotReady) = clearing.poll() {
+                    self.clearing = Some(clearing);
+                    return Ok(Async::NotReady);
+                }
+
+                return Ok(Async::Ready(None));
+            }
+
+            if self.pending.is_none() {
+                self.pending = match self.scroll_id {
+                    Some(ref scroll_id) => Some(self.next_page(scroll_id)),
+                    None => return Ok(Async::Ready(None)),
+                };
+            }
+
+            let res = match self.pending.as_mut().map(|pending| pending.poll()) {
+                Some(Ok(Async::NotReady)) | None => return Ok(Async::NotReady),
+                Some(Ok(Async::Ready(res))) => res,
+                Some(Err(e)) => {
+                    self.pending = None;
+                    self.clear();
+
+                    return Err(e);
+                }
+            };
+
+            self.pending = None;
+            self.set_page(res);
+        }
+    }
+}
+
+impl<TDocument> Drop for ScrollStream<TDocument>
+where
+    TDocument: 'static,
+{
+    fn drop(&mut self) {
+        let first_page = self.scroll_id.is_none() && self.hits.is_none();
+
+        if self.scroll_id.is_some() {
+            self.clear();
+        }
+
+        let handle = match self.client.sender.handle {
+            Some(ref handle) => handle.clone(),
+            None => return,
+        };
+
+        if let Some(clearing) = self.clearing.take() {
+            handle.spawn(clearing);
+        }
+
+        // The scroll id isn't known until the first page arrives, so clear the scroll once it does
+        if let (true, Some(pending)) = (first_page, self.pending.take()) {
+            let (client, params) = (self.client.clone(), self.params.clone());
+
+            let clearing = pending.then(move |res| {
+                match res.ok().and_then(|res| res.scroll_id().and_then(|scroll_id| clear_scroll(&client, &params, scroll_id))) {
+                    Some(clearing) => Either::A(clearing),
+                    None => Either::B(future::ok(())),
+                }
+            });
+
+            handle.spawn(clearing);
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ScrollBody<'a> {
+    scroll: &'a str,
+    scroll_id: &'a str,
+}
+
+#[derive(Serialize)]
+struct ClearScrollBody<'a> {
+    scroll_id: &'a str,
+}
+
+/**
+Get the parameters for the scroll and clear scroll requests that follow a search.
+
+The scroll endpoints reject url parameters that only apply to searches, like `routing`, so only the base url, headers and timeout are kept.
+*/
+fn scroll_params(params: &RequestParams) -> RequestParams {
+    match params.get_timeout() {
+        Some(timeout) => params.base_params().timeout(timeout),
+        None => params.base_params(),
+    }
+}
+
+fn scroll_request(scroll_id: &str, keep_alive: &str) -> Result<ScrollRequest<'static, Vec<u8>>> {
+    let body = serde_json::to_vec(&ScrollBody {
+        scroll: keep_alive,
+        scroll_id: scroll_id,
+    }).map_err(error::request)?;
+
+    Ok(ScrollRequest::new(body))
+}
+
+fn clear_scroll_request(scroll_id: &str) -> Result<ClearScrollRequest<'static, Vec<u8>>> {
+    let body = serde_json::to_vec(&ClearScrollBody { scroll_id: scroll_id }).map_err(error::request)?;
+
+    Ok(ClearScrollRequest::new(body))
+}
+
+fn clear_scroll(client: &AsyncClient, params: &RequestParams, scroll_id: &str) -> Option<PendingClear> {
+    let req = match clear_scroll_request(scroll_id) {
+        Ok(req) => req,
+        Err(_) => return None,
+    };
+
+    let clearing = RequestBuilder::new(client.clone(), Some(params.clone()), RawRequestInner::new(req))
+        .send()
+        .map(|_| ())
+        .map_err(|_| ());
+
+    Some(Box::new(clearing))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use prelude::*;
+    use super::{clear_scroll_request, scroll_params, scroll_request};
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let (req, keep_alive) = client.search::<Value>().scroll().inner.into_request();
+
+        assert_eq!("/_all/_search", req.url.as_ref());
+        assert_eq!("1m", keep_alive);
+    }
+
+    #[test]
+    fn specify_keep_alive() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let (_, keep_alive) = client
+            .search::<Value>()
+            .scroll()
+            .keep_alive("5m")
+            .inner
+            .into_request();
+
+        assert_eq!("5m", keep_alive);
+    }
+
+    #[test]
+    fn scroll_request_body() {
+        let req = scroll_request("abc", "1m").unwrap();
+
+        assert_eq!("/_search/scroll", req.url.as_ref());
+        assert_eq!(r#"{"scroll":"1m","scroll_id":"abc"}"#.as_bytes(), &req.body[..]);
+    }
+
+    #[test]
+    fn clear_scroll_request_body() {
+        let req = clear_scroll_request("abc").unwrap();
+
+        assert_eq!("/_search/scroll", req.url.as_ref());
+        assert_eq!(r#"{"scroll_id":"abc"}"#.as_bytes(), &req.body[..]);
+    }
+
+    #[test]
+    fn scroll_params_only_keep_base_params() {
+        use std::time::Duration;
+
+        let params = RequestParams::new("http://eshost:9200")
+            .url_param("routing", "abc")
+            .url_param("preference", "_local")
+            .timeout(Duration::from_secs(5));
+
+        let params = scroll_params(&params);
+
+        assert_eq!("http://eshost:9200", params.get_base_url());
+        assert_eq!((0, None), params.get_url_qry());
+        assert_eq!(Some(Duration::from_secs(5)), params.get_timeout());
+    }
+}
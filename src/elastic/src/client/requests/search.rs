@@ -14,6 +14,7 @@ use client::requests::{empty_body, DefaultBody, RequestBuilder};
 use client::requests::params::{Index, Type};
 use client::requests::endpoints::SearchRequest;
 use client::requests::raw::RawRequestInner;
+use client::requests::scroll::{ScrollRequestBuilder, ScrollRequestInner};
 use client::responses::SearchResponse;
 
 /**
@@ -139,7 +140,7 @@ where
         }
     }
 
-    fn into_request(self) -> SearchRequest<'static, TBody> {
+    pub(crate) fn into_request(self) -> SearchRequest<'static, TBody> {
         let index = self.index.unwrap_or_else(|| "_all".into());
 
         match self.ty {
@@ -200,6 +201,18 @@ where
             },
         )
     }
+
+    /**
+    Scroll through all of the hits matched by the search request.
+
+    Instead of returning a single page of hits, the returned [`ScrollRequestBuilder`][ScrollRequestBuilder] fetches pages lazily as hits are consumed.
+    See [`ScrollRequestBuilder`][ScrollRequestBuilder] for more details.
+
+    [ScrollRequestBuilder]: ../scroll/type.ScrollRequestBuilder.html
+    */
+    pub fn scroll(self) -> ScrollRequestBuilder<TSender, TDocument, TBody> {
+        RequestBuilder::new(self.client, self.params, ScrollRequestInner::new(self.inner))
+    }
 }
 
 /**
@@ -0,0 +1,544 @@
+/*!
+Builders for [search template requests][docs-search-template].
+
+[docs-search-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html
+*/
+
+use std::marker::PhantomData;
+use futures::{Future, Poll};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{self, Value};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Type};
+use client::requests::endpoints::SearchTemplateRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::document_update::DefaultParams;
+use client::responses::SearchResponse;
+
+/**
+A [search template request][docs-search-template] builder that can be configured before sending.
+
+Call [`Client.search_template`][Client.search_template] to get a `SearchTemplateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-search-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.search_template]: ../../struct.Client.html#search-template-request
+*/
+pub type SearchTemplateRequestBuilder<TSender, TDocument, TParams> = RequestBuilder<TSender, SearchTemplateRequestInner<TDocument, TParams>>;
+
+#[doc(hidden)]
+pub struct SearchTemplateRequestInner<TDocument, TParams> {
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    template: SearchTemplateBuilder<TParams>,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Search template request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SearchTemplateRequestBuilder`][SearchTemplateRequestBuilder] with this `Client` that can be configured before sending.
+
+    The template is either the id of a stored template or an inline template source.
+    Templates are rendered with the given parameters and the result is used as the body of a regular search request.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Search for a [`DocumentType`][documents-mod] called `MyType` using a stored template called `title_search`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: i32,
+    #     pub title: String,
+    #     pub timestamp: Date<DefaultDateMapping>
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.search_template::<MyType>()
+                         .index("myindex")
+                         .template(SearchTemplateBuilder::id("title_search").param("query_string", "a title"))
+                         .send()?;
+
+    for hit in response.hits() {
+        println!("{:?}", hit);
+    }
+    # Ok(())
+    # }
+    ```
+
+    Parameters can also be any serialisable type:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    #[derive(Serialize)]
+    struct TitleSearch {
+        query_string: String,
+        size: usize,
+    }
+
+    let template = SearchTemplateBuilder::inline(json!({
+        "query": {
+            "match": {
+                "title": "{{query_string}}"
+            }
+        },
+        "size": "{{size}}"
+    }));
+
+    let template = template.params(TitleSearch {
+        query_string: String::from("a title"),
+        size: 10,
+    });
+
+    let response = client.search_template::<Value>()
+                         .index("myindex")
+                         .template(template)
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [SearchTemplateRequestBuilder]: requests/search_template/type.SearchTemplateRequestBuilder.html
+    [builder-methods]: requests/search_template/type.SearchTemplateRequestBuilder.html#builder-methods
+    [send-sync]: requests/search_template/type.SearchTemplateRequestBuilder.html#send-synchronously
+    [send-async]: requests/search_template/type.SearchTemplateRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn search_template<TDocument>(&self) -> SearchTemplateRequestBuilder<TSender, TDocument, DefaultParams>
+    where
+        TDocument: DeserializeOwned,
+    {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            SearchTemplateRequestInner {
+                index: None,
+                ty: None,
+                template: SearchTemplateBuilder::empty(),
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TDocument, TParams> SearchTemplateRequestInner<TDocument, TParams>
+where
+    TParams: Serialize,
+{
+    fn into_request(self) -> Result<SearchTemplateRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&self.template).map_err(error::request)?;
+        let index = self.index.unwrap_or_else(|| "_all".into());
+
+        let req = match self.ty {
+            Some(ty) => SearchTemplateRequest::for_index_ty(index, ty, body),
+            None => SearchTemplateRequest::for_index(index, body),
+        };
+
+        Ok(req)
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `SearchTemplateRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument, TParams> SearchTemplateRequestBuilder<TSender, TDocument, TParams>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices for the search template request.
+
+    If no index is specified then `_all` will be used.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /** Set the types for the search template request. */
+    pub fn ty<I>(mut self, ty: Option<I>) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.map(Into::into);
+        self
+    }
+
+    /**
+    Set the template to search with.
+
+    A string is treated as the id of a stored template.
+    */
+    pub fn template<TTemplate, TNewParams>(self, template: TTemplate) -> SearchTemplateRequestBuilder<TSender, TDocument, TNewParams>
+    where
+        TTemplate: Into<SearchTemplateBuilder<TNewParams>>,
+    {
+        RequestBuilder::new(
+            self.client,
+            self.params,
+            SearchTemplateRequestInner {
+                index: self.inner.index,
+                ty: self.inner.ty,
+                template: template.into(),
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument, TParams> SearchTemplateRequestBuilder<SyncSender, TDocument, TParams>
+where
+    TDocument: DeserializeOwned,
+    TParams: Serialize,
+{
+    /**
+    Send a `SearchTemplateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Search an index called `myindex` using a stored template called `title_search`:
+
+    ```no_run
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.search_template::<Value>()
+                         .index("myindex")
+                         .template(SearchTemplateBuilder::id("title_search").param("query_string", "a title"))
+                         .send()?;
+
+    for hit in response.hits() {
+        println!("{:?}", hit);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<SearchResponse<TDocument>> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument, TParams> SearchTemplateRequestBuilder<AsyncSender, TDocument, TParams>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+    TParams: Serialize + Send + 'static,
+{
+    /**
+    Send a `SearchTemplateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised search response.
+
+    # Examples
+
+    Search an index called `myindex` using a stored template called `title_search`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.search_template::<Value>()
+                       .index("myindex")
+                       .template(SearchTemplateBuilder::id("title_search").param("query_string", "a title"))
+                       .send();
+
+    future.and_then(|response| {
+        for hit in response.hits() {
+            println!("{:?}", hit);
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TDocument> {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending<TDocument> {
+    inner: Box<Future<Item = SearchResponse<TDocument>, Error = Error>>,
+}
+
+impl<TDocument> Pending<TDocument> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = SearchResponse<TDocument>, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<TDocument> Future for Pending<TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    type Item = SearchResponse<TDocument>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+/**
+A builder for a search template that can be configured before sending.
+
+The template is either the id of a stored template or an inline template source, along with the parameters to render it with.
+*/
+#[derive(Serialize)]
+pub struct SearchTemplateBuilder<TParams> {
+    #[serde(skip_serializing_if = "Option::is_none")] id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] inline: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")] params: Option<TParams>,
+}
+
+impl SearchTemplateBuilder<DefaultParams> {
+    pub(crate) fn empty() -> Self {
+        SearchTemplateBuilder {
+            id: None,
+            inline: None,
+            params: None,
+        }
+    }
+
+    /** Create a new search template builder using the id of a stored template. */
+    pub fn id<TId>(id: TId) -> Self
+    where
+        TId: ToString,
+    {
+        SearchTemplateBuilder {
+            id: Some(id.to_string()),
+            ..SearchTemplateBuilder::empty()
+        }
+    }
+
+    /**
+    Create a new search template builder using an inline template source.
+
+    The source can be a search body with `{{placeholders}}` or a string, which is needed for templates that use sections like `{{#param}}`.
+    */
+    pub fn inline<TSource>(source: TSource) -> Self
+    where
+        TSource: Into<Value>,
+    {
+        SearchTemplateBuilder {
+            inline: Some(source.into()),
+            ..SearchTemplateBuilder::empty()
+        }
+    }
+
+    /** Set a template parameter. */
+    pub fn param<TKey, TValue>(mut self, key: TKey, value: TValue) -> Self
+    where
+        TKey: ToString,
+        TValue: Into<Value>,
+    {
+        let mut params = self.params.unwrap_or_else(DefaultParams::new);
+        params.insert(key.to_string(), value.into());
+
+        self.params = Some(params);
+        self
+    }
+}
+
+impl<TParams> SearchTemplateBuilder<TParams> {
+    /** Specify a new set of parameters for the search template. */
+    pub fn params<TNewParams>(self, params: TNewParams) -> SearchTemplateBuilder<TNewParams> {
+        SearchTemplateBuilder {
+            id: self.id,
+            inline: self.inline,
+            params: Some(params),
+        }
+    }
+}
+
+impl From<String> for SearchTemplateBuilder<DefaultParams> {
+    fn from(id: String) -> Self {
+        SearchTemplateBuilder::id(id)
+    }
+}
+
+impl<'a> From<&'a str> for SearchTemplateBuilder<DefaultParams> {
+    fn from(id: &'a str) -> Self {
+        SearchTemplateBuilder::id(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use super::SearchTemplateBuilder;
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .search_template::<Value>()
+            .template("title_search")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/_all/_search/template", req.url.as_ref());
+        assert_eq!(json!({ "id": "title_search" }), serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_index_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .search_template::<Value>()
+            .index("new-idx")
+            .ty(Some("new-ty"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/new-idx/new-ty/_search/template", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_template_params() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .search_template::<Value>()
+            .template(SearchTemplateBuilder::id("title_search").param("query_string", "a title"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "id": "title_search",
+            "params": {
+                "query_string": "a title"
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_inline_template_typed_params() {
+        #[derive(Serialize)]
+        struct TitleSearch {
+            query_string: &'static str,
+        }
+
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let template = SearchTemplateBuilder::inline(json!({
+            "query": {
+                "match": {
+                    "title": "{{query_string}}"
+                }
+            }
+        })).params(TitleSearch { query_string: "a title" });
+
+        let req = client
+            .search_template::<Value>()
+            .template(template)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "inline": {
+                "query": {
+                    "match": {
+                        "title": "{{query_string}}"
+                    }
+                }
+            },
+            "params": {
+                "query_string": "a title"
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
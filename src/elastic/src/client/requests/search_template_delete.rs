@@ -0,0 +1,203 @@
+/*!
+Builders for [delete stored search template requests][docs-stored-templates].
+
+[docs-stored-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html#pre-registered-templates
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Id;
+use client::requests::endpoints::DeleteTemplateRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+
+/**
+A [delete stored search template request][docs-stored-templates] builder that can be configured before sending.
+
+Call [`Client.search_template_delete`][Client.search_template_delete] to get a `SearchTemplateDeleteRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-stored-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html#pre-registered-templates
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.search_template_delete]: ../../struct.Client.html#delete-stored-search-template-request
+*/
+pub type SearchTemplateDeleteRequestBuilder<TSender> = RequestBuilder<TSender, SearchTemplateDeleteRequestInner>;
+
+#[doc(hidden)]
+pub struct SearchTemplateDeleteRequestInner {
+    id: Id<'static>,
+}
+
+/**
+# Delete stored search template request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SearchTemplateDeleteRequestBuilder`][SearchTemplateDeleteRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Delete a stored template called `title_search`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.search_template_delete(id("title_search")).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SearchTemplateDeleteRequestBuilder]: requests/search_template_delete/type.SearchTemplateDeleteRequestBuilder.html
+    [send-sync]: requests/search_template_delete/type.SearchTemplateDeleteRequestBuilder.html#send-synchronously
+    [send-async]: requests/search_template_delete/type.SearchTemplateDeleteRequestBuilder.html#send-asynchronously
+    */
+    pub fn search_template_delete(&self, id: Id<'static>) -> SearchTemplateDeleteRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, SearchTemplateDeleteRequestInner { id: id })
+    }
+}
+
+impl SearchTemplateDeleteRequestInner {
+    fn into_request(self) -> DeleteTemplateRequest<'static> {
+        DeleteTemplateRequest::for_id(self.id)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SearchTemplateDeleteRequestBuilder<SyncSender> {
+    /**
+    Send a `SearchTemplateDeleteRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Delete a stored template called `title_search`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.search_template_delete(id("title_search")).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SearchTemplateDeleteRequestBuilder<AsyncSender> {
+    /**
+    Send a `SearchTemplateDeleteRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Delete a stored template called `title_search`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.search_template_delete(id("title_search")).send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.search_template_delete(id("title_search")).inner.into_request();
+
+        assert_eq!("/_search/template/title_search", req.url.as_ref());
+    }
+}
@@ -0,0 +1,209 @@
+/*!
+Builders for [get stored search template requests][docs-stored-templates].
+
+[docs-stored-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html#pre-registered-templates
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Id;
+use client::requests::endpoints::GetTemplateRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::GetSearchTemplateResponse;
+
+/**
+A [get stored search template request][docs-stored-templates] builder that can be configured before sending.
+
+Call [`Client.search_template_get`][Client.search_template_get] to get a `SearchTemplateGetRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-stored-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html#pre-registered-templates
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.search_template_get]: ../../struct.Client.html#get-stored-search-template-request
+*/
+pub type SearchTemplateGetRequestBuilder<TSender> = RequestBuilder<TSender, SearchTemplateGetRequestInner>;
+
+#[doc(hidden)]
+pub struct SearchTemplateGetRequestInner {
+    id: Id<'static>,
+}
+
+/**
+# Get stored search template request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SearchTemplateGetRequestBuilder`][SearchTemplateGetRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get a stored template called `title_search`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.search_template_get(id("title_search")).send()?;
+
+    if let Some(template) = response.template() {
+        println!("{}", template);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SearchTemplateGetRequestBuilder]: requests/search_template_get/type.SearchTemplateGetRequestBuilder.html
+    [send-sync]: requests/search_template_get/type.SearchTemplateGetRequestBuilder.html#send-synchronously
+    [send-async]: requests/search_template_get/type.SearchTemplateGetRequestBuilder.html#send-asynchronously
+    */
+    pub fn search_template_get(&self, id: Id<'static>) -> SearchTemplateGetRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, SearchTemplateGetRequestInner { id: id })
+    }
+}
+
+impl SearchTemplateGetRequestInner {
+    fn into_request(self) -> GetTemplateRequest<'static> {
+        GetTemplateRequest::for_id(self.id)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SearchTemplateGetRequestBuilder<SyncSender> {
+    /**
+    Send a `SearchTemplateGetRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get a stored template called `title_search`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.search_template_get(id("title_search")).send()?;
+
+    if let Some(template) = response.template() {
+        println!("{}", template);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<GetSearchTemplateResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SearchTemplateGetRequestBuilder<AsyncSender> {
+    /**
+    Send a `SearchTemplateGetRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get stored search template response.
+
+    # Examples
+
+    Get a stored template called `title_search`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.search_template_get(id("title_search")).send();
+
+    future.and_then(|response| {
+        if let Some(template) = response.template() {
+            println!("{}", template);
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = GetSearchTemplateResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = GetSearchTemplateResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = GetSearchTemplateResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.search_template_get(id("title_search")).inner.into_request();
+
+        assert_eq!("/_search/template/title_search", req.url.as_ref());
+    }
+}
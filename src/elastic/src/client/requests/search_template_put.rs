@@ -0,0 +1,308 @@
+/*!
+Builders for [put stored search template requests][docs-stored-templates].
+
+[docs-stored-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html#pre-registered-templates
+*/
+
+use serde_json::{self, Value};
+use futures::{Future, IntoFuture, Poll};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Id;
+use client::requests::endpoints::PutTemplateRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+
+/**
+A [put stored search template request][docs-stored-templates] builder that can be configured before sending.
+
+Call [`Client.search_template_put`][Client.search_template_put] to get a `SearchTemplatePutRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-stored-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html#pre-registered-templates
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.search_template_put]: ../../struct.Client.html#put-stored-search-template-request
+*/
+pub type SearchTemplatePutRequestBuilder<TSender> = RequestBuilder<TSender, SearchTemplatePutRequestInner>;
+
+#[doc(hidden)]
+pub struct SearchTemplatePutRequestInner {
+    id: Id<'static>,
+    template: Value,
+}
+
+/**
+# Put stored search template request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SearchTemplatePutRequestBuilder`][SearchTemplatePutRequestBuilder] with this `Client` that can be configured before sending.
+
+    The template can be a search body with `{{placeholders}}` or a string.
+    If a template with the same id already exists then it's replaced.
+    Stored templates can be used by id in a [`search_template`][Client.search_template] request, so the template can change without changing the code that searches with it.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Put a template called `title_search` that matches a title:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let template = json!({
+        "query": {
+            "match": {
+                "title": "{{query_string}}"
+            }
+        }
+    });
+
+    let response = client.search_template_put(id("title_search"), template)
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SearchTemplatePutRequestBuilder]: requests/search_template_put/type.SearchTemplatePutRequestBuilder.html
+    [builder-methods]: requests/search_template_put/type.SearchTemplatePutRequestBuilder.html#builder-methods
+    [send-sync]: requests/search_template_put/type.SearchTemplatePutRequestBuilder.html#send-synchronously
+    [send-async]: requests/search_template_put/type.SearchTemplatePutRequestBuilder.html#send-asynchronously
+    [Client.search_template]: #search-template-request
+    */
+    pub fn search_template_put<TTemplate>(&self, id: Id<'static>, template: TTemplate) -> SearchTemplatePutRequestBuilder<TSender>
+    where
+        TTemplate: Into<Value>,
+    {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            SearchTemplatePutRequestInner {
+                id: id,
+                template: template.into(),
+            },
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct PutTemplateBody<'a> {
+    template: &'a Value,
+}
+
+impl SearchTemplatePutRequestInner {
+    fn into_request(self) -> Result<PutTemplateRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&PutTemplateBody { template: &self.template }).map_err(error::request)?;
+
+        Ok(PutTemplateRequest::for_id(self.id, body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `SearchTemplatePutRequestBuilder` before sending it.
+*/
+impl<TSender> SearchTemplatePutRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /** Set the template to put, replacing the template the builder was created with. */
+    pub fn template<TTemplate>(mut self, template: TTemplate) -> Self
+    where
+        TTemplate: Into<Value>,
+    {
+        self.inner.template = template.into();
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SearchTemplatePutRequestBuilder<SyncSender> {
+    /**
+    Send a `SearchTemplatePutRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Put a template called `title_search` as a string:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let template = r#"{ "query": { "match": { "title": "{{query_string}}" } } }"#;
+
+    let response = client.search_template_put(id("title_search"), template)
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SearchTemplatePutRequestBuilder<AsyncSender> {
+    /**
+    Send a `SearchTemplatePutRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Put a template called `title_search` as a string:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let template = r#"{ "query": { "match": { "title": "{{query_string}}" } } }"#;
+
+    let future = client.search_template_put(id("title_search"), template)
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let template = json!({
+            "query": {
+                "match": {
+                    "title": "{{query_string}}"
+                }
+            }
+        });
+
+        let req = client
+            .search_template_put(id("title_search"), template)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "template": {
+                "query": {
+                    "match": {
+                        "title": "{{query_string}}"
+                    }
+                }
+            }
+        });
+
+        assert_eq!("/_search/template/title_search", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_template() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .search_template_put(id("title_search"), json!({}))
+            .template("{{#query_string}}{}{{/query_string}}")
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "template": "{{#query_string}}{}{{/query_string}}"
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
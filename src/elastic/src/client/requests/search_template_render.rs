@@ -0,0 +1,288 @@
+/*!
+Builders for [render search template requests][docs-render-template].
+
+[docs-render-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html#_validating_templates
+*/
+
+use futures::{Future, Poll};
+use serde::Serialize;
+use serde_json;
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::endpoints::RenderSearchTemplateRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::document_update::DefaultParams;
+use client::requests::search_template::SearchTemplateBuilder;
+use client::responses::RenderSearchTemplateResponse;
+
+/**
+A [render search template request][docs-render-template] builder that can be configured before sending.
+
+Call [`Client.search_template_render`][Client.search_template_render] to get a `SearchTemplateRenderRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-render-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html#_validating_templates
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.search_template_render]: ../../struct.Client.html#render-search-template-request
+*/
+pub type SearchTemplateRenderRequestBuilder<TSender, TParams> = RequestBuilder<TSender, SearchTemplateRenderRequestInner<TParams>>;
+
+#[doc(hidden)]
+pub struct SearchTemplateRenderRequestInner<TParams> {
+    template: SearchTemplateBuilder<TParams>,
+}
+
+/**
+# Render search template request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SearchTemplateRenderRequestBuilder`][SearchTemplateRenderRequestBuilder] with this `Client` that can be configured before sending.
+
+    The request renders a template with the given parameters without searching, so it can be used to check what a template expands to.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Render a stored template called `title_search`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.search_template_render()
+                         .template(SearchTemplateBuilder::id("title_search").param("query_string", "a title"))
+                         .send()?;
+
+    println!("{}", response.template_output());
+    # Ok(())
+    # }
+    ```
+
+    [SearchTemplateRenderRequestBuilder]: requests/search_template_render/type.SearchTemplateRenderRequestBuilder.html
+    [builder-methods]: requests/search_template_render/type.SearchTemplateRenderRequestBuilder.html#builder-methods
+    [send-sync]: requests/search_template_render/type.SearchTemplateRenderRequestBuilder.html#send-synchronously
+    [send-async]: requests/search_template_render/type.SearchTemplateRenderRequestBuilder.html#send-asynchronously
+    */
+    pub fn search_template_render(&self) -> SearchTemplateRenderRequestBuilder<TSender, DefaultParams> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            SearchTemplateRenderRequestInner {
+                template: SearchTemplateBuilder::empty(),
+            },
+        )
+    }
+}
+
+impl<TParams> SearchTemplateRenderRequestInner<TParams>
+where
+    TParams: Serialize,
+{
+    fn into_request(self) -> Result<RenderSearchTemplateRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&self.template).map_err(error::request)?;
+
+        Ok(RenderSearchTemplateRequest::new(body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `SearchTemplateRenderRequestBuilder` before sending it.
+*/
+impl<TSender, TParams> SearchTemplateRenderRequestBuilder<TSender, TParams>
+where
+    TSender: Sender,
+{
+    /**
+    Set the template to render.
+
+    A string is treated as the id of a stored template.
+    */
+    pub fn template<TTemplate, TNewParams>(self, template: TTemplate) -> SearchTemplateRenderRequestBuilder<TSender, TNewParams>
+    where
+        TTemplate: Into<SearchTemplateBuilder<TNewParams>>,
+    {
+        RequestBuilder::new(
+            self.client,
+            self.params,
+            SearchTemplateRenderRequestInner {
+                template: template.into(),
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TParams> SearchTemplateRenderRequestBuilder<SyncSender, TParams>
+where
+    TParams: Serialize,
+{
+    /**
+    Send a `SearchTemplateRenderRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Render an inline template:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let template = SearchTemplateBuilder::inline(json!({
+        "query": {
+            "match": {
+                "title": "{{query_string}}"
+            }
+        }
+    }));
+
+    let response = client.search_template_render()
+                         .template(template.param("query_string", "a title"))
+                         .send()?;
+
+    println!("{}", response.template_output());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<RenderSearchTemplateResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TParams> SearchTemplateRenderRequestBuilder<AsyncSender, TParams>
+where
+    TParams: Serialize + Send + 'static,
+{
+    /**
+    Send a `SearchTemplateRenderRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised render search template response.
+
+    # Examples
+
+    Render a stored template called `title_search`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.search_template_render()
+                       .template(SearchTemplateBuilder::id("title_search").param("query_string", "a title"))
+                       .send();
+
+    future.and_then(|response| {
+        println!("{}", response.template_output());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = RenderSearchTemplateResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = RenderSearchTemplateResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = RenderSearchTemplateResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .search_template_render()
+            .template(SearchTemplateBuilder::id("title_search").param("query_string", "a title"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "id": "title_search",
+            "params": {
+                "query_string": "a title"
+            }
+        });
+
+        assert_eq!("/_render/template", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
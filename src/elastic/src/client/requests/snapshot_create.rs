@@ -0,0 +1,339 @@
+/*!
+Builders for [create snapshot requests][docs-snapshots].
+
+[docs-snapshots]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot
+*/
+
+use serde_json;
+use futures::{Future, IntoFuture, Poll};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Repository, Snapshot};
+use client::requests::endpoints::SnapshotCreateRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CreateSnapshotResponse;
+
+/**
+A [create snapshot request][docs-snapshots] builder that can be configured before sending.
+
+Call [`Client.snapshot_create`][Client.snapshot_create] to get a `SnapshotCreateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-snapshots]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.snapshot_create]: ../../struct.Client.html#create-snapshot-request
+*/
+pub type SnapshotCreateRequestBuilder<TSender> = RequestBuilder<TSender, SnapshotCreateRequestInner>;
+
+#[doc(hidden)]
+pub struct SnapshotCreateRequestInner {
+    repository: Repository<'static>,
+    snapshot: Snapshot<'static>,
+    body: SnapshotCreateBody,
+}
+
+#[derive(Default, Serialize)]
+struct SnapshotCreateBody {
+    #[serde(skip_serializing_if = "Option::is_none")] indices: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] ignore_unavailable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")] include_global_state: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")] partial: Option<bool>,
+}
+
+/**
+# Create snapshot request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SnapshotCreateRequestBuilder`][SnapshotCreateRequestBuilder] with this `Client` that can be configured before sending.
+
+    The repository must already be registered with [`snapshot_repository_put`][Client.snapshot_repository_put].
+    By default, all open indices are included in the snapshot and the request returns as soon as the snapshot has started.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Snapshot all indices into a repository called `my_backup`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_create(repository("my_backup"), snapshot("snapshot_1"))
+                         .send()?;
+
+    assert!(response.accepted());
+    # Ok(())
+    # }
+    ```
+
+    [SnapshotCreateRequestBuilder]: requests/snapshot_create/type.SnapshotCreateRequestBuilder.html
+    [builder-methods]: requests/snapshot_create/type.SnapshotCreateRequestBuilder.html#builder-methods
+    [send-sync]: requests/snapshot_create/type.SnapshotCreateRequestBuilder.html#send-synchronously
+    [send-async]: requests/snapshot_create/type.SnapshotCreateRequestBuilder.html#send-asynchronously
+    [Client.snapshot_repository_put]: #create-snapshot-repository-request
+    */
+    pub fn snapshot_create(&self, repository: Repository<'static>, snapshot: Snapshot<'static>) -> SnapshotCreateRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            SnapshotCreateRequestInner {
+                repository: repository,
+                snapshot: snapshot,
+                body: SnapshotCreateBody::default(),
+            },
+        )
+    }
+}
+
+impl SnapshotCreateRequestInner {
+    fn into_request(self) -> Result<SnapshotCreateRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&self.body).map_err(error::request)?;
+
+        Ok(SnapshotCreateRequest::for_repository_snapshot(self.repository, self.snapshot, body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `SnapshotCreateRequestBuilder` before sending it.
+*/
+impl<TSender> SnapshotCreateRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices to include in the snapshot.
+
+    Index names can include wildcards, like `logs-*`.
+    If no index is specified then all open indices are included.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        let index: Index<'static> = index.into();
+
+        self.inner.body.indices = Some(index.0.into_owned());
+        self
+    }
+
+    /** Set whether or not indices that don't exist should be ignored instead of failing the snapshot. */
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.inner.body.ignore_unavailable = Some(ignore_unavailable);
+        self
+    }
+
+    /** Set whether or not the global cluster state should be included in the snapshot. */
+    pub fn include_global_state(mut self, include_global_state: bool) -> Self {
+        self.inner.body.include_global_state = Some(include_global_state);
+        self
+    }
+
+    /** Set whether or not the snapshot should succeed when some primary shards aren't available. */
+    pub fn partial(mut self, partial: bool) -> Self {
+        self.inner.body.partial = Some(partial);
+        self
+    }
+
+    /**
+    Wait for the snapshot to complete before returning a response.
+
+    The response will contain the details of the completed snapshot.
+    This sets the `wait_for_completion` parameter.
+    */
+    pub fn wait_for_completion(self) -> Self {
+        self.params(|p| p.url_param("wait_for_completion", true))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SnapshotCreateRequestBuilder<SyncSender> {
+    /**
+    Send a `SnapshotCreateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Snapshot indices starting with `logs-` and wait for the snapshot to complete:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_create(repository("my_backup"), snapshot("snapshot_1"))
+                         .index("logs-*")
+                         .include_global_state(false)
+                         .wait_for_completion()
+                         .send()?;
+
+    if let Some(snapshot) = response.snapshot() {
+        println!("snapshot {} is {:?}", snapshot.name(), snapshot.state());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CreateSnapshotResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SnapshotCreateRequestBuilder<AsyncSender> {
+    /**
+    Send a `SnapshotCreateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised create snapshot response.
+
+    # Examples
+
+    Snapshot indices starting with `logs-` and wait for the snapshot to complete:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.snapshot_create(repository("my_backup"), snapshot("snapshot_1"))
+                       .index("logs-*")
+                       .wait_for_completion()
+                       .send();
+
+    future.and_then(|response| {
+        if let Some(snapshot) = response.snapshot() {
+            println!("snapshot {} is {:?}", snapshot.name(), snapshot.state());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CreateSnapshotResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CreateSnapshotResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CreateSnapshotResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_create(repository("my_backup"), snapshot("snapshot_1"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/_snapshot/my_backup/snapshot_1", req.url.as_ref());
+        assert_eq!(json!({}), serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_body() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_create(repository("my_backup"), snapshot("snapshot_1"))
+            .index("index_1,index_2")
+            .ignore_unavailable(true)
+            .include_global_state(false)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "indices": "index_1,index_2",
+            "ignore_unavailable": true,
+            "include_global_state": false
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_wait_for_completion() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_create(repository("my_backup"), snapshot("snapshot_1"))
+            .wait_for_completion();
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+
+        assert_eq!("?wait_for_completion=true", query.unwrap());
+    }
+}
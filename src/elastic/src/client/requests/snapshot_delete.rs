@@ -0,0 +1,213 @@
+/*!
+Builders for [delete snapshot requests][docs-snapshots].
+
+[docs-snapshots]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Repository, Snapshot};
+use client::requests::endpoints::SnapshotDeleteRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+
+/**
+A [delete snapshot request][docs-snapshots] builder that can be configured before sending.
+
+Call [`Client.snapshot_delete`][Client.snapshot_delete] to get a `SnapshotDeleteRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-snapshots]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.snapshot_delete]: ../../struct.Client.html#delete-snapshot-request
+*/
+pub type SnapshotDeleteRequestBuilder<TSender> = RequestBuilder<TSender, SnapshotDeleteRequestInner>;
+
+#[doc(hidden)]
+pub struct SnapshotDeleteRequestInner {
+    repository: Repository<'static>,
+    snapshot: Snapshot<'static>,
+}
+
+/**
+# Delete snapshot request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SnapshotDeleteRequestBuilder`][SnapshotDeleteRequestBuilder] with this `Client` that can be configured before sending.
+
+    If the snapshot is still running then it's aborted.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Delete a snapshot called `snapshot_1` in a repository called `my_backup`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_delete(repository("my_backup"), snapshot("snapshot_1")).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SnapshotDeleteRequestBuilder]: requests/snapshot_delete/type.SnapshotDeleteRequestBuilder.html
+    [send-sync]: requests/snapshot_delete/type.SnapshotDeleteRequestBuilder.html#send-synchronously
+    [send-async]: requests/snapshot_delete/type.SnapshotDeleteRequestBuilder.html#send-asynchronously
+    */
+    pub fn snapshot_delete(&self, repository: Repository<'static>, snapshot: Snapshot<'static>) -> SnapshotDeleteRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            SnapshotDeleteRequestInner {
+                repository: repository,
+                snapshot: snapshot,
+            },
+        )
+    }
+}
+
+impl SnapshotDeleteRequestInner {
+    fn into_request(self) -> SnapshotDeleteRequest<'static> {
+        SnapshotDeleteRequest::for_repository_snapshot(self.repository, self.snapshot)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SnapshotDeleteRequestBuilder<SyncSender> {
+    /**
+    Send a `SnapshotDeleteRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Delete a snapshot called `snapshot_1` in a repository called `my_backup`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_delete(repository("my_backup"), snapshot("snapshot_1")).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SnapshotDeleteRequestBuilder<AsyncSender> {
+    /**
+    Send a `SnapshotDeleteRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Delete a snapshot called `snapshot_1` in a repository called `my_backup`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.snapshot_delete(repository("my_backup"), snapshot("snapshot_1")).send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.snapshot_delete(repository("my_backup"), snapshot("snapshot_1")).inner.into_request();
+
+        assert_eq!("/_snapshot/my_backup/snapshot_1", req.url.as_ref());
+    }
+}
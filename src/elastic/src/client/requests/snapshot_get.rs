@@ -0,0 +1,264 @@
+/*!
+Builders for [get snapshot requests][docs-snapshots].
+
+[docs-snapshots]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Repository, Snapshot};
+use client::requests::endpoints::SnapshotGetRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::GetSnapshotsResponse;
+
+const ALL_SNAPSHOTS: &'static str = "_all";
+
+/**
+A [get snapshot request][docs-snapshots] builder that can be configured before sending.
+
+Call [`Client.snapshot_get`][Client.snapshot_get] to get a `SnapshotGetRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-snapshots]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.snapshot_get]: ../../struct.Client.html#get-snapshot-request
+*/
+pub type SnapshotGetRequestBuilder<TSender> = RequestBuilder<TSender, SnapshotGetRequestInner>;
+
+#[doc(hidden)]
+pub struct SnapshotGetRequestInner {
+    repository: Repository<'static>,
+    snapshot: Option<Snapshot<'static>>,
+}
+
+/**
+# Get snapshot request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SnapshotGetRequestBuilder`][SnapshotGetRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    List the snapshots in a repository called `my_backup`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_get(repository("my_backup")).send()?;
+
+    for snapshot in response.iter() {
+        println!("{} is {:?}", snapshot.name(), snapshot.state());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SnapshotGetRequestBuilder]: requests/snapshot_get/type.SnapshotGetRequestBuilder.html
+    [builder-methods]: requests/snapshot_get/type.SnapshotGetRequestBuilder.html#builder-methods
+    [send-sync]: requests/snapshot_get/type.SnapshotGetRequestBuilder.html#send-synchronously
+    [send-async]: requests/snapshot_get/type.SnapshotGetRequestBuilder.html#send-asynchronously
+    */
+    pub fn snapshot_get(&self, repository: Repository<'static>) -> SnapshotGetRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            SnapshotGetRequestInner {
+                repository: repository,
+                snapshot: None,
+            },
+        )
+    }
+}
+
+impl SnapshotGetRequestInner {
+    fn into_request(self) -> SnapshotGetRequest<'static> {
+        let snapshot = self.snapshot.unwrap_or_else(|| Snapshot::from(ALL_SNAPSHOTS));
+
+        SnapshotGetRequest::for_repository_snapshot(self.repository, snapshot)
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `SnapshotGetRequestBuilder` before sending it.
+*/
+impl<TSender> SnapshotGetRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the names of the snapshots to get.
+
+    Snapshot names can include wildcards, like `snapshot_*`.
+    If no snapshot is specified then all snapshots in the repository will be returned.
+    */
+    pub fn snapshot<I>(mut self, snapshot: I) -> Self
+    where
+        I: Into<Snapshot<'static>>,
+    {
+        self.inner.snapshot = Some(snapshot.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SnapshotGetRequestBuilder<SyncSender> {
+    /**
+    Send a `SnapshotGetRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get a snapshot called `snapshot_1`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_get(repository("my_backup"))
+                         .snapshot("snapshot_1")
+                         .send()?;
+
+    if let Some(snapshot) = response.get("snapshot_1") {
+        println!("snapshot_1 contains {:?}", snapshot.indices());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<GetSnapshotsResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SnapshotGetRequestBuilder<AsyncSender> {
+    /**
+    Send a `SnapshotGetRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get snapshot response.
+
+    # Examples
+
+    Get a snapshot called `snapshot_1`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.snapshot_get(repository("my_backup"))
+                       .snapshot("snapshot_1")
+                       .send();
+
+    future.and_then(|response| {
+        if let Some(snapshot) = response.get("snapshot_1") {
+            println!("snapshot_1 contains {:?}", snapshot.indices());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = GetSnapshotsResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = GetSnapshotsResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = GetSnapshotsResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.snapshot_get(repository("my_backup")).inner.into_request();
+
+        assert_eq!("/_snapshot/my_backup/_all", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_snapshot() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_get(repository("my_backup"))
+            .snapshot("snapshot_1")
+            .inner
+            .into_request();
+
+        assert_eq!("/_snapshot/my_backup/snapshot_1", req.url.as_ref());
+    }
+}
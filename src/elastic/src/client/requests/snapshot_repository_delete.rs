@@ -0,0 +1,205 @@
+/*!
+Builders for [delete snapshot repository requests][docs-repositories].
+
+[docs-repositories]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_repositories
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Repository;
+use client::requests::endpoints::SnapshotDeleteRepositoryRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+
+/**
+A [delete snapshot repository request][docs-repositories] builder that can be configured before sending.
+
+Call [`Client.snapshot_repository_delete`][Client.snapshot_repository_delete] to get a `SnapshotRepositoryDeleteRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-repositories]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_repositories
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.snapshot_repository_delete]: ../../struct.Client.html#delete-snapshot-repository-request
+*/
+pub type SnapshotRepositoryDeleteRequestBuilder<TSender> = RequestBuilder<TSender, SnapshotRepositoryDeleteRequestInner>;
+
+#[doc(hidden)]
+pub struct SnapshotRepositoryDeleteRequestInner {
+    repository: Repository<'static>,
+}
+
+/**
+# Delete snapshot repository request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SnapshotRepositoryDeleteRequestBuilder`][SnapshotRepositoryDeleteRequestBuilder] with this `Client` that can be configured before sending.
+
+    The repository is only unregistered, the snapshots stored in it aren't deleted.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Delete a snapshot repository called `my_backup`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_repository_delete(repository("my_backup")).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SnapshotRepositoryDeleteRequestBuilder]: requests/snapshot_repository_delete/type.SnapshotRepositoryDeleteRequestBuilder.html
+    [send-sync]: requests/snapshot_repository_delete/type.SnapshotRepositoryDeleteRequestBuilder.html#send-synchronously
+    [send-async]: requests/snapshot_repository_delete/type.SnapshotRepositoryDeleteRequestBuilder.html#send-asynchronously
+    */
+    pub fn snapshot_repository_delete(&self, repository: Repository<'static>) -> SnapshotRepositoryDeleteRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, SnapshotRepositoryDeleteRequestInner { repository: repository })
+    }
+}
+
+impl SnapshotRepositoryDeleteRequestInner {
+    fn into_request(self) -> SnapshotDeleteRepositoryRequest<'static> {
+        SnapshotDeleteRepositoryRequest::for_repository(self.repository)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SnapshotRepositoryDeleteRequestBuilder<SyncSender> {
+    /**
+    Send a `SnapshotRepositoryDeleteRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Delete a snapshot repository called `my_backup`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_repository_delete(repository("my_backup")).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SnapshotRepositoryDeleteRequestBuilder<AsyncSender> {
+    /**
+    Send a `SnapshotRepositoryDeleteRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Delete a snapshot repository called `my_backup`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.snapshot_repository_delete(repository("my_backup")).send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.snapshot_repository_delete(repository("my_backup")).inner.into_request();
+
+        assert_eq!("/_snapshot/my_backup", req.url.as_ref());
+    }
+}
@@ -0,0 +1,254 @@
+/*!
+Builders for [get snapshot repository requests][docs-repositories].
+
+[docs-repositories]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_repositories
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Repository;
+use client::requests::endpoints::SnapshotGetRepositoryRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::GetRepositoriesResponse;
+
+/**
+A [get snapshot repository request][docs-repositories] builder that can be configured before sending.
+
+Call [`Client.snapshot_repository_get`][Client.snapshot_repository_get] to get a `SnapshotRepositoryGetRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-repositories]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_repositories
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.snapshot_repository_get]: ../../struct.Client.html#get-snapshot-repository-request
+*/
+pub type SnapshotRepositoryGetRequestBuilder<TSender> = RequestBuilder<TSender, SnapshotRepositoryGetRequestInner>;
+
+#[doc(hidden)]
+pub struct SnapshotRepositoryGetRequestInner {
+    repository: Option<Repository<'static>>,
+}
+
+/**
+# Get snapshot repository request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SnapshotRepositoryGetRequestBuilder`][SnapshotRepositoryGetRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get all registered snapshot repositories:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_repository_get().send()?;
+
+    for (name, repository) in response.iter() {
+        println!("{} is a {} repository", name, repository.ty());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SnapshotRepositoryGetRequestBuilder]: requests/snapshot_repository_get/type.SnapshotRepositoryGetRequestBuilder.html
+    [builder-methods]: requests/snapshot_repository_get/type.SnapshotRepositoryGetRequestBuilder.html#builder-methods
+    [send-sync]: requests/snapshot_repository_get/type.SnapshotRepositoryGetRequestBuilder.html#send-synchronously
+    [send-async]: requests/snapshot_repository_get/type.SnapshotRepositoryGetRequestBuilder.html#send-asynchronously
+    */
+    pub fn snapshot_repository_get(&self) -> SnapshotRepositoryGetRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, SnapshotRepositoryGetRequestInner { repository: None })
+    }
+}
+
+impl SnapshotRepositoryGetRequestInner {
+    fn into_request(self) -> SnapshotGetRepositoryRequest<'static> {
+        match self.repository {
+            Some(repository) => SnapshotGetRepositoryRequest::for_repository(repository),
+            None => SnapshotGetRepositoryRequest::new(),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `SnapshotRepositoryGetRequestBuilder` before sending it.
+*/
+impl<TSender> SnapshotRepositoryGetRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the names of the repositories to get.
+
+    If no repository is specified then all repositories will be returned.
+    */
+    pub fn repository<I>(mut self, repository: I) -> Self
+    where
+        I: Into<Repository<'static>>,
+    {
+        self.inner.repository = Some(repository.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SnapshotRepositoryGetRequestBuilder<SyncSender> {
+    /**
+    Send a `SnapshotRepositoryGetRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get a snapshot repository called `my_backup`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_repository_get()
+                         .repository("my_backup")
+                         .send()?;
+
+    if let Some(repository) = response.get("my_backup") {
+        println!("{:?}", repository.settings());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<GetRepositoriesResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SnapshotRepositoryGetRequestBuilder<AsyncSender> {
+    /**
+    Send a `SnapshotRepositoryGetRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get snapshot repository response.
+
+    # Examples
+
+    Get a snapshot repository called `my_backup`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.snapshot_repository_get()
+                       .repository("my_backup")
+                       .send();
+
+    future.and_then(|response| {
+        if let Some(repository) = response.get("my_backup") {
+            println!("{:?}", repository.settings());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = GetRepositoriesResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = GetRepositoriesResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = GetRepositoriesResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.snapshot_repository_get().inner.into_request();
+
+        assert_eq!("/_snapshot", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_repository() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_repository_get()
+            .repository("my_backup")
+            .inner
+            .into_request();
+
+        assert_eq!("/_snapshot/my_backup", req.url.as_ref());
+    }
+}
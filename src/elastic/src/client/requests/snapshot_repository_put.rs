@@ -0,0 +1,370 @@
+/*!
+Builders for [create snapshot repository requests][docs-repositories].
+
+[docs-repositories]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_repositories
+*/
+
+use serde_json::{self, Map, Value};
+use futures::{Future, IntoFuture, Poll};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::Repository;
+use client::requests::endpoints::SnapshotCreateRepositoryRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::CommandResponse;
+
+/**
+A [create snapshot repository request][docs-repositories] builder that can be configured before sending.
+
+Call [`Client.snapshot_repository_put`][Client.snapshot_repository_put] to get a `SnapshotRepositoryPutRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-repositories]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_repositories
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.snapshot_repository_put]: ../../struct.Client.html#create-snapshot-repository-request
+*/
+pub type SnapshotRepositoryPutRequestBuilder<TSender> = RequestBuilder<TSender, SnapshotRepositoryPutRequestInner>;
+
+#[doc(hidden)]
+pub struct SnapshotRepositoryPutRequestInner {
+    repository: Repository<'static>,
+    settings: SnapshotRepository,
+}
+
+/**
+The type and settings for a snapshot repository.
+
+# Examples
+
+A shared filesystem repository:
+
+```
+# use elastic::prelude::*;
+let repository = SnapshotRepository::fs("/mount/backups/my_backup").compress(true);
+```
+
+A read-only url repository:
+
+```
+# use elastic::prelude::*;
+let repository = SnapshotRepository::url("file:/mount/backups/my_backup");
+```
+
+A repository provided by a plugin:
+
+```
+# use elastic::prelude::*;
+let repository = SnapshotRepository::new("s3").setting("bucket", "my_bucket");
+```
+*/
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SnapshotRepository {
+    #[serde(rename = "type")] ty: String,
+    settings: Map<String, Value>,
+}
+
+impl SnapshotRepository {
+    /** Create a repository with the given `type` and no settings. */
+    pub fn new<I>(ty: I) -> Self
+    where
+        I: Into<String>,
+    {
+        SnapshotRepository {
+            ty: ty.into(),
+            settings: Map::new(),
+        }
+    }
+
+    /**
+    Create a shared filesystem repository.
+
+    The location must be registered in the `path.repo` setting on every node.
+    */
+    pub fn fs<I>(location: I) -> Self
+    where
+        I: Into<String>,
+    {
+        SnapshotRepository::new("fs").setting("location", location.into())
+    }
+
+    /**
+    Create a read-only url repository.
+
+    The url must be allowed by the `repositories.url.allowed_urls` setting on every node.
+    */
+    pub fn url<I>(url: I) -> Self
+    where
+        I: Into<String>,
+    {
+        SnapshotRepository::new("url").setting("url", url.into())
+    }
+
+    /** Set a setting for the repository. */
+    pub fn setting<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        self.settings.insert(key.into(), value.into());
+        self
+    }
+
+    /** Set whether or not metadata files are compressed. */
+    pub fn compress(self, compress: bool) -> Self {
+        self.setting("compress", compress)
+    }
+
+    /** The `type` of the repository. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The settings for the repository. */
+    pub fn settings(&self) -> &Map<String, Value> {
+        &self.settings
+    }
+}
+
+/**
+# Create snapshot repository request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SnapshotRepositoryPutRequestBuilder`][SnapshotRepositoryPutRequestBuilder] with this `Client` that can be configured before sending.
+
+    If a repository with the same name already exists then it's replaced.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Register a shared filesystem repository called `my_backup`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_repository_put(repository("my_backup"), SnapshotRepository::fs("/mount/backups/my_backup"))
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SnapshotRepositoryPutRequestBuilder]: requests/snapshot_repository_put/type.SnapshotRepositoryPutRequestBuilder.html
+    [builder-methods]: requests/snapshot_repository_put/type.SnapshotRepositoryPutRequestBuilder.html#builder-methods
+    [send-sync]: requests/snapshot_repository_put/type.SnapshotRepositoryPutRequestBuilder.html#send-synchronously
+    [send-async]: requests/snapshot_repository_put/type.SnapshotRepositoryPutRequestBuilder.html#send-asynchronously
+    */
+    pub fn snapshot_repository_put(&self, repository: Repository<'static>, settings: SnapshotRepository) -> SnapshotRepositoryPutRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            SnapshotRepositoryPutRequestInner {
+                repository: repository,
+                settings: settings,
+            },
+        )
+    }
+}
+
+impl SnapshotRepositoryPutRequestInner {
+    fn into_request(self) -> Result<SnapshotCreateRepositoryRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&self.settings).map_err(error::request)?;
+
+        Ok(SnapshotCreateRepositoryRequest::for_repository(self.repository, body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `SnapshotRepositoryPutRequestBuilder` before sending it.
+*/
+impl<TSender> SnapshotRepositoryPutRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set whether or not the repository should be verified on all nodes when it's registered.
+
+    This sets the `verify` parameter, which defaults to `true`.
+    */
+    pub fn verify(self, verify: bool) -> Self {
+        self.params(move |p| p.url_param("verify", verify))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SnapshotRepositoryPutRequestBuilder<SyncSender> {
+    /**
+    Send a `SnapshotRepositoryPutRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Register a read-only url repository called `readonly`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_repository_put(repository("readonly"), SnapshotRepository::url("file:/mount/backups/my_backup"))
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SnapshotRepositoryPutRequestBuilder<AsyncSender> {
+    /**
+    Send a `SnapshotRepositoryPutRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Register a read-only url repository called `readonly`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.snapshot_repository_put(repository("readonly"), SnapshotRepository::url("file:/mount/backups/my_backup"))
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CommandResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_repository_put(repository("my_backup"), SnapshotRepository::fs("/mount/backups/my_backup").compress(true))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "type": "fs",
+            "settings": {
+                "compress": true,
+                "location": "/mount/backups/my_backup"
+            }
+        });
+
+        assert_eq!("/_snapshot/my_backup", req.url.as_ref());
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_url_repository() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_repository_put(repository("readonly"), SnapshotRepository::url("file:/mount/backups/my_backup"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "type": "url",
+            "settings": {
+                "url": "file:/mount/backups/my_backup"
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
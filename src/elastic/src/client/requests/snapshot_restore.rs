@@ -0,0 +1,385 @@
+/*!
+Builders for [restore snapshot requests][docs-restore].
+
+[docs-restore]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_restore
+*/
+
+use serde_json;
+use futures::{Future, IntoFuture, Poll};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Index, Repository, Snapshot};
+use client::requests::endpoints::SnapshotRestoreRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::RestoreSnapshotResponse;
+use types::settings::IndexSettings;
+
+/**
+A [restore snapshot request][docs-restore] builder that can be configured before sending.
+
+Call [`Client.snapshot_restore`][Client.snapshot_restore] to get a `SnapshotRestoreRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-restore]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_restore
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.snapshot_restore]: ../../struct.Client.html#restore-snapshot-request
+*/
+pub type SnapshotRestoreRequestBuilder<TSender> = RequestBuilder<TSender, SnapshotRestoreRequestInner>;
+
+#[doc(hidden)]
+pub struct SnapshotRestoreRequestInner {
+    repository: Repository<'static>,
+    snapshot: Snapshot<'static>,
+    body: SnapshotRestoreBody,
+}
+
+#[derive(Default, Serialize)]
+struct SnapshotRestoreBody {
+    #[serde(skip_serializing_if = "Option::is_none")] indices: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] ignore_unavailable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")] include_global_state: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")] include_aliases: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")] partial: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")] rename_pattern: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] rename_replacement: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] index_settings: Option<IndexSettings>,
+}
+
+/**
+# Restore snapshot request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SnapshotRestoreRequestBuilder`][SnapshotRestoreRequestBuilder] with this `Client` that can be configured before sending.
+
+    By default, all indices in the snapshot are restored and the request returns as soon as the restore has started.
+    Indices that already exist in the cluster must be closed before they can be restored over, or be [renamed][rename] as they're restored.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Restore all indices from a snapshot called `snapshot_1`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_restore(repository("my_backup"), snapshot("snapshot_1"))
+                         .send()?;
+
+    assert!(response.accepted());
+    # Ok(())
+    # }
+    ```
+
+    [SnapshotRestoreRequestBuilder]: requests/snapshot_restore/type.SnapshotRestoreRequestBuilder.html
+    [builder-methods]: requests/snapshot_restore/type.SnapshotRestoreRequestBuilder.html#builder-methods
+    [send-sync]: requests/snapshot_restore/type.SnapshotRestoreRequestBuilder.html#send-synchronously
+    [send-async]: requests/snapshot_restore/type.SnapshotRestoreRequestBuilder.html#send-asynchronously
+    [rename]: requests/snapshot_restore/type.SnapshotRestoreRequestBuilder.html#method.rename
+    */
+    pub fn snapshot_restore(&self, repository: Repository<'static>, snapshot: Snapshot<'static>) -> SnapshotRestoreRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            SnapshotRestoreRequestInner {
+                repository: repository,
+                snapshot: snapshot,
+                body: SnapshotRestoreBody::default(),
+            },
+        )
+    }
+}
+
+impl SnapshotRestoreRequestInner {
+    fn into_request(self) -> Result<SnapshotRestoreRequest<'static, Vec<u8>>> {
+        let body = serde_json::to_vec(&self.body).map_err(error::request)?;
+
+        Ok(SnapshotRestoreRequest::for_repository_snapshot(self.repository, self.snapshot, body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `SnapshotRestoreRequestBuilder` before sending it.
+*/
+impl<TSender> SnapshotRestoreRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices to restore from the snapshot.
+
+    Index names can include wildcards, like `logs-*`.
+    If no index is specified then all indices in the snapshot are restored.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        let index: Index<'static> = index.into();
+
+        self.inner.body.indices = Some(index.0.into_owned());
+        self
+    }
+
+    /**
+    Rename indices as they're restored.
+
+    Index names that match the regular expression `pattern` are replaced with `replacement`, which can refer to capture groups like `$1`.
+
+    # Examples
+
+    Restore `index_1` as `restored_index_1`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_restore(repository("my_backup"), snapshot("snapshot_1"))
+                         .index("index_1")
+                         .rename("index_(.+)", "restored_index_$1")
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn rename<IPattern, IReplacement>(mut self, pattern: IPattern, replacement: IReplacement) -> Self
+    where
+        IPattern: Into<String>,
+        IReplacement: Into<String>,
+    {
+        self.inner.body.rename_pattern = Some(pattern.into());
+        self.inner.body.rename_replacement = Some(replacement.into());
+        self
+    }
+
+    /** Set whether or not indices that aren't in the snapshot should be ignored instead of failing the restore. */
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.inner.body.ignore_unavailable = Some(ignore_unavailable);
+        self
+    }
+
+    /** Set whether or not the global cluster state should be restored. */
+    pub fn include_global_state(mut self, include_global_state: bool) -> Self {
+        self.inner.body.include_global_state = Some(include_global_state);
+        self
+    }
+
+    /** Set whether or not the aliases of restored indices should be restored. */
+    pub fn include_aliases(mut self, include_aliases: bool) -> Self {
+        self.inner.body.include_aliases = Some(include_aliases);
+        self
+    }
+
+    /** Set whether or not indices should be restored when some of their shards weren't snapshotted. */
+    pub fn partial(mut self, partial: bool) -> Self {
+        self.inner.body.partial = Some(partial);
+        self
+    }
+
+    /**
+    Override settings for the restored indices.
+
+    Some settings, like the number of shards, can't be changed.
+    */
+    pub fn index_settings(mut self, settings: IndexSettings) -> Self {
+        self.inner.body.index_settings = Some(settings);
+        self
+    }
+
+    /**
+    Wait for the restore to complete before returning a response.
+
+    The response will contain the restored indices and shards.
+    This sets the `wait_for_completion` parameter.
+    */
+    pub fn wait_for_completion(self) -> Self {
+        self.params(|p| p.url_param("wait_for_completion", true))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SnapshotRestoreRequestBuilder<SyncSender> {
+    /**
+    Send a `SnapshotRestoreRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Restore indices starting with `logs-` and wait for the restore to complete:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_restore(repository("my_backup"), snapshot("snapshot_1"))
+                         .index("logs-*")
+                         .wait_for_completion()
+                         .send()?;
+
+    for index in response.indices() {
+        println!("restored {}", index);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<RestoreSnapshotResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SnapshotRestoreRequestBuilder<AsyncSender> {
+    /**
+    Send a `SnapshotRestoreRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised restore snapshot response.
+
+    # Examples
+
+    Restore indices starting with `logs-` and wait for the restore to complete:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.snapshot_restore(repository("my_backup"), snapshot("snapshot_1"))
+                       .index("logs-*")
+                       .wait_for_completion()
+                       .send();
+
+    future.and_then(|response| {
+        for index in response.indices() {
+            println!("restored {}", index);
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = RestoreSnapshotResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = RestoreSnapshotResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = RestoreSnapshotResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+    use types::settings::IndexSettings;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_restore(repository("my_backup"), snapshot("snapshot_1"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/_snapshot/my_backup/snapshot_1/_restore", req.url.as_ref());
+        assert_eq!(json!({}), serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_rename() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_restore(repository("my_backup"), snapshot("snapshot_1"))
+            .index("index_1")
+            .rename("index_(.+)", "restored_index_$1")
+            .include_aliases(false)
+            .index_settings(IndexSettings::new().number_of_replicas(0))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "indices": "index_1",
+            "include_aliases": false,
+            "rename_pattern": "index_(.+)",
+            "rename_replacement": "restored_index_$1",
+            "index_settings": {
+                "number_of_replicas": 0
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+}
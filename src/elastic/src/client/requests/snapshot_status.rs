@@ -0,0 +1,267 @@
+/*!
+Builders for [snapshot status requests][docs-snapshot-status].
+
+[docs-snapshot-status]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot_status
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::params::{Repository, Snapshot};
+use client::requests::endpoints::SnapshotStatusRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::SnapshotStatusResponse;
+
+/**
+A [snapshot status request][docs-snapshot-status] builder that can be configured before sending.
+
+Call [`Client.snapshot_status`][Client.snapshot_status] to get a `SnapshotStatusRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-snapshot-status]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot_status
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.snapshot_status]: ../../struct.Client.html#snapshot-status-request
+*/
+pub type SnapshotStatusRequestBuilder<TSender> = RequestBuilder<TSender, SnapshotStatusRequestInner>;
+
+#[doc(hidden)]
+pub struct SnapshotStatusRequestInner {
+    repository: Repository<'static>,
+    snapshot: Option<Snapshot<'static>>,
+}
+
+/**
+# Snapshot status request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SnapshotStatusRequestBuilder`][SnapshotStatusRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the status of the snapshots that are currently running in a repository called `my_backup`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_status(repository("my_backup")).send()?;
+
+    for snapshot in response.iter() {
+        println!("{} is {}", snapshot.name(), snapshot.state());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SnapshotStatusRequestBuilder]: requests/snapshot_status/type.SnapshotStatusRequestBuilder.html
+    [builder-methods]: requests/snapshot_status/type.SnapshotStatusRequestBuilder.html#builder-methods
+    [send-sync]: requests/snapshot_status/type.SnapshotStatusRequestBuilder.html#send-synchronously
+    [send-async]: requests/snapshot_status/type.SnapshotStatusRequestBuilder.html#send-asynchronously
+    */
+    pub fn snapshot_status(&self, repository: Repository<'static>) -> SnapshotStatusRequestBuilder<TSender> {
+        RequestBuilder::new(
+            self.clone(),
+            None,
+            SnapshotStatusRequestInner {
+                repository: repository,
+                snapshot: None,
+            },
+        )
+    }
+}
+
+impl SnapshotStatusRequestInner {
+    fn into_request(self) -> SnapshotStatusRequest<'static> {
+        match self.snapshot {
+            Some(snapshot) => SnapshotStatusRequest::for_repository_snapshot(self.repository, snapshot),
+            None => SnapshotStatusRequest::for_repository(self.repository),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `SnapshotStatusRequestBuilder` before sending it.
+*/
+impl<TSender> SnapshotStatusRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the names of the snapshots to get the status of.
+
+    Snapshots don't need to be running to get their status.
+    If no snapshot is specified then the status of the snapshots that are currently running in the repository will be returned.
+    */
+    pub fn snapshot<I>(mut self, snapshot: I) -> Self
+    where
+        I: Into<Snapshot<'static>>,
+    {
+        self.inner.snapshot = Some(snapshot.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SnapshotStatusRequestBuilder<SyncSender> {
+    /**
+    Send a `SnapshotStatusRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the progress of each shard in a snapshot called `snapshot_1`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_status(repository("my_backup"))
+                         .snapshot("snapshot_1")
+                         .send()?;
+
+    if let Some(snapshot) = response.get("snapshot_1") {
+        for (index, status) in snapshot.indices() {
+            for (shard, status) in status.shards() {
+                println!("{}[{}] is {}", index, shard, status.stage());
+            }
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<SnapshotStatusResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SnapshotStatusRequestBuilder<AsyncSender> {
+    /**
+    Send a `SnapshotStatusRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised snapshot status response.
+
+    # Examples
+
+    Get the number of shards that are done in a snapshot called `snapshot_1`:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.snapshot_status(repository("my_backup"))
+                       .snapshot("snapshot_1")
+                       .send();
+
+    future.and_then(|response| {
+        if let Some(snapshot) = response.get("snapshot_1") {
+            println!("{} of {} shards are done", snapshot.shards_stats().done(), snapshot.shards_stats().total());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = SnapshotStatusResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = SnapshotStatusResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = SnapshotStatusResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.snapshot_status(repository("my_backup")).inner.into_request();
+
+        assert_eq!("/_snapshot/my_backup/_status", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_snapshot() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_status(repository("my_backup"))
+            .snapshot("snapshot_1")
+            .inner
+            .into_request();
+
+        assert_eq!("/_snapshot/my_backup/snapshot_1/_status", req.url.as_ref());
+    }
+}
@@ -0,0 +1,550 @@
+/*!
+Builders for the [tasks API][docs-tasks].
+
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html
+*/
+
+use std::io;
+use std::thread;
+use std::time::Duration;
+use std::marker::PhantomData;
+use futures::{future, Future, IntoFuture, Poll};
+use futures::future::{Either, Loop};
+use tokio_core::reactor::Timeout;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::{empty_body, DefaultBody, RequestBuilder};
+use client::requests::endpoints::{TasksCancelRequest, TasksGetRequest, TasksListRequest};
+use client::requests::raw::RawRequestInner;
+use client::responses::tasks::{CancelTasksResponse, GetTaskResponse, ListTasksResponse};
+
+/**
+A [list tasks request][docs-tasks] builder that can be configured before sending.
+
+Call [`Client.tasks_list`][Client.tasks_list] to get a `TasksListRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.tasks_list]: ../../struct.Client.html#list-tasks-request
+*/
+pub type TasksListRequestBuilder<TSender> = RequestBuilder<TSender, TasksListRequestInner>;
+
+#[doc(hidden)]
+pub struct TasksListRequestInner;
+
+/**
+# List tasks request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`TasksListRequestBuilder`][TasksListRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    List the reindex tasks running in a cluster:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.tasks_list()
+                         .actions("*reindex")
+                         .send()?;
+
+    for task in response.iter() {
+        println!("{}: running for {}ns", task.task_id(), task.running_time_in_nanos());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [TasksListRequestBuilder]: requests/tasks/type.TasksListRequestBuilder.html
+    [builder-methods]: requests/tasks/type.TasksListRequestBuilder.html#builder-methods
+    [send-sync]: requests/tasks/type.TasksListRequestBuilder.html#send-synchronously
+    [send-async]: requests/tasks/type.TasksListRequestBuilder.html#send-asynchronously
+    */
+    pub fn tasks_list(&self) -> TasksListRequestBuilder<TSender> {
+        RequestBuilder::new(self.clone(), None, TasksListRequestInner)
+    }
+}
+
+impl TasksListRequestInner {
+    fn into_request(self) -> TasksListRequest<'static> {
+        TasksListRequest::new()
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `TasksListRequestBuilder` before sending it.
+*/
+impl<TSender> TasksListRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Only list tasks for actions that match the given pattern, like `*reindex`.
+
+    This sets the `actions` parameter.
+    */
+    pub fn actions<TActions>(self, actions: TActions) -> Self
+    where
+        TActions: ToString,
+    {
+        let actions = actions.to_string();
+
+        self.params(move |p| p.url_param("actions", actions.clone()))
+    }
+
+    /**
+    Include detailed information about each task, like its status and description.
+
+    This sets the `detailed` parameter.
+    */
+    pub fn detailed(self) -> Self {
+        self.params(|p| p.url_param("detailed", true))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl TasksListRequestBuilder<SyncSender> {
+    /**
+    Send a `TasksListRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    List the tasks running in a cluster:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.tasks_list().send()?;
+
+    for task in response.iter() {
+        println!("{}: {}", task.task_id(), task.action());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ListTasksResponse<Value>> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl TasksListRequestBuilder<AsyncSender> {
+    /**
+    Send a `TasksListRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised list tasks response.
+
+    # Examples
+
+    List the tasks running in a cluster:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.tasks_list().send();
+
+    future.and_then(|response| {
+        for task in response.iter() {
+            println!("{}: {}", task.task_id(), task.action());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<ListTasksResponse<Value>> {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/**
+# Task handle
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Get a [`TaskHandle`][TaskHandle] for a task running in the background.
+
+    Requests that run in the background, like delete by query or reindex, already return a typed handle.
+    Use this method to get a handle for a task id that was started somewhere else, like another process.
+    The handle has two generic parameters:
+
+    - `TResponse`: the response for the request the task is running.
+    - `TStatus`: the progress of the task while it's running. Use a `Value` if the task doesn't report its progress.
+
+    # Examples
+
+    Wait for a delete by query task that's already running:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # use elastic::client::responses::bulk_by_scroll::BulkByScrollStatus;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let task = client.task::<DeleteByQueryResponse, BulkByScrollStatus>("oTUltX4IQMOUUVeiohTt8A:12345");
+
+    let response = task.wait_with_progress(|status| {
+        println!("deleted {} of {} documents", status.deleted(), status.total());
+    })?;
+    # Ok(())
+    # }
+    ```
+
+    [TaskHandle]: requests/tasks/struct.TaskHandle.html
+    */
+    pub fn task<TResponse, TStatus>(&self, task: &str) -> TaskHandle<TSender, TResponse, TStatus> {
+        TaskHandle {
+            client: self.clone(),
+            task: task.to_owned(),
+            interval: Duration::from_secs(1),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/**
+A handle to a task running in the background.
+
+Call [`Client.task`][Client.task] to get a `TaskHandle`.
+The task is polled using a [get task request][docs-tasks] until it completes.
+A handle for an [`AsyncClient`][AsyncClient] can also be used as a future that resolves once the task completes.
+
+[Client.task]: ../../struct.Client.html#task-handle
+[AsyncClient]: ../../type.AsyncClient.html
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html
+*/
+pub struct TaskHandle<TSender, TResponse, TStatus> {
+    client: Client<TSender>,
+    task: String,
+    interval: Duration,
+    _marker: PhantomData<(TResponse, TStatus)>,
+}
+
+impl<TSender, TResponse, TStatus> TaskHandle<TSender, TResponse, TStatus>
+where
+    TSender: Sender,
+{
+    /** The id of the task in the form `node_id:task_number`. */
+    pub fn task(&self) -> &str {
+        &self.task
+    }
+
+    /**
+    Set the time to wait between polling the task while waiting for it to complete.
+
+    The default interval is 1 second.
+    */
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    fn get_request(&self) -> TasksGetRequest<'static> {
+        TasksGetRequest::for_task_id(self.task.clone())
+    }
+
+    fn cancel_request(&self) -> TasksCancelRequest<'static, DefaultBody> {
+        TasksCancelRequest::for_task_id(self.task.clone(), empty_body())
+    }
+}
+
+/**
+# Poll synchronously
+*/
+impl<TResponse, TStatus> TaskHandle<SyncSender, TResponse, TStatus>
+where
+    TResponse: DeserializeOwned,
+    TStatus: DeserializeOwned,
+{
+    /** Get the current progress of the task. */
+    pub fn progress(&self) -> Result<GetTaskResponse<TResponse, TStatus>> {
+        let req = self.get_request();
+
+        RequestBuilder::new(self.client.clone(), None, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+
+    /**
+    Block the current thread until the task completes.
+
+    If the task failed then its error will be returned.
+    */
+    pub fn wait(self) -> Result<TResponse> {
+        self.wait_with_progress(|_| ())
+    }
+
+    /**
+    Block the current thread until the task completes, calling `on_progress` each time the task reports its progress.
+
+    If the task failed then its error will be returned.
+    */
+    pub fn wait_with_progress<TProgress>(self, mut on_progress: TProgress) -> Result<TResponse>
+    where
+        TProgress: FnMut(&TStatus),
+    {
+        loop {
+            let progress = self.progress()?;
+
+            if let Some(status) = progress.task().status() {
+                on_progress(status);
+            }
+
+            if let Some(res) = into_result(progress) {
+                return res;
+            }
+
+            thread::sleep(self.interval);
+        }
+    }
+
+    /**
+    Cancel the task.
+
+    Not all tasks can be cancelled.
+    */
+    pub fn cancel(&self) -> Result<CancelTasksResponse<TStatus>> {
+        let req = self.cancel_request();
+
+        RequestBuilder::new(self.client.clone(), None, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Poll asynchronously
+*/
+impl<TResponse, TStatus> TaskHandle<AsyncSender, TResponse, TStatus>
+where
+    TResponse: DeserializeOwned + Send + 'static,
+    TStatus: DeserializeOwned + Send + 'static,
+{
+    /** Get the current progress of the task. */
+    pub fn progress(&self) -> Pending<GetTaskResponse<TResponse, TStatus>> {
+        let req = self.get_request();
+
+        let res_future = RequestBuilder::new(self.client.clone(), None, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+
+    /**
+    Return a future that will resolve once the task completes.
+
+    If the task failed then its error will be returned.
+    This method will fail if the `AsyncClient` wasn't built with a reactor `Handle`.
+    */
+    pub fn wait(self) -> Pending<TResponse> {
+        self.wait_with_progress(|_| ())
+    }
+
+    /**
+    Return a future that will resolve once the task completes, calling `on_progress` each time the task reports its progress.
+
+    If the task failed then its error will be returned.
+    This method will fail if the `AsyncClient` wasn't built with a reactor `Handle`.
+    */
+    pub fn wait_with_progress<TProgress>(self, on_progress: TProgress) -> Pending<TResponse>
+    where
+        TProgress: FnMut(&TStatus) + 'static,
+    {
+        let handle = match self.client.sender.handle.clone() {
+            Some(handle) => handle,
+            None => {
+                return Pending::new(future::err(error::request(io::Error::new(
+                    io::ErrorKind::Other,
+                    "waiting for a task requires an `AsyncClient` built with a reactor `Handle`",
+                ))))
+            }
+        };
+
+        let res_future = future::loop_fn((self, on_progress), move |(task, mut on_progress)| {
+            let handle = handle.clone();
+
+            task.progress().and_then(move |progress| {
+                if let Some(status) = progress.task().status() {
+                    on_progress(status);
+                }
+
+                match into_result(progress) {
+                    Some(res) => Either::A(res.map(Loop::Break).into_future()),
+                    None => {
+                        let delay = Timeout::new(task.interval, &handle)
+                            .into_future()
+                            .flatten()
+                            .map_err(error::request);
+
+                        Either::B(delay.map(move |()| Loop::Continue((task, on_progress))))
+                    }
+                }
+            })
+        });
+
+        Pending::new(res_future)
+    }
+
+    /**
+    Cancel the task.
+
+    Not all tasks can be cancelled.
+    */
+    pub fn cancel(&self) -> Pending<CancelTasksResponse<TStatus>> {
+        let req = self.cancel_request();
+
+        let res_future = RequestBuilder::new(self.client.clone(), None, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+impl<TResponse, TStatus> IntoFuture for TaskHandle<AsyncSender, TResponse, TStatus>
+where
+    TResponse: DeserializeOwned + Send + 'static,
+    TStatus: DeserializeOwned + Send + 'static,
+{
+    type Future = Pending<TResponse>;
+    type Item = TResponse;
+    type Error = Error;
+
+    fn into_future(self) -> Self::Future {
+        self.wait()
+    }
+}
+
+fn into_result<TResponse, TStatus>(progress: GetTaskResponse<TResponse, TStatus>) -> Option<Result<TResponse>> {
+    let completed = progress.is_completed();
+
+    match progress.into_result() {
+        Some(res) => Some(res.map_err(Error::Api)),
+        None if completed => Some(Err(error::request(io::Error::new(
+            io::ErrorKind::Other,
+            "the task completed without a response",
+        )))),
+        None => None,
+    }
+}
+
+/** A future returned by calling `send` or polling a task. */
+pub struct Pending<T> {
+    inner: Box<Future<Item = T, Error = Error>>,
+}
+
+impl<T> Pending<T> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = T, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<T> Future for Pending<T> {
+    type Item = T;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use prelude::*;
+
+    #[test]
+    fn default_list_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.tasks_list().inner.into_request();
+
+        assert_eq!("/_tasks", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_list_params() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.tasks_list().actions("*reindex").detailed();
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+
+        assert_eq!("?actions=*reindex&detailed=true", query.unwrap());
+    }
+
+    #[test]
+    fn task_handle_requests() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let task = client.task::<Value, Value>("oTUltX4IQMOUUVeiohTt8A:12345");
+
+        assert_eq!("oTUltX4IQMOUUVeiohTt8A:12345", task.task());
+        assert_eq!("/_tasks/oTUltX4IQMOUUVeiohTt8A:12345", task.get_request().url.as_ref());
+        assert_eq!("/_tasks/oTUltX4IQMOUUVeiohTt8A:12345/_cancel", task.cancel_request().url.as_ref());
+    }
+}
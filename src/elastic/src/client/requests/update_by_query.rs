@@ -0,0 +1,616 @@
+/*!
+Builders for [update by query requests][docs-update-by-query].
+
+[docs-update-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html
+*/
+
+use std::marker::PhantomData;
+use futures::{Future, IntoFuture, Poll};
+use serde::ser::Serialize;
+use serde_json::{self, Map, Value};
+
+use error::{self, Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::RequestBuilder;
+use client::requests::document_update::{DefaultParams, ScriptBuilder};
+use client::requests::params::{Index, Type};
+use client::requests::endpoints::UpdateByQueryRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::tasks::TaskHandle;
+use client::responses::{UpdateByQueryResponse, StartedTaskResponse};
+use client::responses::bulk_by_scroll::BulkByScrollStatus;
+
+/**
+A [update by query request][docs-update-by-query] builder that can be configured before sending.
+
+Call [`Client.update_by_query`][Client.update_by_query] to get a `UpdateByQueryRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-update-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.update_by_query]: ../../struct.Client.html#update-by-query-request
+*/
+pub type UpdateByQueryRequestBuilder<TSender, TResponse> = RequestBuilder<TSender, UpdateByQueryRequestInner<TResponse>>;
+
+#[doc(hidden)]
+pub struct UpdateByQueryRequestInner<TResponse> {
+    index: Index<'static>,
+    ty: Option<Type<'static>>,
+    body: Map<String, Value>,
+    err: Option<Error>,
+    _marker: PhantomData<TResponse>,
+}
+
+/**
+# Update by query request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`UpdateByQueryRequestBuilder`][UpdateByQueryRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Update the `title` of all documents in an index called `myindex` that match a query:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.update_by_query(index("myindex"))
+                         .query(json!({
+                             "term": {
+                                 "archived": true
+                             }
+                         }))
+                         .script_fluent("ctx._source.title = params.title", |script| script
+                            .param("title", "Archived"))
+                         .send()?;
+
+    println!("updated {} documents", response.updated());
+
+    for failure in response.failures() {
+        println!("failed: {:?}", failure);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [UpdateByQueryRequestBuilder]: requests/update_by_query/type.UpdateByQueryRequestBuilder.html
+    [builder-methods]: requests/update_by_query/type.UpdateByQueryRequestBuilder.html#builder-methods
+    [send-sync]: requests/update_by_query/type.UpdateByQueryRequestBuilder.html#send-synchronously
+    [send-async]: requests/update_by_query/type.UpdateByQueryRequestBuilder.html#send-asynchronously
+    */
+    pub fn update_by_query(&self, index: Index<'static>) -> UpdateByQueryRequestBuilder<TSender, UpdateByQueryResponse> {
+        RequestBuilder::new(self.clone(), None, UpdateByQueryRequestInner::new(index))
+    }
+}
+
+impl<TResponse> UpdateByQueryRequestInner<TResponse> {
+    fn new(index: Index<'static>) -> Self {
+        UpdateByQueryRequestInner {
+            index: index,
+            ty: None,
+            body: Map::new(),
+            err: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn set_body<TValue>(&mut self, key: &str, value: TValue)
+    where
+        TValue: Serialize,
+    {
+        if self.err.is_some() {
+            return;
+        }
+
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.body.insert(key.to_owned(), value);
+            }
+            Err(e) => self.err = Some(error::request(e)),
+        }
+    }
+
+    fn set_script<TParams>(&mut self, builder: ScriptBuilder<TParams>)
+    where
+        TParams: Serialize,
+    {
+        if self.err.is_some() {
+            return;
+        }
+
+        match serde_json::to_value(builder.build()) {
+            Ok(Value::Object(script)) => self.body.extend(script),
+            Ok(_) => (),
+            Err(e) => self.err = Some(error::request(e)),
+        }
+    }
+
+    fn into_request(self) -> Result<UpdateByQueryRequest<'static, Vec<u8>>> {
+        if let Some(err) = self.err {
+            return Err(err);
+        }
+
+        let body = serde_json::to_vec(&self.body).map_err(error::request)?;
+
+        let req = match self.ty {
+            Some(ty) => UpdateByQueryRequest::for_index_ty(self.index, ty, body),
+            None => UpdateByQueryRequest::for_index(self.index, body),
+        };
+
+        Ok(req)
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `UpdateByQueryRequestBuilder` before sending it.
+*/
+impl<TSender, TResponse> UpdateByQueryRequestBuilder<TSender, TResponse>
+where
+    TSender: Sender,
+{
+    /** Set the types for the update by query request. */
+    pub fn ty<I>(mut self, ty: Option<I>) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.map(Into::into);
+        self
+    }
+
+    /**
+    Set the query used to find documents to update.
+
+    If no query is specified then all documents will be updated.
+    If the query fails to serialise then the error will be returned when the request is sent.
+    */
+    pub fn query<TQuery>(mut self, query: TQuery) -> Self
+    where
+        TQuery: Serialize,
+    {
+        self.inner.set_body("query", query);
+        self
+    }
+
+    /**
+    Update each matching document using [a script][painless-lang].
+
+    If no script is specified then matching documents will be reindexed without changes, which can be used to pick up new mapping properties.
+    Subsequent calls to `script` will override any previous script properties.
+
+    # Examples
+
+    Update the `title` property of documents using a parameterised script:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::client::requests::document_update::ScriptBuilder;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let script = ScriptBuilder::new("ctx._source.title = params.newTitle")
+        .param("newTitle", "New Title");
+
+    let response = client.update_by_query(index("myindex"))
+                         .script(script)
+                         .send()?;
+
+    println!("updated {} documents", response.updated());
+    # Ok(())
+    # }
+    ```
+
+    [painless-lang]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-painless.html
+    */
+    pub fn script<TScript, TParams>(mut self, builder: TScript) -> Self
+    where
+        TScript: Into<ScriptBuilder<TParams>>,
+        TParams: Serialize,
+    {
+        self.inner.set_script(builder.into());
+        self
+    }
+
+    /**
+    Update each matching document using [a script][painless-lang] configured by a fluent closure API.
+
+    The fluent API can be more ergonomic to work with than constructing builders directly.
+
+    # Examples
+
+    Update the `title` property of documents using a parameterised script:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.update_by_query(index("myindex"))
+                         .script_fluent("ctx._source.title = params.newTitle", |script| script
+                            .param("newTitle", "New Title"))
+                         .send()?;
+
+    println!("updated {} documents", response.updated());
+    # Ok(())
+    # }
+    ```
+
+    [painless-lang]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-painless.html
+    */
+    pub fn script_fluent<TScript, TBuilder, TParams>(self, source: TScript, builder: TBuilder) -> Self
+    where
+        TScript: ToString,
+        TBuilder: Fn(ScriptBuilder<DefaultParams>) -> ScriptBuilder<TParams>,
+        TParams: Serialize,
+    {
+        let builder = builder(ScriptBuilder::new(source));
+
+        self.script(builder)
+    }
+
+    /**
+    Run the update by query request in the background.
+
+    Instead of waiting for all documents to be updated, sending the request will return a [`UpdateByQueryTask`][UpdateByQueryTask] that can be used to check on its progress.
+    This sets the `wait_for_completion` parameter to `false`.
+
+    # Examples
+
+    Start updating documents in the background and wait for it to complete:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let task = client.update_by_query(index("myindex"))
+                     .script("ctx._source.counter++")
+                     .background()
+                     .send()?;
+
+    let response = task.wait_with_progress(|status| {
+        println!("updated {} of {} documents", status.updated(), status.total());
+    })?;
+    # Ok(())
+    # }
+    ```
+
+    [UpdateByQueryTask]: type.UpdateByQueryTask.html
+    */
+    pub fn background(self) -> UpdateByQueryRequestBuilder<TSender, StartedTaskResponse> {
+        let builder = self.params(|p| p.url_param("wait_for_completion", false));
+
+        RequestBuilder::new(
+            builder.client,
+            builder.params,
+            UpdateByQueryRequestInner {
+                index: builder.inner.index,
+                ty: builder.inner.ty,
+                body: builder.inner.body,
+                err: builder.inner.err,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl UpdateByQueryRequestBuilder<SyncSender, UpdateByQueryResponse> {
+    /**
+    Send a `UpdateByQueryRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Update all documents in an index called `myindex` that match a query:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.update_by_query(index("myindex"))
+                         .query(json!({ "term": { "archived": true } }))
+                         .script("ctx._source.counter++")
+                         .send()?;
+
+    println!("updated {} documents", response.updated());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<UpdateByQueryResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send synchronously in the background
+*/
+impl UpdateByQueryRequestBuilder<SyncSender, StartedTaskResponse> {
+    /**
+    Start a `UpdateByQueryRequestBuilder` in the background using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until the update by query task has started.
+    The returned [`UpdateByQueryTask`][UpdateByQueryTask] can be used to check on its progress.
+
+    [SyncClient]: ../../type.SyncClient.html
+    [UpdateByQueryTask]: type.UpdateByQueryTask.html
+    */
+    pub fn send(self) -> Result<UpdateByQueryTask<SyncSender>> {
+        let client = self.client;
+        let req = self.inner.into_request()?;
+
+        let res: StartedTaskResponse = RequestBuilder::new(client.clone(), self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()?;
+
+        Ok(client.task(res.task()))
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl UpdateByQueryRequestBuilder<AsyncSender, UpdateByQueryResponse> {
+    /**
+    Send a `UpdateByQueryRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised update by query response.
+
+    # Examples
+
+    Update all documents in an index called `myindex` that match a query:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.update_by_query(index("myindex"))
+                       .query(json!({ "term": { "archived": true } }))
+                       .script("ctx._source.counter++")
+                       .send();
+
+    future.and_then(|response| {
+        println!("updated {} documents", response.updated());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<UpdateByQueryResponse> {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client, params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/**
+# Send asynchronously in the background
+*/
+impl UpdateByQueryRequestBuilder<AsyncSender, StartedTaskResponse> {
+    /**
+    Start a `UpdateByQueryRequestBuilder` in the background using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to a [`UpdateByQueryTask`][UpdateByQueryTask] once the update by query task has started.
+
+    # Examples
+
+    Start updating documents in the background and wait for it to complete:
+
+    ```no_run
+    # extern crate futures;
+    # extern crate tokio_core;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.update_by_query(index("myindex"))
+                       .script("ctx._source.counter++")
+                       .background()
+                       .send()
+                       .and_then(|task| task.wait());
+
+    future.and_then(|response| {
+        println!("updated {} documents", response.updated());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [UpdateByQueryTask]: type.UpdateByQueryTask.html
+    */
+    pub fn send(self) -> Pending<UpdateByQueryTask<AsyncSender>> {
+        let (client, params, inner) = (self.client, self.params, self.inner);
+
+        let res_future = inner.into_request().into_future().and_then(move |req| {
+            RequestBuilder::new(client.clone(), params, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response::<StartedTaskResponse>())
+                .map(move |res| client.task(res.task()))
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/**
+A handle to a update by query request running in the background.
+
+Call [`UpdateByQueryRequestBuilder.background`][UpdateByQueryRequestBuilder.background] to get a `UpdateByQueryTask` when the request is sent.
+
+[UpdateByQueryRequestBuilder.background]: type.UpdateByQueryRequestBuilder.html#method.background
+*/
+pub type UpdateByQueryTask<TSender> = TaskHandle<TSender, UpdateByQueryResponse, BulkByScrollStatus>;
+
+/** A future returned by calling `send`. */
+pub struct Pending<T> {
+    inner: Box<Future<Item = T, Error = Error>>,
+}
+
+impl<T> Pending<T> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = T, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<T> Future for Pending<T> {
+    type Item = T;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .update_by_query(index("test-idx"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/test-idx/_update_by_query", req.url.as_ref());
+        assert_eq!(json!({}), serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .update_by_query(index("test-idx"))
+            .ty(Some("new-ty"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/test-idx/new-ty/_update_by_query", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_query() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .update_by_query(index("test-idx"))
+            .query(json!({ "match_all": {} }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "query": {
+                "match_all": {}
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_script() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .update_by_query(index("test-idx"))
+            .query(json!({ "match_all": {} }))
+            .script_fluent("ctx._source.a = params.str", |script| script.param("str", "some value"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "query": {
+                "match_all": {}
+            },
+            "script": {
+                "inline": "ctx._source.a = params.str",
+                "params": {
+                    "str": "some value"
+                }
+            }
+        });
+
+        assert_eq!(expected_body, serde_json::from_slice::<Value>(&req.body).unwrap());
+    }
+
+    #[test]
+    fn specify_background() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.update_by_query(index("test-idx")).background();
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+
+        assert_eq!("?wait_for_completion=false", query.unwrap());
+    }
+}
@@ -0,0 +1,370 @@
+/*!
+Builders for [validate query requests][docs-validate].
+
+[docs-validate]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html
+*/
+
+use futures::{Future, Poll};
+
+use error::{Error, Result};
+use client::{AsyncSender, Client, Sender, SyncSender};
+use client::requests::{empty_body, DefaultBody, RequestBuilder};
+use client::requests::params::{Index, Type};
+use client::requests::endpoints::IndicesValidateQueryRequest;
+use client::requests::raw::RawRequestInner;
+use client::responses::ValidateQueryResponse;
+
+/**
+A [validate query request][docs-validate] builder that can be configured before sending.
+
+Call [`Client.validate_query`][Client.validate_query] to get a `ValidateQueryRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-validate]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.validate_query]: ../../struct.Client.html#validate-query-request
+*/
+pub type ValidateQueryRequestBuilder<TSender, TBody> = RequestBuilder<TSender, ValidateQueryRequestInner<TBody>>;
+
+#[doc(hidden)]
+pub struct ValidateQueryRequestInner<TBody> {
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    body: TBody,
+}
+
+/**
+# Validate query request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ValidateQueryRequestBuilder`][ValidateQueryRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether a simple [Query String][docs-querystring] query is valid for an index:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let query = "a query string";
+
+    let response = client.validate_query()
+                         .index("myindex")
+                         .body(json!({
+                             "query": {
+                                 "query_string": {
+                                     "query": query
+                                 }
+                             }
+                         }))
+                         .explain()
+                         .send()?;
+
+    for explanation in response.iter() {
+        if let Some(error) = explanation.error() {
+            println!("invalid query on {}: {}", explanation.index(), error);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [ValidateQueryRequestBuilder]: requests/validate_query/type.ValidateQueryRequestBuilder.html
+    [builder-methods]: requests/validate_query/type.ValidateQueryRequestBuilder.html#builder-methods
+    [send-sync]: requests/validate_query/type.ValidateQueryRequestBuilder.html#send-synchronously
+    [send-async]: requests/validate_query/type.ValidateQueryRequestBuilder.html#send-asynchronously
+    [docs-querystring]: https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-query-string-query.html
+    */
+    pub fn validate_query(&self) -> ValidateQueryRequestBuilder<TSender, DefaultBody> {
+        RequestBuilder::new(self.clone(), None, ValidateQueryRequestInner::new(empty_body()))
+    }
+}
+
+impl<TBody> ValidateQueryRequestInner<TBody> {
+    fn new(body: TBody) -> Self {
+        ValidateQueryRequestInner {
+            index: None,
+            ty: None,
+            body: body,
+        }
+    }
+
+    fn into_request(self) -> IndicesValidateQueryRequest<'static, TBody> {
+        let index = self.index.unwrap_or_else(|| "_all".into());
+
+        match self.ty {
+            Some(ty) => IndicesValidateQueryRequest::for_index_ty(index, ty, self.body),
+            None => IndicesValidateQueryRequest::for_index(index, self.body),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ValidateQueryRequestBuilder` before sending it.
+*/
+impl<TSender, TBody> ValidateQueryRequestBuilder<TSender, TBody>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices for the validate query request.
+
+    If no index is specified then `_all` will be used.
+    */
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index<'static>>,
+    {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /** Set the types for the validate query request. */
+    pub fn ty<I>(mut self, ty: Option<I>) -> Self
+    where
+        I: Into<Type<'static>>,
+    {
+        self.inner.ty = ty.map(Into::into);
+        self
+    }
+
+    /**
+    Return an explanation for each index, including the reason a query isn't valid.
+
+    The explanations are available from [`ValidateQueryResponse.explanations`][ValidateQueryResponse.explanations].
+
+    [ValidateQueryResponse.explanations]: ../../responses/struct.ValidateQueryResponse.html#method.explanations
+    */
+    pub fn explain(self) -> Self {
+        self.params(|p| p.url_param("explain", true))
+    }
+
+    /**
+    Return the query as it will be executed for each index.
+
+    The rewritten query is available from the `explanation` of each [`QueryExplanation`][QueryExplanation].
+
+    [QueryExplanation]: ../../responses/validate/struct.QueryExplanation.html
+    */
+    pub fn rewrite(self) -> Self {
+        self.params(|p| p.url_param("rewrite", true))
+    }
+
+    /**
+    Return an explanation for every shard instead of a single random shard for each index.
+
+    This is only used along with [`rewrite`](#method.rewrite).
+    */
+    pub fn all_shards(self) -> Self {
+        self.params(|p| p.url_param("all_shards", true))
+    }
+
+    /**
+    Set the query body for the validate query request.
+
+    If no body is specified then a `match_all` query will be validated.
+    */
+    pub fn body<TNewBody>(self, body: TNewBody) -> ValidateQueryRequestBuilder<TSender, TNewBody>
+    where
+        TNewBody: Into<TSender::Body>,
+    {
+        RequestBuilder::new(
+            self.client,
+            self.params,
+            ValidateQueryRequestInner {
+                body: body,
+                index: self.inner.index,
+                ty: self.inner.ty,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TBody> ValidateQueryRequestBuilder<SyncSender, TBody>
+where
+    TBody: Into<<SyncSender as Sender>::Body>,
+{
+    /**
+    Send a `ValidateQueryRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Validate a query on an index called `myindex`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.validate_query()
+                         .index("myindex")
+                         .body(r#"{ "query": { "match": { "title": "a title" } } }"#)
+                         .send()?;
+
+    println!("valid: {}", response.valid());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ValidateQueryResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TBody> ValidateQueryRequestBuilder<AsyncSender, TBody>
+where
+    TBody: Into<<AsyncSender as Sender>::Body>,
+{
+    /**
+    Send a `ValidateQueryRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised validate query response.
+
+    # Examples
+
+    Validate a query on an index called `myindex`:
+
+    ```no_run
+    # extern crate tokio_core;
+    # extern crate futures;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let core = tokio_core::reactor::Core::new()?;
+    # let client = AsyncClientBuilder::new().build(&core.handle())?;
+    let future = client.validate_query()
+                       .index("myindex")
+                       .body(r#"{ "query": { "match": { "title": "a title" } } }"#)
+                       .send();
+
+    future.and_then(|response| {
+        println!("valid: {}", response.valid());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future = RequestBuilder::new(self.client, self.params, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = ValidateQueryResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = ValidateQueryResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = ValidateQueryResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.validate_query().inner.into_request();
+
+        assert_eq!("/_all/_validate/query", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .validate_query()
+            .index("new-idx")
+            .ty(Some("new-ty"))
+            .inner
+            .into_request();
+
+        assert_eq!("/new-idx/new-ty/_validate/query", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_body() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.validate_query().body("{}").inner.into_request();
+
+        assert_eq!("{}", req.body);
+    }
+
+    #[test]
+    fn specify_explain_rewrite_all_shards() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .validate_query()
+            .explain()
+            .rewrite()
+            .all_shards();
+
+        let (_, query) = req.params.as_ref().unwrap().get_url_qry();
+        assert_eq!("?all_shards=true&explain=true&rewrite=true", query.unwrap());
+    }
+}
@@ -16,15 +16,33 @@ pub mod parse;
 pub use self::sync::*;
 pub use self::async::*;
 
-pub use elastic_reqwest::res::{BulkErrorsResponse, BulkResponse, CommandResponse, DeleteResponse, GetResponse, IndicesExistsResponse, IndexResponse, PingResponse, SearchResponse, Shards, UpdateResponse};
+pub use elastic_reqwest::res::{AliasExistsResponse, AnalyzeResponse, BulkByScrollResponse, BulkErrorsResponse, BulkResponse, CatAliasesResponse, CatAllocationResponse, CatHealthResponse, CatIndicesResponse, CatNodesResponse, CatResponse, CatSegmentsResponse, CatShardsResponse, CatThreadPoolResponse, ClusterHealthResponse, ClusterHealthStatus, CommandResponse, CountResponse, CreateSnapshotResponse, DeleteByQueryResponse, DeleteResponse, ExistsResponse, ExplainResponse, GetAliasesResponse, GetIndexSettingsResponse, GetIndexTemplatesResponse, GetMappingResponse, GetPipelinesResponse, GetRepositoriesResponse, GetResponse, GetSearchTemplateResponse, GetSnapshotsResponse, IndicesExistsResponse, IndexResponse, IndexTemplateExistsResponse, MgetResponse, MsearchResponse, MtermvectorsResponse, NodesInfoResponse, PingResponse, ReindexResponse, RenderSearchTemplateResponse, RestoreSnapshotResponse, SearchResponse, Shards, SimulatePipelineResponse, SnapshotStatusResponse, StartedTaskResponse, TermvectorsResponse, UpdateByQueryResponse, UpdateResponse, ValidateQueryResponse};
 
 pub use elastic_reqwest::res::search;
+pub use elastic_reqwest::res::msearch;
 pub use elastic_reqwest::res::bulk;
+pub use elastic_reqwest::res::bulk_by_scroll;
+pub use elastic_reqwest::res::mget;
+pub use elastic_reqwest::res::nodes_info;
+pub use elastic_reqwest::res::tasks;
+pub use elastic_reqwest::res::aliases;
+pub use elastic_reqwest::res::index_templates;
+pub use elastic_reqwest::res::mapping;
+pub use elastic_reqwest::res::index_settings;
+pub use elastic_reqwest::res::cluster_health;
+pub use elastic_reqwest::res::snapshot;
+pub use elastic_reqwest::res::ingest;
+pub use elastic_reqwest::res::cat;
+pub use elastic_reqwest::res::analyze;
+pub use elastic_reqwest::res::explain;
+pub use elastic_reqwest::res::validate;
+pub use elastic_reqwest::res::termvectors;
+pub use elastic_reqwest::res::search_template;
 
 pub mod prelude {
     /*! A glob import for convenience. */
 
-    pub use super::{BulkErrorsResponse, BulkResponse, CommandResponse, DeleteResponse, GetResponse, IndicesExistsResponse, IndexResponse, PingResponse, SearchResponse, Shards, UpdateResponse};
+    pub use super::{AliasExistsResponse, AnalyzeResponse, BulkByScrollResponse, BulkErrorsResponse, BulkResponse, CatAliasesResponse, CatAllocationResponse, CatHealthResponse, CatIndicesResponse, CatNodesResponse, CatResponse, CatSegmentsResponse, CatShardsResponse, CatThreadPoolResponse, ClusterHealthResponse, ClusterHealthStatus, CommandResponse, CountResponse, CreateSnapshotResponse, DeleteByQueryResponse, DeleteResponse, ExistsResponse, ExplainResponse, GetAliasesResponse, GetIndexSettingsResponse, GetIndexTemplatesResponse, GetMappingResponse, GetPipelinesResponse, GetRepositoriesResponse, GetResponse, GetSearchTemplateResponse, GetSnapshotsResponse, IndicesExistsResponse, IndexResponse, IndexTemplateExistsResponse, MgetResponse, MsearchResponse, MtermvectorsResponse, NodesInfoResponse, PingResponse, ReindexResponse, RenderSearchTemplateResponse, RestoreSnapshotResponse, SearchResponse, Shards, SimulatePipelineResponse, SnapshotStatusResponse, StartedTaskResponse, TermvectorsResponse, UpdateByQueryResponse, UpdateResponse, ValidateQueryResponse};
 
     pub use super::async::AsyncResponseBuilder;
     pub use super::sync::SyncResponseBuilder;
@@ -0,0 +1,457 @@
+/*!
+Retry policies for requests that fail with a transient error.
+
+By default, requests are never retried.
+A [`RetryPolicy`][RetryPolicy] can be given to the client builders to retry requests that fail with a retryable status code or error.
+Only requests with an idempotent method (`HEAD`, `GET`, `PUT` and `DELETE`) are retried unless the policy opts in to retrying any method.
+Requests with a body that can't be cloned, like a `File`, are never retried.
+
+[RetryPolicy]: trait.RetryPolicy.html
+*/
+
+use std::cmp;
+use std::sync::Arc;
+use std::time::Duration;
+use rand::{self, Rng};
+use elastic_reqwest::{AsyncBody, SyncBody};
+
+use error::Error;
+use client::requests::{HttpMethod, HttpRequest, Url};
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_INITIAL_BACKOFF_MILLIS: u64 = 100;
+const DEFAULT_MAX_BACKOFF_MILLIS: u64 = 10 * 1000;
+const DEFAULT_RETRY_STATUSES: &'static [u16] = &[502, 503, 504];
+
+/**
+A policy that decides whether a failed request should be retried.
+
+# Examples
+
+Retry any request up to `5` times with a fixed back-off when a node is unavailable:
+
+```
+# use std::time::Duration;
+# use elastic::prelude::*;
+use elastic::client::{Failure, FailureKind, RetryPolicy};
+
+struct FixedBackoff;
+
+impl RetryPolicy for FixedBackoff {
+    fn retry(&self, failure: &Failure) -> Option<Duration> {
+        match *failure.kind() {
+            FailureKind::Status(503) if failure.attempt() < 5 => Some(Duration::from_millis(500)),
+            _ => None,
+        }
+    }
+
+    fn retry_non_idempotent(&self) -> bool {
+        true
+    }
+}
+
+let builder = SyncClientBuilder::new().retry_policy(FixedBackoff);
+```
+*/
+pub trait RetryPolicy: Send + Sync {
+    /**
+    Decide whether a failed request should be retried.
+
+    Returns the amount of time to wait before sending the request again, or `None` if the failure should be returned to the caller.
+    */
+    fn retry(&self, failure: &Failure) -> Option<Duration>;
+
+    /**
+    Whether requests with a method that isn't idempotent, like `POST`, can be retried.
+
+    The default is `false`.
+    */
+    fn retry_non_idempotent(&self) -> bool {
+        false
+    }
+}
+
+/** A failed attempt to send a request. */
+pub struct Failure<'a> {
+    attempt: u32,
+    method: &'a HttpMethod,
+    kind: FailureKind<'a>,
+}
+
+impl<'a> Failure<'a> {
+    pub(crate) fn new(attempt: u32, method: &'a HttpMethod, kind: FailureKind<'a>) -> Self {
+        Failure {
+            attempt: attempt,
+            method: method,
+            kind: kind,
+        }
+    }
+
+    /** The number of attempts that have been made to send the request, including this one. */
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /** The method of the request. */
+    pub fn method(&self) -> &HttpMethod {
+        self.method
+    }
+
+    /** The kind of failure. */
+    pub fn kind(&self) -> &FailureKind<'a> {
+        &self.kind
+    }
+}
+
+/** The kind of failure for an attempt to send a request. */
+pub enum FailureKind<'a> {
+    /** A response was received with an unsuccessful status code. */
+    Status(u16),
+    /** The request couldn't be sent or a response couldn't be received. */
+    Error(&'a Error),
+}
+
+/**
+A retry policy with exponential back-off and jitter.
+
+The back-off before each retry is a random duration between zero and `initial_backoff * 2^(attempt - 1)`, up to `max_backoff`.
+
+By default, the policy will:
+
+- Make at most `3` attempts to send a request
+- Start with a back-off of `100ms`, up to a maximum of `10s`
+- Retry responses with a `502`, `503` or `504` status code
+- Retry requests that fail to send or receive a response
+- Only retry requests with an idempotent method
+
+# Examples
+
+Retry requests up to `5` times when Elasticsearch rejects them:
+
+```
+# use elastic::prelude::*;
+use elastic::client::ExponentialBackoff;
+
+let builder = SyncClientBuilder::new()
+    .retry_policy(ExponentialBackoff::new()
+        .max_attempts(5)
+        .retry_status(vec![429, 502, 503, 504]));
+```
+*/
+pub struct ExponentialBackoff {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    retry_status: Vec<u16>,
+    retry_errors: bool,
+    retry_non_idempotent: bool,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        ExponentialBackoff::new()
+    }
+}
+
+impl ExponentialBackoff {
+    /** Create a new exponential back-off policy with the default settings. */
+    pub fn new() -> Self {
+        ExponentialBackoff {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: Duration::from_millis(DEFAULT_INITIAL_BACKOFF_MILLIS),
+            max_backoff: Duration::from_millis(DEFAULT_MAX_BACKOFF_MILLIS),
+            retry_status: DEFAULT_RETRY_STATUSES.to_vec(),
+            retry_errors: true,
+            retry_non_idempotent: false,
+        }
+    }
+
+    /** Set the maximum number of attempts to send a request, including the first one. */
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /** Set the back-off for the first retry. */
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /** Set the maximum back-off between retries. */
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /** Set the response status codes that will be retried. */
+    pub fn retry_status<I>(mut self, retry_status: I) -> Self
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        self.retry_status = retry_status.into_iter().collect();
+        self
+    }
+
+    /** Set whether requests that fail to send or receive a response will be retried. */
+    pub fn retry_errors(mut self, retry_errors: bool) -> Self {
+        self.retry_errors = retry_errors;
+        self
+    }
+
+    /**
+    Set whether requests with a method that isn't idempotent, like `POST`, will be retried.
+
+    Only opt in to this if retrying a request that may have already been applied is safe.
+    */
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let doublings = cmp::min(attempt.saturating_sub(1), 31);
+        let max_millis = duration_millis(self.max_backoff);
+        let backoff_millis = cmp::min(duration_millis(self.initial_backoff).saturating_mul(1u64 << doublings), max_millis);
+
+        if backoff_millis == 0 {
+            return Duration::from_millis(0);
+        }
+
+        Duration::from_millis(rand::thread_rng().gen_range(0, backoff_millis + 1))
+    }
+}
+
+impl RetryPolicy for ExponentialBackoff {
+    fn retry(&self, failure: &Failure) -> Option<Duration> {
+        if failure.attempt() >= self.max_attempts {
+            return None;
+        }
+
+        let retryable = match *failure.kind() {
+            FailureKind::Status(status) => self.retry_status.contains(&status),
+            FailureKind::Error(_) => self.retry_errors,
+        };
+
+        if retryable {
+            Some(self.backoff(failure.attempt()))
+        } else {
+            None
+        }
+    }
+
+    fn retry_non_idempotent(&self) -> bool {
+        self.retry_non_idempotent
+    }
+}
+
+fn duration_millis(duration: Duration) -> u64 {
+    duration
+        .as_secs()
+        .saturating_mul(1000)
+        .saturating_add((duration.subsec_nanos() / 1_000_000) as u64)
+}
+
+/** A request body that may be cloned so the request can be retried. */
+pub(crate) trait TryCloneBody: Sized {
+    fn try_clone_body(&self) -> Option<Self>;
+}
+
+impl TryCloneBody for SyncBody {
+    fn try_clone_body(&self) -> Option<Self> {
+        self.try_clone()
+    }
+}
+
+impl TryCloneBody for AsyncBody {
+    fn try_clone_body(&self) -> Option<Self> {
+        self.try_clone()
+    }
+}
+
+/**
+The attempts to send a single request.
+
+A copy of the request body is kept between attempts so the request can be retried.
+*/
+pub(crate) struct Attempts<TBody> {
+    policy: Option<Arc<RetryPolicy>>,
+    url: Url<'static>,
+    method: HttpMethod,
+    body: Option<TBody>,
+    attempt: u32,
+}
+
+impl<TBody> Attempts<TBody>
+where
+    TBody: TryCloneBody,
+{
+    pub(crate) fn new(policy: Option<Arc<RetryPolicy>>, req: HttpRequest<'static, TBody>) -> Self {
+        let policy = policy.and_then(|policy| {
+            if is_idempotent(&req.method) || policy.retry_non_idempotent() {
+                Some(policy)
+            } else {
+                None
+            }
+        });
+
+        Attempts {
+            policy: policy,
+            url: req.url,
+            method: req.method,
+            body: req.body,
+            attempt: 0,
+        }
+    }
+
+    /**
+    Get the request for the next attempt.
+
+    If the body can't be cloned then the request won't be retried.
+    */
+    pub(crate) fn next(&mut self) -> HttpRequest<'static, TBody> {
+        self.attempt += 1;
+
+        let body = match (self.policy.is_some(), self.body.take()) {
+            (true, Some(body)) => match body.try_clone_body() {
+                Some(retry_body) => {
+                    self.body = Some(retry_body);
+                    Some(body)
+                }
+                None => {
+                    self.policy = None;
+                    Some(body)
+                }
+            },
+            (_, body) => body,
+        };
+
+        HttpRequest {
+            url: self.url.clone(),
+            method: self.method.clone(),
+            body: body,
+        }
+    }
+
+    /**
+    Get the back-off before retrying the last attempt.
+
+    Returns `None` if the attempt shouldn't be retried.
+    */
+    pub(crate) fn retry(&self, kind: FailureKind) -> Option<Duration> {
+        let failure = Failure::new(self.attempt, &self.method, kind);
+
+        self.policy.as_ref().and_then(|policy| policy.retry(&failure))
+    }
+
+    /** The number of attempts made so far. */
+    pub(crate) fn attempt(&self) -> u32 {
+        self.attempt
+    }
+}
+
+/**
+Whether or not a response status code is an error that a policy could retry.
+
+Redirects and other non-error statuses are never retried.
+*/
+pub(crate) fn is_failure_status(status: u16) -> bool {
+    status >= 400
+}
+
+fn is_idempotent(method: &HttpMethod) -> bool {
+    match *method {
+        HttpMethod::Head | HttpMethod::Get | HttpMethod::Put | HttpMethod::Delete => true,
+        HttpMethod::Post | HttpMethod::Patch => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use elastic_reqwest::SyncBody;
+
+    use super::*;
+    use client::requests::{HttpMethod, HttpRequest};
+
+    fn request(method: HttpMethod) -> HttpRequest<'static, SyncBody> {
+        HttpRequest {
+            url: "/".into(),
+            method: method,
+            body: Some("{}".into()),
+        }
+    }
+
+    fn attempts(policy: ExponentialBackoff, method: HttpMethod) -> Attempts<SyncBody> {
+        let policy: Arc<RetryPolicy> = Arc::new(policy.initial_backoff(Duration::from_millis(0)));
+
+        Attempts::new(Some(policy), request(method))
+    }
+
+    #[test]
+    fn retry_status_until_max_attempts() {
+        let mut attempts = attempts(ExponentialBackoff::new().max_attempts(2), HttpMethod::Get);
+
+        attempts.next();
+        assert!(attempts.retry(FailureKind::Status(503)).is_some());
+
+        attempts.next();
+        assert!(attempts.retry(FailureKind::Status(503)).is_none());
+    }
+
+    #[test]
+    fn no_retry_for_other_status() {
+        let mut attempts = attempts(ExponentialBackoff::new(), HttpMethod::Get);
+
+        attempts.next();
+
+        assert!(attempts.retry(FailureKind::Status(404)).is_none());
+    }
+
+    #[test]
+    fn no_retry_for_non_idempotent_method() {
+        let mut attempts = attempts(ExponentialBackoff::new(), HttpMethod::Post);
+
+        attempts.next();
+
+        assert!(attempts.retry(FailureKind::Status(503)).is_none());
+    }
+
+    #[test]
+    fn retry_for_non_idempotent_method_opt_in() {
+        let mut attempts = attempts(ExponentialBackoff::new().retry_non_idempotent(true), HttpMethod::Post);
+
+        attempts.next();
+
+        assert!(attempts.retry(FailureKind::Status(503)).is_some());
+    }
+
+    #[test]
+    fn only_error_statuses_are_failures() {
+        assert!(!is_failure_status(200));
+        assert!(!is_failure_status(301));
+        assert!(!is_failure_status(304));
+        assert!(is_failure_status(429));
+        assert!(is_failure_status(503));
+    }
+
+    #[test]
+    fn body_is_kept_for_retries() {
+        let mut attempts = attempts(ExponentialBackoff::new(), HttpMethod::Put);
+
+        assert!(attempts.next().body.is_some());
+        assert!(attempts.next().body.is_some());
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let policy = ExponentialBackoff::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(200));
+
+        for attempt in 1..40 {
+            assert!(policy.backoff(attempt) <= Duration::from_millis(200));
+        }
+    }
+}
@@ -1,11 +1,19 @@
+use std::io;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 use uuid::Uuid;
-use elastic_reqwest::{SyncBody, SyncElasticClient};
+use elastic_reqwest::{Error as ElasticReqwestError, SyncBody, SyncElasticClient};
 use reqwest::{Client as SyncHttpClient, ClientBuilder as SyncHttpClientBuilder};
 
-use error::{self, Result};
+use error::{self, Error, Result};
 use client::requests::HttpRequest;
 use client::responses::{sync_response, SyncResponseBuilder};
 use client::{private, Client, RequestParams, Sender};
+use client::nodes::{is_unreachable, NodeAddresses, NodePool, SniffedNodesBuilder};
+use client::responses::NodesInfoResponse;
+use client::retry::{is_failure_status, Attempts, FailureKind, RetryPolicy};
 
 /** 
 A synchronous Elasticsearch client.
@@ -35,7 +43,9 @@ pub type SyncClient = Client<SyncSender>;
 /** A synchronous request sender. */
 #[derive(Clone)]
 pub struct SyncSender {
-    pub(in client) http: SyncHttpClient,
+    pub(in client) http: SyncHttpClients,
+    pub(in client) nodes: NodeAddresses,
+    pub(in client) retry: Option<Arc<RetryPolicy>>,
 }
 
 impl private::Sealed for SyncSender {}
@@ -49,42 +59,194 @@ impl Sender for SyncSender {
         TRequest: Into<HttpRequest<'static, TBody>>,
         TBody: Into<Self::Body>,
     {
+        if let Some(refresh) = self.nodes.should_refresh() {
+            let res = self.send(refresh.sniff_request(), &refresh.sniff_params(params))
+                .and_then(|res| res.into_response::<NodesInfoResponse>());
+
+            refresh.refresh(res);
+        }
+
         let correlation_id = Uuid::new_v4();
         let req = req.into();
-
-        info!(
-            "Elasticsearch Request: correlation_id: '{}', method: '{:?}', path: '{}'",
-            correlation_id,
-            req.method,
-            req.url.as_ref()
+        let http = self.http.get(params.get_timeout())?;
+
+        let mut attempts = Attempts::new(
+            self.retry.clone(),
+            HttpRequest {
+                url: req.url,
+                method: req.method,
+                body: req.body.map(Into::into),
+            },
         );
 
-        let res = match self.http.elastic_req(params, req).map_err(error::request) {
-            Ok(res) => {
-                info!(
-                    "Elasticsearch Response: correlation_id: '{}', status: '{}'",
-                    correlation_id,
-                    res.status()
-                );
-                res
+        loop {
+            let req = attempts.next();
+
+            let node = self.nodes.next();
+            let node_params = node.as_ref().map(|node| node.params(params));
+            let params = node_params.as_ref().unwrap_or(params);
+
+            info!(
+                "Elasticsearch Request: correlation_id: '{}', attempt: '{}', method: '{:?}', base url: '{}', path: '{}'",
+                correlation_id,
+                attempts.attempt(),
+                req.method,
+                params.get_base_url(),
+                req.url.as_ref()
+            );
+
+            let res = match http.elastic_req(params, req) {
+                Ok(res) => {
+                    if let Some(ref node) = node {
+                        node.mark_alive();
+                    }
+
+                    info!(
+                        "Elasticsearch Response: correlation_id: '{}', status: '{}'",
+                        correlation_id,
+                        res.status()
+                    );
+                    res
+                }
+                Err(e) => {
+                    if let Some(ref node) = node {
+                        if is_unreachable(&e) {
+                            node.mark_dead();
+                        }
+                    }
+
+                    let e = send_error(e);
+
+                    error!(
+                        "Elasticsearch Response: correlation_id: '{}', error: '{}'",
+                        correlation_id,
+                        e
+                    );
+
+                    if let Some(backoff) = attempts.retry(FailureKind::Error(&e)) {
+                        thread::sleep(backoff);
+                        continue;
+                    }
+
+                    Err(e)?
+                }
+            };
+
+            let status: u16 = res.status().into();
+            if is_failure_status(status) {
+                if let Some(backoff) = attempts.retry(FailureKind::Status(status)) {
+                    thread::sleep(backoff);
+                    continue;
+                }
             }
-            Err(e) => {
-                error!(
-                    "Elasticsearch Response: correlation_id: '{}', error: '{}'",
-                    correlation_id,
-                    e
-                );
-                Err(e)?
+
+            return Ok(sync_response(res));
+        }
+    }
+}
+
+/**
+The `reqwest::Client`s used to send requests.
+
+A `reqwest::Client` can't change its timeout once it's built.
+Requests with a different timeout to the default are sent with a client that's built for that timeout and reused for later requests with the same timeout.
+*/
+#[derive(Clone)]
+pub(in client) struct SyncHttpClients {
+    default: SyncHttpClient,
+    timeout: Option<Duration>,
+    builder: Option<Arc<Fn() -> SyncHttpClientBuilder + Send + Sync>>,
+    timeouts: Arc<Mutex<HashMap<Duration, SyncHttpClient>>>,
+}
+
+impl SyncHttpClients {
+    fn fixed(http: SyncHttpClient) -> Self {
+        SyncHttpClients {
+            default: http,
+            timeout: None,
+            builder: None,
+            timeouts: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn build(builder: Arc<Fn() -> SyncHttpClientBuilder + Send + Sync>, timeout: Option<Duration>) -> Result<Self> {
+        let mut default = builder();
+        if let Some(timeout) = timeout {
+            default.timeout(timeout);
+        }
+
+        Ok(SyncHttpClients {
+            default: default.build().map_err(error::build)?,
+            timeout: timeout,
+            builder: Some(builder),
+            timeouts: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    /** Get a client that will wait at most the given timeout for a response. */
+    fn get(&self, timeout: Option<Duration>) -> Result<SyncHttpClient> {
+        let timeout = match timeout {
+            Some(timeout) if Some(timeout) != self.timeout => timeout,
+            _ => return Ok(self.default.clone()),
+        };
+
+        let builder = match self.builder {
+            Some(ref builder) => builder,
+            None => {
+                return Err(error::request(io::Error::new(
+                    io::ErrorKind::Other,
+                    "a timeout can't be applied to a given `reqwest::Client`, use `http_client_builder` instead",
+                )))
             }
         };
 
-        Ok(sync_response(res))
+        let mut timeouts = self.timeouts.lock().map_err(|_| error::request(io::Error::new(io::ErrorKind::Other, "the cached `reqwest::Client`s are poisoned")))?;
+
+        if let Some(http) = timeouts.get(&timeout) {
+            return Ok(http.clone());
+        }
+
+        let mut http = builder();
+        http.timeout(timeout);
+
+        let http = http.build().map_err(error::request)?;
+        timeouts.insert(timeout, http.clone());
+
+        Ok(http)
+    }
+}
+
+/**
+Convert an error sending a request into a client error.
+
+The `reqwest::Client` returns an io error when a request times out.
+The kind of that error is `WouldBlock` on unix platforms and `TimedOut` on Windows.
+*/
+fn send_error(err: ElasticReqwestError) -> Error {
+    let is_timeout = match err {
+        ElasticReqwestError::Http(ref err) => err.get_ref()
+            .and_then(|err| err.downcast_ref::<io::Error>())
+            .map(|err| match err.kind() {
+                io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => true,
+                _ => false,
+            })
+            .unwrap_or(false),
+        _ => false,
+    };
+
+    if is_timeout {
+        error::timeout()
+    } else {
+        error::request(err)
     }
 }
 
 /** A builder for a syncronous client. */
 pub struct SyncClientBuilder {
     http: Option<SyncHttpClient>,
+    http_builder: Option<Arc<Fn() -> SyncHttpClientBuilder + Send + Sync>>,
+    nodes: NodeAddresses,
+    retry: Option<Arc<RetryPolicy>>,
     params: RequestParams,
 }
 
@@ -103,10 +265,14 @@ impl SyncClientBuilder {
     - Send requests to `localhost:9200`
     - Not use any authentication
     - Not use TLS
+    - Not time out requests
     */
     pub fn new() -> Self {
         SyncClientBuilder {
             http: None,
+            http_builder: None,
+            nodes: NodeAddresses::Default,
+            retry: None,
             params: RequestParams::default(),
         }
     }
@@ -117,6 +283,9 @@ impl SyncClientBuilder {
     pub fn from_params(params: RequestParams) -> Self {
         SyncClientBuilder {
             http: None,
+            http_builder: None,
+            nodes: NodeAddresses::Default,
+            retry: None,
             params: params,
         }
     }
@@ -147,6 +316,93 @@ impl SyncClientBuilder {
         self
     }
 
+    /**
+    Send requests to a fixed set of nodes.
+
+    The nodes must be fully qualified urls.
+    Requests are sent to each node in turn.
+    If a request fails to connect to a node then that node won't be used again until a back-off period has passed.
+    The back-off starts at a minute and doubles for each consecutive failure.
+
+    Setting static nodes overrides the base url from the default request parameters.
+
+    # Examples
+
+    Send requests to a cluster of three nodes:
+
+    ```
+    # use elastic::prelude::*;
+    let builder = SyncClientBuilder::new()
+        .static_nodes(vec![
+            "http://es_node_1:9200",
+            "http://es_node_2:9200",
+            "http://es_node_3:9200",
+        ]);
+    ```
+    */
+    pub fn static_nodes<I, S>(mut self, nodes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.nodes = NodeAddresses::Static(NodePool::new(nodes));
+
+        self
+    }
+
+    /**
+    Sniff the nodes in the cluster, starting from a seed node.
+
+    The seed node must be a fully qualified url.
+    Before the first request is sent the client will fetch the `http.publish_address` of each node in the cluster, and send requests to those nodes in turn.
+    The nodes will be sniffed again every `5` minutes.
+    Use [`sniff_nodes_fluent`][SyncClientBuilder.sniff_nodes_fluent] to change how often nodes are sniffed.
+
+    Sniffing nodes overrides the base url from the default request parameters.
+
+    # Examples
+
+    Sniff the nodes in a cluster from a local node:
+
+    ```
+    # use elastic::prelude::*;
+    let builder = SyncClientBuilder::new()
+        .sniff_nodes("http://localhost:9200");
+    ```
+
+    [SyncClientBuilder.sniff_nodes_fluent]: #method.sniff_nodes_fluent
+    */
+    pub fn sniff_nodes<I>(self, seed: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.sniff_nodes_fluent(seed, |n| n)
+    }
+
+    /**
+    Sniff the nodes in the cluster, starting from a seed node, with a function to configure how nodes are sniffed.
+
+    # Examples
+
+    Sniff the nodes in a cluster every minute:
+
+    ```
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    let builder = SyncClientBuilder::new()
+        .sniff_nodes_fluent("http://localhost:9200", |n| n.wait(Duration::from_secs(60)));
+    ```
+    */
+    pub fn sniff_nodes_fluent<I, F>(mut self, seed: I, builder: F) -> Self
+    where
+        I: Into<String>,
+        F: Fn(SniffedNodesBuilder) -> SniffedNodesBuilder,
+    {
+        self.nodes = NodeAddresses::Sniffed(builder(SniffedNodesBuilder::new(seed)).build());
+
+        self
+    }
+
     /**
     Specify default request parameters.
     
@@ -195,9 +451,120 @@ impl SyncClientBuilder {
         self
     }
 
-    /** Use the given `reqwest::Client` for sending requests. */
+    /**
+    Set a default timeout for requests.
+
+    By default, requests don't time out.
+    If a response isn't received before the timeout expires then the request
+    will fail with a client error where [`is_timeout`][ClientError.is_timeout] is `true`.
+    The timeout applies to each attempt when requests are retried, and can be overridden per request.
+
+    The timeout is set on the `reqwest::Client`s the `SyncClient` builds, so it can't be combined with a `reqwest::Client` given to [`http_client`][SyncClientBuilder.http_client].
+    Use [`http_client_builder`][SyncClientBuilder.http_client_builder] to configure the `reqwest::Client`s instead.
+
+    # Examples
+
+    Wait at most 10 seconds for a response:
+
+    ```
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    let builder = SyncClientBuilder::new()
+        .timeout(Duration::from_secs(10));
+    ```
+
+    [ClientError.is_timeout]: ../error/struct.ClientError.html#method.is_timeout
+    [SyncClientBuilder.http_client]: #method.http_client
+    [SyncClientBuilder.http_client_builder]: #method.http_client_builder
+    */
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.params = self.params.timeout(timeout);
+
+        self
+    }
+
+    /**
+    Retry requests that fail with a transient error using the given policy.
+
+    By default, requests aren't retried.
+    Only requests with an idempotent method are retried unless the policy opts in to retrying any method.
+    See the [`RetryPolicy`][RetryPolicy] trait for more details.
+
+    # Examples
+
+    Retry requests using an exponential back-off:
+
+    ```
+    # use elastic::prelude::*;
+    use elastic::client::ExponentialBackoff;
+
+    let builder = SyncClientBuilder::new()
+        .retry_policy(ExponentialBackoff::new().max_attempts(5));
+    ```
+
+    [RetryPolicy]: trait.RetryPolicy.html
+    */
+    pub fn retry_policy<P>(mut self, policy: P) -> Self
+    where
+        P: RetryPolicy + 'static,
+    {
+        self.retry = Some(Arc::new(policy));
+
+        self
+    }
+
+    /**
+    Use the given `reqwest::Client` for sending requests.
+
+    A `reqwest::Client` can't change its timeout, so requests sent with this client can't use a [`timeout`][SyncClientBuilder.timeout].
+    Use [`http_client_builder`][SyncClientBuilder.http_client_builder] to send requests with a timeout.
+
+    [SyncClientBuilder.timeout]: #method.timeout
+    [SyncClientBuilder.http_client_builder]: #method.http_client_builder
+    */
     pub fn http_client(mut self, client: SyncHttpClient) -> Self {
         self.http = Some(client);
+        self.http_builder = None;
+
+        self
+    }
+
+    /**
+    Build the `reqwest::Client`s for sending requests with the given function.
+
+    The function is called to build the default client, and again for each distinct per-request timeout.
+    The timeout is set on the `reqwest::ClientBuilder` it returns, so other options like proxies and certificates work alongside a timeout.
+
+    # Examples
+
+    Send requests through a proxy and wait at most 10 seconds for a response:
+
+    ```no_run
+    # extern crate elastic;
+    # extern crate reqwest;
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    let client = SyncClientBuilder::new()
+        .http_client_builder(|| {
+            let mut builder = reqwest::ClientBuilder::new();
+            builder.proxy(reqwest::Proxy::all("http://my_proxy:8080").unwrap());
+
+            builder
+        })
+        .timeout(Duration::from_secs(10))
+        .build()?;
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn http_client_builder<F>(mut self, builder: F) -> Self
+    where
+        F: Fn() -> SyncHttpClientBuilder + Send + Sync + 'static,
+    {
+        self.http_builder = Some(Arc::new(builder));
+        self.http = None;
 
         self
     }
@@ -208,14 +575,81 @@ impl SyncClientBuilder {
     [Client]: struct.Client.html
     */
     pub fn build(self) -> Result<SyncClient> {
-        let http = self.http
-            .map(Ok)
-            .unwrap_or_else(|| SyncHttpClientBuilder::new().build())
-            .map_err(error::build)?;
+        let timeout = self.params.get_timeout();
+
+        let http = match (self.http, timeout) {
+            (Some(_), Some(_)) => {
+                return Err(error::build(io::Error::new(
+                    io::ErrorKind::Other,
+                    "a default timeout can't be applied to a given `reqwest::Client`, use `http_client_builder` instead",
+                )))
+            }
+            (Some(http), None) => SyncHttpClients::fixed(http),
+            (None, timeout) => {
+                let builder = self.http_builder.unwrap_or_else(|| Arc::new(SyncHttpClientBuilder::new));
+
+                SyncHttpClients::build(builder, timeout)?
+            }
+        };
 
         Ok(SyncClient {
-            sender: SyncSender { http: http },
+            sender: SyncSender {
+                http: http,
+                nodes: self.nodes,
+                retry: self.retry,
+            },
             params: self.params,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use reqwest::{Client as SyncHttpClient, ClientBuilder as SyncHttpClientBuilder};
+    use elastic_reqwest::Error as ElasticReqwestError;
+
+    use super::{send_error, SyncHttpClients};
+    use error::Error;
+
+    #[test]
+    fn http_clients_are_reused_for_a_timeout() {
+        let http = SyncHttpClients::build(Arc::new(SyncHttpClientBuilder::new), Some(Duration::from_secs(5))).unwrap();
+
+        http.get(Some(Duration::from_secs(1))).unwrap();
+        http.get(Some(Duration::from_secs(1))).unwrap();
+        http.get(Some(Duration::from_secs(5))).unwrap();
+        http.get(None).unwrap();
+
+        assert_eq!(1, http.timeouts.lock().unwrap().len());
+    }
+
+    #[test]
+    fn fixed_http_client_rejects_timeout() {
+        let http = SyncHttpClients::fixed(SyncHttpClient::new());
+
+        assert!(http.get(None).is_ok());
+        assert!(http.get(Some(Duration::from_secs(1))).is_err());
+    }
+
+    #[test]
+    fn timed_out_request_is_timeout_error() {
+        // The listener accepts connections but never responds
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let http = SyncHttpClientBuilder::new()
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let err = http.get(&url).send().unwrap_err();
+
+        match send_error(ElasticReqwestError::from(err)) {
+            Error::Client(ref e) => assert!(e.is_timeout()),
+            _ => panic!("expected a client error"),
+        }
+    }
+}
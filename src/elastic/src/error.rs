@@ -101,6 +101,30 @@ pub struct ClientError {
     inner: inner::Error,
 }
 
+impl ClientError {
+    /** Whether or not the error was caused by a request timing out. */
+    pub fn is_timeout(&self) -> bool {
+        match *self.inner.kind() {
+            inner::ErrorKind::Timeout => true,
+            _ => false,
+        }
+    }
+
+    /**
+    Whether or not Elasticsearch responded before a condition the request was waiting for was met.
+
+    Requests like [`cluster_health`](../struct.Client.html#cluster-health-request) can wait for the cluster to reach a state.
+    If that state isn't reached in time then Elasticsearch still returns a successful response, but with `timed_out: true` in its body.
+    The client turns that response into this error instead of returning it.
+    */
+    pub fn is_wait_timeout(&self) -> bool {
+        match *self.inner.kind() {
+            inner::ErrorKind::WaitTimeout => true,
+            _ => false,
+        }
+    }
+}
+
 impl StdError for ClientError {
     fn description(&self) -> &str {
         self.inner.description()
@@ -135,6 +159,18 @@ where
     })
 }
 
+pub(crate) fn timeout() -> Error {
+    Error::Client(ClientError {
+        inner: inner::Error::from_kind(inner::ErrorKind::Timeout),
+    })
+}
+
+pub(crate) fn wait_timeout() -> Error {
+    Error::Client(ClientError {
+        inner: inner::Error::from_kind(inner::ErrorKind::WaitTimeout),
+    })
+}
+
 pub(crate) fn response<E>(status: u16, err: E) -> Error
 where
     E: Into<MaybeApiError<E>> + StdError + Send + 'static,
@@ -210,6 +246,14 @@ mod inner {
                 description("error sending a request")
                 display("error sending a request")
             }
+            Timeout {
+                description("timed out waiting for a response")
+                display("timed out waiting for a response")
+            }
+            WaitTimeout {
+                description("timed out waiting for the cluster to reach the requested state")
+                display("timed out waiting for the cluster to reach the requested state")
+            }
             Response(status: u16) {
                 description("error receiving a response")
                 display("error receiving a response. Status code: {}", status)
@@ -227,4 +271,33 @@ mod tests {
     fn error_is_send_sync() {
         assert_send::<Error>();
     }
+
+    #[test]
+    fn timeout_error_is_timeout() {
+        match timeout() {
+            Error::Client(ref e) => assert!(e.is_timeout()),
+            _ => panic!("expected a client error"),
+        }
+    }
+
+    #[test]
+    fn wait_timeout_error_is_not_timeout() {
+        match wait_timeout() {
+            Error::Client(ref e) => {
+                assert!(e.is_wait_timeout());
+                assert!(!e.is_timeout());
+            }
+            _ => panic!("expected a client error"),
+        }
+    }
+
+    #[test]
+    fn request_error_is_not_timeout() {
+        let err = request(io::Error::new(io::ErrorKind::Other, "no connection"));
+
+        match err {
+            Error::Client(ref e) => assert!(!e.is_timeout()),
+            _ => panic!("expected a client error"),
+        }
+    }
 }
@@ -286,6 +286,7 @@ extern crate futures;
 extern crate futures_cpupool;
 #[macro_use]
 extern crate log;
+extern crate rand;
 extern crate reqwest;
 extern crate serde;
 #[macro_use]
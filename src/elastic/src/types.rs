@@ -71,6 +71,16 @@ For some types (like `Date`), it's helpful to have an extra generic parameter th
 For most types the format isn't exposed, because there aren't any alternative formats available.
 This is a particularly helpful feature for serialisation.
 
+## Settings
+
+Index settings, including the analysis chain, are modelled by [`IndexSettings`][settings-mod].
+Custom analyzers defined in settings can be referenced by name in a field mapping, like `TextMapping::analyzer`.
+
+## Ingest
+
+Ingest pipelines and their processors are modelled by [`Pipeline`][ingest-mod].
+A pipeline can be put on the cluster and then referenced by name when indexing documents.
+
 # Examples
 
 ## Derive document mapping
@@ -260,9 +270,11 @@ Serialising `MyType`s mapping will produce the following json:
 [date-mod]: date/index.html
 [geopoint-mod]: geo/point/index.html
 [geoshape-mod]: geo/shape/index.html
+[settings-mod]: settings/index.html
+[ingest-mod]: ingest/index.html
 */
 
-pub use elastic_types::{boolean, date, document, geo, ip, number, prelude, string};
+pub use elastic_types::{boolean, date, document, geo, ingest, ip, number, prelude, settings, string};
 
 #[doc(hidden)]
 pub use elastic_types::derive;
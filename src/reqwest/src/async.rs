@@ -22,48 +22,69 @@ pub fn default(handle: &Handle) -> Result<(Client, RequestParams), Error> {
 }
 
 /** A type that can be converted into a request body. */
-pub struct AsyncBody(Body);
+pub struct AsyncBody(AsyncBodyInner);
+
+enum AsyncBodyInner {
+    Bytes(Bytes),
+    Body(Body),
+}
 
 impl AsyncBody {
     /** Convert the body into its inner value. */
     pub fn into_inner(self) -> Body {
-        self.0
+        match self.0 {
+            AsyncBodyInner::Bytes(bytes) => bytes.into(),
+            AsyncBodyInner::Body(body) => body,
+        }
+    }
+
+    /**
+    Attempt to clone the body.
+
+    Bodies that are buffered in memory, like strings, bytes and json values, can be cloned.
+    Bodies that are created from a `reqwest::unstable::async::Body` can't be cloned and will return `None`.
+    */
+    pub fn try_clone(&self) -> Option<AsyncBody> {
+        match self.0 {
+            AsyncBodyInner::Bytes(ref bytes) => Some(AsyncBody(AsyncBodyInner::Bytes(bytes.clone()))),
+            AsyncBodyInner::Body(_) => None,
+        }
     }
 }
 
 impl From<Body> for AsyncBody {
     fn from(body: Body) -> AsyncBody {
-        AsyncBody(body)
+        AsyncBody(AsyncBodyInner::Body(body))
     }
 }
 
 impl From<Vec<u8>> for AsyncBody {
     fn from(body: Vec<u8>) -> AsyncBody {
-        AsyncBody(body.into())
+        AsyncBody(AsyncBodyInner::Bytes(body.into()))
     }
 }
 
 impl From<String> for AsyncBody {
     fn from(body: String) -> AsyncBody {
-        AsyncBody(body.into())
+        AsyncBody(AsyncBodyInner::Bytes(body.into()))
     }
 }
 
 impl From<Value> for AsyncBody {
     fn from(body: Value) -> AsyncBody {
-        AsyncBody(body.to_string().into())
+        AsyncBody(AsyncBodyInner::Bytes(body.to_string().into()))
     }
 }
 
 impl From<&'static [u8]> for AsyncBody {
     fn from(body: &'static [u8]) -> AsyncBody {
-        AsyncBody(Bytes::from(body).into())
+        AsyncBody(AsyncBodyInner::Bytes(Bytes::from(body)))
     }
 }
 
 impl From<&'static str> for AsyncBody {
     fn from(body: &'static str) -> AsyncBody {
-        AsyncBody(Bytes::from(body).into())
+        AsyncBody(AsyncBodyInner::Bytes(Bytes::from(body)))
     }
 }
 
@@ -346,4 +367,11 @@ mod tests {
     fn json_value_into_body() {
         AsyncBody::from(json!({}));
     }
+
+    #[test]
+    fn buffered_body_can_be_cloned() {
+        assert!(AsyncBody::from("abc").try_clone().is_some());
+        assert!(AsyncBody::from(Vec::new()).try_clone().is_some());
+        assert!(AsyncBody::from(json!({})).try_clone().is_some());
+    }
 }
@@ -259,6 +259,7 @@ pub use self::res::parse;
 use std::sync::Arc;
 use std::collections::BTreeMap;
 use std::str;
+use std::time::Duration;
 use reqwest::Error as ReqwestError;
 use reqwest::header::{ContentType, Header, Headers};
 use url::form_urlencoded::Serializer;
@@ -342,6 +343,7 @@ pub struct RequestParams {
     /** Base url for Elasticsearch. */ base_url: String,
     /** Simple key-value store for url query params. */ url_params: BTreeMap<&'static str, String>,
     /** The complete set of headers that will be sent with the request. */ headers_factory: Option<Arc<Fn(&mut Headers) + Send + Sync + 'static>>,
+    /** The maximum amount of time to wait for a response. */ timeout: Option<Duration>,
 }
 
 impl RequestParams {
@@ -356,6 +358,7 @@ impl RequestParams {
             base_url: base.into(),
             headers_factory: None,
             url_params: BTreeMap::new(),
+            timeout: None,
         }
     }
 
@@ -376,6 +379,21 @@ impl RequestParams {
         self
     }
 
+    /**
+    Set the maximum amount of time to wait for a response.
+
+    The `elastic_req` methods in this crate don't read the timeout.
+    Requests are sent with whatever timeout the `reqwest` client was built with.
+
+    The `elastic` clients do enforce it for each attempt at sending a request.
+    A synchronous client sends the request with a `reqwest` client built with the timeout.
+    An asynchronous client races the request against a timer on its reactor, and fails with a timeout error if the timer fires first.
+    */
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /** Set a request header. */
     pub fn header<H>(self, header: H) -> Self
     where
@@ -410,11 +428,30 @@ impl RequestParams {
         self
     }
 
+    /**
+    Get a copy of these parameters with only the base url and headers.
+
+    Url query params and the timeout aren't copied.
+    */
+    pub fn base_params(&self) -> Self {
+        RequestParams {
+            base_url: self.base_url.clone(),
+            headers_factory: self.headers_factory.clone(),
+            url_params: BTreeMap::new(),
+            timeout: None,
+        }
+    }
+
     /** Get the base url. */
     pub fn get_base_url(&self) -> &str {
         &self.base_url
     }
 
+    /** Get the timeout. */
+    pub fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
     /** Create a new `Headers` structure, and thread it through the configuration functions. */
     pub fn get_headers(&self) -> Headers {
         let mut headers = Headers::new();
@@ -539,6 +576,20 @@ mod tests {
         assert_eq!("http://eshost:9200", req.base_url);
     }
 
+    #[test]
+    fn request_params_has_no_default_timeout() {
+        let req = RequestParams::default();
+
+        assert_eq!(None, req.get_timeout());
+    }
+
+    #[test]
+    fn request_params_can_set_timeout() {
+        let req = RequestParams::default().timeout(Duration::from_secs(5));
+
+        assert_eq!(Some(Duration::from_secs(5)), req.get_timeout());
+    }
+
     #[test]
     fn request_params_can_set_url_query() {
         let req = RequestParams::default()
@@ -552,6 +603,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn base_request_params_keep_base_url_and_headers() {
+        let req = RequestParams::default()
+            .base_url("http://eshost:9200")
+            .header(Authorization("let me in".to_owned()))
+            .url_param("refresh", true)
+            .timeout(Duration::from_secs(5))
+            .base_params();
+
+        assert_eq!("http://eshost:9200", req.get_base_url());
+        assert_eq!(
+            Some(&Authorization("let me in".to_owned())),
+            req.get_headers().get::<Authorization<String>>()
+        );
+        assert_eq!((0, None), req.get_url_qry());
+        assert_eq!(None, req.get_timeout());
+    }
+
     #[test]
     fn empty_request_params_returns_empty_string() {
         let req = RequestParams::default();
@@ -1,5 +1,6 @@
 /*! Synchronous http client. */
 
+use std::borrow::Cow;
 use std::io::Cursor;
 use std::fs::File;
 use serde::de::DeserializeOwned;
@@ -20,54 +21,76 @@ pub fn default() -> Result<(Client, RequestParams), Error> {
 }
 
 /** A type that can be converted into a request body. */
-pub struct SyncBody(Body);
+pub struct SyncBody(SyncBodyInner);
+
+enum SyncBodyInner {
+    Bytes(Cow<'static, [u8]>),
+    Body(Body),
+}
 
 impl SyncBody {
     /** Convert the body into its inner value. */
     pub fn into_inner(self) -> Body {
-        self.0
+        match self.0 {
+            SyncBodyInner::Bytes(Cow::Borrowed(bytes)) => Body::new(Cursor::new(bytes)),
+            SyncBodyInner::Bytes(Cow::Owned(bytes)) => bytes.into(),
+            SyncBodyInner::Body(body) => body,
+        }
+    }
+
+    /**
+    Attempt to clone the body.
+
+    Bodies that are buffered in memory, like strings, bytes and json values, can be cloned.
+    Bodies that are read from a `File` or a `reqwest::Body` can't be cloned and will return `None`.
+    */
+    pub fn try_clone(&self) -> Option<SyncBody> {
+        match self.0 {
+            SyncBodyInner::Bytes(ref bytes) => Some(SyncBody(SyncBodyInner::Bytes(bytes.clone()))),
+            SyncBodyInner::Body(_) => None,
+        }
     }
 }
 
 impl From<Body> for SyncBody {
     fn from(body: Body) -> SyncBody {
-        SyncBody(body)
+        SyncBody(SyncBodyInner::Body(body))
     }
 }
 
 impl From<File> for SyncBody {
     fn from(body: File) -> SyncBody {
-        SyncBody(body.into())
+        SyncBody(SyncBodyInner::Body(body.into()))
     }
 }
 
 impl From<Vec<u8>> for SyncBody {
     fn from(body: Vec<u8>) -> SyncBody {
-        SyncBody(body.into())
+        SyncBody(SyncBodyInner::Bytes(Cow::Owned(body)))
     }
 }
 
 impl From<String> for SyncBody {
     fn from(body: String) -> SyncBody {
-        SyncBody(body.into())
+        SyncBody(SyncBodyInner::Bytes(Cow::Owned(body.into_bytes())))
     }
 }
 
 impl From<Value> for SyncBody {
     fn from(body: Value) -> SyncBody {
-        SyncBody(body.to_string().into())
+        SyncBody(SyncBodyInner::Bytes(Cow::Owned(body.to_string().into_bytes())))
     }
 }
 
 impl From<&'static [u8]> for SyncBody {
     fn from(body: &'static [u8]) -> SyncBody {
-        SyncBody(Body::new(Cursor::new(body)))
+        SyncBody(SyncBodyInner::Bytes(Cow::Borrowed(body)))
     }
 }
 
 impl From<&'static str> for SyncBody {
     fn from(body: &'static str) -> SyncBody {
-        SyncBody(Body::new(Cursor::new(body)))
+        SyncBody(SyncBodyInner::Bytes(Cow::Borrowed(body.as_bytes())))
     }
 }
 
@@ -285,4 +308,16 @@ mod tests {
     fn json_value_into_body() {
         SyncBody::from(json!({}));
     }
+
+    #[test]
+    fn buffered_body_can_be_cloned() {
+        assert!(SyncBody::from("abc").try_clone().is_some());
+        assert!(SyncBody::from(Vec::new()).try_clone().is_some());
+        assert!(SyncBody::from(json!({})).try_clone().is_some());
+    }
+
+    #[test]
+    fn file_body_cannot_be_cloned() {
+        assert!(SyncBody::from(File::open("Cargo.toml").unwrap()).try_clone().is_none());
+    }
 }
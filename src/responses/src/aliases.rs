@@ -0,0 +1,130 @@
+/*!
+Response types for a [get alias request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html#alias-retrieving).
+*/
+
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::collections::btree_map::{BTreeMap, Iter};
+
+/**
+Response for a [get alias request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html#alias-retrieving).
+
+The response maps indices to the aliases that point to them.
+
+# Examples
+
+Find the indices an alias points to:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> GetAliasesResponse { unimplemented!() }
+# fn main() {
+let response: GetAliasesResponse = do_request();
+
+for index in response.indices_for("myalias") {
+    println!("myalias points to {}", index);
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct GetAliasesResponse(BTreeMap<String, IndexAliases>);
+
+#[derive(Deserialize, Debug)]
+struct IndexAliases {
+    #[serde(default)] aliases: BTreeMap<String, AliasInfo>,
+}
+
+/** The configuration for an alias on a single index. */
+#[derive(Deserialize, Debug)]
+pub struct AliasInfo {
+    filter: Option<Value>,
+    index_routing: Option<String>,
+    search_routing: Option<String>,
+}
+
+impl AliasInfo {
+    /** The filter applied to searches through the alias. */
+    pub fn filter(&self) -> Option<&Value> {
+        self.filter.as_ref()
+    }
+
+    /** The routing used when indexing through the alias. */
+    pub fn index_routing(&self) -> Option<&str> {
+        self.index_routing.as_ref().map(|routing| routing.as_ref())
+    }
+
+    /** The routing used when searching through the alias. */
+    pub fn search_routing(&self) -> Option<&str> {
+        self.search_routing.as_ref().map(|routing| routing.as_ref())
+    }
+}
+
+impl GetAliasesResponse {
+    /** The names of the indices the given alias points to. */
+    pub fn indices_for(&self, alias: &str) -> Vec<&str> {
+        self.iter()
+            .filter(|&(_, name, _)| name == alias)
+            .map(|(index, _, _)| index)
+            .collect()
+    }
+
+    /** The names of the aliases that point to the given index. */
+    pub fn aliases_for(&self, index: &str) -> Vec<&str> {
+        self.0
+            .get(index)
+            .map(|aliases| aliases.aliases.keys().map(|alias| alias.as_ref()).collect())
+            .unwrap_or_else(Vec::new)
+    }
+
+    /** Get the configuration for an alias on an index. */
+    pub fn get(&self, index: &str, alias: &str) -> Option<&AliasInfo> {
+        self.0.get(index).and_then(|aliases| aliases.aliases.get(alias))
+    }
+
+    /** Iterate through each index and alias pair in the form `(index, alias, info)`. */
+    pub fn iter(&self) -> Aliases {
+        Aliases {
+            indices: self.0.iter(),
+            current: None,
+        }
+    }
+}
+
+/** A borrowing iterator over index and alias pairs. */
+pub struct Aliases<'a> {
+    indices: Iter<'a, String, IndexAliases>,
+    current: Option<(&'a str, Iter<'a, String, AliasInfo>)>,
+}
+
+impl<'a> Iterator for Aliases<'a> {
+    type Item = (&'a str, &'a str, &'a AliasInfo);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((index, ref mut aliases)) = self.current {
+                if let Some((alias, info)) = aliases.next() {
+                    return Some((index, alias, info));
+                }
+            }
+
+            match self.indices.next() {
+                Some((index, aliases)) => self.current = Some((index, aliases.aliases.iter())),
+                None => return None,
+            }
+        }
+    }
+}
+
+impl IsOk for GetAliasesResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+/*!
+Response types for an [analyze request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-analyze.html).
+*/
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::slice::Iter;
+
+/**
+Response for an [analyze request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-analyze.html).
+
+The response contains the tokens produced by analysing the text.
+If the request was made with `explain` then the response also contains the tokens produced by each step in the analysis chain.
+
+# Examples
+
+Print the tokens produced by an analyzer:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> AnalyzeResponse { unimplemented!() }
+# fn main() {
+let response: AnalyzeResponse = do_request();
+
+for token in response.iter() {
+    println!("{} at position {} ({}..{})", token.token(), token.position(), token.start_offset(), token.end_offset());
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct AnalyzeResponse {
+    #[serde(default)] tokens: Vec<Token>,
+    detail: Option<AnalyzeDetail>,
+}
+
+impl AnalyzeResponse {
+    /**
+    The tokens produced by analysing the text.
+
+    For `explain` requests these are the tokens from the last step in the analysis chain.
+    */
+    pub fn tokens(&self) -> &[Token] {
+        match self.detail {
+            Some(ref detail) if self.tokens.is_empty() => detail.tokens(),
+            _ => &self.tokens,
+        }
+    }
+
+    /** Iterate through the tokens produced by analysing the text. */
+    pub fn iter(&self) -> Iter<Token> {
+        self.tokens().iter()
+    }
+
+    /** The tokens produced by each step in the analysis chain, if the request was made with `explain`. */
+    pub fn detail(&self) -> Option<&AnalyzeDetail> {
+        self.detail.as_ref()
+    }
+}
+
+impl IsOk for AnalyzeResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/** A single token produced by analysing text. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Token {
+    token: String,
+    start_offset: u32,
+    end_offset: u32,
+    #[serde(rename = "type")] ty: String,
+    position: u32,
+    #[serde(rename = "positionLength")] position_length: Option<u32>,
+}
+
+impl Token {
+    /** The text of the token. */
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /** The character offset in the original text where the token starts. */
+    pub fn start_offset(&self) -> u32 {
+        self.start_offset
+    }
+
+    /** The character offset in the original text where the token ends. */
+    pub fn end_offset(&self) -> u32 {
+        self.end_offset
+    }
+
+    /** The type of the token, like `<ALPHANUM>` or `word`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The position of the token in the token stream. */
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /**
+    The number of positions the token spans.
+
+    This is only returned for `explain` requests.
+    */
+    pub fn position_length(&self) -> Option<u32> {
+        self.position_length
+    }
+}
+
+/**
+The tokens produced by each step in the analysis chain.
+
+A named analyzer that isn't `custom` only has a single step.
+A `custom` analyzer or an explicit chain has steps for the character filters, tokenizer and token filters.
+*/
+#[derive(Deserialize, Debug)]
+pub struct AnalyzeDetail {
+    custom_analyzer: bool,
+    analyzer: Option<AnalyzeStep>,
+    #[serde(default)] charfilters: Vec<CharFilterStep>,
+    tokenizer: Option<AnalyzeStep>,
+    #[serde(default)] tokenfilters: Vec<AnalyzeStep>,
+}
+
+impl AnalyzeDetail {
+    /** Whether or not the text was analysed by a `custom` analyzer or an explicit chain. */
+    pub fn custom_analyzer(&self) -> bool {
+        self.custom_analyzer
+    }
+
+    /** The tokens produced by a named analyzer that isn't `custom`. */
+    pub fn analyzer(&self) -> Option<&AnalyzeStep> {
+        self.analyzer.as_ref()
+    }
+
+    /** The text produced by each character filter. */
+    pub fn char_filters(&self) -> &[CharFilterStep] {
+        &self.charfilters
+    }
+
+    /** The tokens produced by the tokenizer. */
+    pub fn tokenizer(&self) -> Option<&AnalyzeStep> {
+        self.tokenizer.as_ref()
+    }
+
+    /** The tokens produced by each token filter. */
+    pub fn token_filters(&self) -> &[AnalyzeStep] {
+        &self.tokenfilters
+    }
+
+    fn tokens(&self) -> &[Token] {
+        self.tokenfilters
+            .last()
+            .or(self.tokenizer.as_ref())
+            .or(self.analyzer.as_ref())
+            .map(|step| step.tokens())
+            .unwrap_or(&[])
+    }
+}
+
+/** The tokens produced by a single step in the analysis chain. */
+#[derive(Deserialize, Debug)]
+pub struct AnalyzeStep {
+    name: String,
+    #[serde(default)] tokens: Vec<Token>,
+}
+
+impl AnalyzeStep {
+    /** The name of the analyzer, tokenizer or token filter. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** The tokens produced by this step. */
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+}
+
+/** The text produced by a character filter. */
+#[derive(Deserialize, Debug)]
+pub struct CharFilterStep {
+    name: String,
+    #[serde(default)] filtered_text: Vec<String>,
+}
+
+impl CharFilterStep {
+    /** The name of the character filter. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** The filtered text for each input text. */
+    pub fn filtered_text(&self) -> &[String] {
+        &self.filtered_text
+    }
+}
@@ -453,6 +453,7 @@ pub struct ErrorItem<TIndex = DefaultAllocatedField, TType = DefaultAllocatedFie
     index: TIndex,
     ty: TType,
     id: TId,
+    status: u16,
     err: BulkError,
 }
 
@@ -476,6 +477,15 @@ impl<TIndex, TType, TId> ErrorItem<TIndex, TType, TId> {
     pub fn id(&self) -> &TId {
         &self.id
     }
+
+    /**
+    The HTTP status code for this item.
+
+    A status of `429` means the item was rejected because the cluster was too busy to process it, and it can be retried.
+    */
+    pub fn status(&self) -> u16 {
+        self.status
+    }
 }
 
 impl<TIndex, TType, TId> fmt::Display for ErrorItem<TIndex, TType, TId>
@@ -571,6 +581,7 @@ where
                 index: self.inner.index,
                 ty: self.inner.ty,
                 id: self.inner.id,
+                status: self.inner.status,
                 err: err,
             }),
             None => None,
@@ -0,0 +1,297 @@
+/*!
+Response types for requests that update documents in bulk by scrolling through a search, like a [delete by query request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html).
+*/
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::slice::Iter;
+
+/**
+Response for a request that updates documents in bulk by scrolling through a search.
+
+The same response is returned by [delete by query](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html),
+[update by query](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html)
+and [reindex](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html) requests.
+Some counters, like `deleted`, are only relevant to some requests and will be `0` otherwise.
+
+Individual documents that couldn't be processed are returned as [`Failure`](struct.Failure.html)s.
+
+# Examples
+
+Check a delete by query response for failures:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> DeleteByQueryResponse { unimplemented!() }
+# fn main() {
+let response: DeleteByQueryResponse = do_request();
+
+println!("deleted {} of {} documents", response.deleted(), response.total());
+
+for failure in response.failures() {
+    println!("failed: {:?}", failure);
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct BulkByScrollResponse {
+    took: u64,
+    timed_out: bool,
+    total: u64,
+    #[serde(default)] updated: u64,
+    #[serde(default)] created: u64,
+    #[serde(default)] deleted: u64,
+    batches: u32,
+    version_conflicts: u64,
+    noops: u64,
+    retries: Retries,
+    #[serde(default)] throttled_millis: u64,
+    #[serde(default)] failures: Vec<Failure>,
+}
+
+/** Response for a [delete by query request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html). */
+pub type DeleteByQueryResponse = BulkByScrollResponse;
+
+/** Response for an [update by query request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html). */
+pub type UpdateByQueryResponse = BulkByScrollResponse;
+
+/** Response for a [reindex request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html). */
+pub type ReindexResponse = BulkByScrollResponse;
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+struct Retries {
+    bulk: u64,
+    search: u64,
+}
+
+impl BulkByScrollResponse {
+    /** Time in milliseconds it took for Elasticsearch to process the request. */
+    pub fn took(&self) -> u64 {
+        self.took
+    }
+
+    /** Whether or not any requests timed out while processing documents. */
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /** The number of documents that were processed. */
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /** The number of documents that were updated. */
+    pub fn updated(&self) -> u64 {
+        self.updated
+    }
+
+    /** The number of documents that were created. */
+    pub fn created(&self) -> u64 {
+        self.created
+    }
+
+    /** The number of documents that were deleted. */
+    pub fn deleted(&self) -> u64 {
+        self.deleted
+    }
+
+    /** The number of scroll responses that were pulled back. */
+    pub fn batches(&self) -> u32 {
+        self.batches
+    }
+
+    /** The number of documents that had a version conflict. */
+    pub fn version_conflicts(&self) -> u64 {
+        self.version_conflicts
+    }
+
+    /** The number of documents that were ignored because a script set `ctx.op` to `noop`. */
+    pub fn noops(&self) -> u64 {
+        self.noops
+    }
+
+    /** The number of bulk actions that were retried. */
+    pub fn bulk_retries(&self) -> u64 {
+        self.retries.bulk
+    }
+
+    /** The number of search actions that were retried. */
+    pub fn search_retries(&self) -> u64 {
+        self.retries.search
+    }
+
+    /** Time in milliseconds the request slept to conform to `requests_per_second`. */
+    pub fn throttled_millis(&self) -> u64 {
+        self.throttled_millis
+    }
+
+    /** Returns `true` if there were no failures. */
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /** Iterate through the documents that couldn't be processed. */
+    pub fn failures(&self) -> Failures {
+        Failures(self.failures.iter())
+    }
+}
+
+/**
+The progress of a request that updates documents in bulk by scrolling through a search.
+
+This is the `status` of a running [task](../tasks/struct.GetTaskResponse.html) for a delete by query, update by query or reindex request.
+*/
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct BulkByScrollStatus {
+    total: u64,
+    #[serde(default)] updated: u64,
+    #[serde(default)] created: u64,
+    #[serde(default)] deleted: u64,
+    batches: u32,
+    version_conflicts: u64,
+    noops: u64,
+    retries: Retries,
+    #[serde(default)] throttled_millis: u64,
+}
+
+impl BulkByScrollStatus {
+    /** The total number of documents that will be processed. */
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /** The number of documents that have been updated so far. */
+    pub fn updated(&self) -> u64 {
+        self.updated
+    }
+
+    /** The number of documents that have been created so far. */
+    pub fn created(&self) -> u64 {
+        self.created
+    }
+
+    /** The number of documents that have been deleted so far. */
+    pub fn deleted(&self) -> u64 {
+        self.deleted
+    }
+
+    /** The number of scroll responses that have been pulled back so far. */
+    pub fn batches(&self) -> u32 {
+        self.batches
+    }
+
+    /** The number of documents that have had a version conflict so far. */
+    pub fn version_conflicts(&self) -> u64 {
+        self.version_conflicts
+    }
+
+    /** The number of documents that have been ignored so far because a script set `ctx.op` to `noop`. */
+    pub fn noops(&self) -> u64 {
+        self.noops
+    }
+
+    /** The number of bulk actions that have been retried so far. */
+    pub fn bulk_retries(&self) -> u64 {
+        self.retries.bulk
+    }
+
+    /** The number of search actions that have been retried so far. */
+    pub fn search_retries(&self) -> u64 {
+        self.retries.search
+    }
+
+    /** Time in milliseconds the request has slept to conform to `requests_per_second`. */
+    pub fn throttled_millis(&self) -> u64 {
+        self.throttled_millis
+    }
+
+    /**
+    The number of documents that have been processed so far.
+
+    This is the sum of documents that were updated, created, deleted, ignored as a noop or had a version conflict.
+    */
+    pub fn processed(&self) -> u64 {
+        self.updated + self.created + self.deleted + self.noops + self.version_conflicts
+    }
+}
+
+/** A borrowing iterator for documents that couldn't be processed. */
+pub struct Failures<'a>(Iter<'a, Failure>);
+
+impl<'a> Iterator for Failures<'a> {
+    type Item = &'a Failure;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/**
+A document that couldn't be processed.
+
+Failures either come from indexing a document, in which case the index, type and id are known,
+or from the search used to find documents, in which case they aren't.
+*/
+#[derive(Deserialize, Debug)]
+pub struct Failure {
+    index: Option<String>,
+    #[serde(rename = "type")] ty: Option<String>,
+    id: Option<String>,
+    status: Option<u16>,
+    #[serde(default, deserialize_with = "deserialize_cause")] cause: Option<ApiError>,
+    #[serde(default, deserialize_with = "deserialize_cause")] reason: Option<ApiError>,
+}
+
+impl Failure {
+    /** The index of the document. */
+    pub fn index(&self) -> Option<&str> {
+        self.index.as_ref().map(|index| index.as_ref())
+    }
+
+    /** The type of the document. */
+    pub fn ty(&self) -> Option<&str> {
+        self.ty.as_ref().map(|ty| ty.as_ref())
+    }
+
+    /** The id of the document. */
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_ref().map(|id| id.as_ref())
+    }
+
+    /**
+    The HTTP status code for the document.
+
+    A status of `409` means the document had a version conflict.
+    */
+    pub fn status(&self) -> Option<u16> {
+        self.status
+    }
+
+    /** The error that caused the failure. */
+    pub fn cause(&self) -> Option<&ApiError> {
+        self.cause.as_ref().or(self.reason.as_ref())
+    }
+}
+
+impl IsOk for BulkByScrollResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            // Version conflicts abort the request with a `409`, but still return a complete response
+            409 => {
+                let (maybe_err, body) = body.body()?;
+
+                let is_ok = maybe_err
+                    .as_object()
+                    .and_then(|maybe_err| maybe_err.get("error"))
+                    .is_none();
+
+                Ok(MaybeOkResponse::new(is_ok, body))
+            }
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,748 @@
+/*!
+Response types for [cat requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat.html).
+
+Cat responses can be parsed from either the `format=json` output or the default whitespace-aligned text output.
+Text output must include column headers, so requests should set the `v` parameter.
+Only the default columns for each cat API are modelled, other columns are ignored.
+
+# Examples
+
+Find indices that aren't healthy:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> (u16, Vec<u8>) { unimplemented!() }
+# fn main() {
+// The body could be from either `_cat/indices?v` or `_cat/indices?format=json`
+let (response_status, response_body) = do_request();
+
+let response = parse::<CatIndicesResponse>().from_slice(response_status, response_body).unwrap();
+
+for row in response.iter().filter(|row| row.health() != Some(ClusterHealthStatus::Green)) {
+    println!("{} is {:?}", row.index(), row.health());
+}
+# }
+```
+*/
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::slice::Iter;
+use std::vec::IntoIter;
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde_json::{Map, Value};
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use cluster_health::ClusterHealthStatus;
+use error::*;
+
+const NONE_VALUE: &'static str = "-";
+
+/** Response for a [cat indices request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-indices.html). */
+pub type CatIndicesResponse = CatResponse<CatIndicesRow>;
+
+/** Response for a [cat shards request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-shards.html). */
+pub type CatShardsResponse = CatResponse<CatShardsRow>;
+
+/** Response for a [cat nodes request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-nodes.html). */
+pub type CatNodesResponse = CatResponse<CatNodesRow>;
+
+/** Response for a [cat allocation request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-allocation.html). */
+pub type CatAllocationResponse = CatResponse<CatAllocationRow>;
+
+/** Response for a [cat health request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-health.html). */
+pub type CatHealthResponse = CatResponse<CatHealthRow>;
+
+/** Response for a [cat aliases request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-alias.html). */
+pub type CatAliasesResponse = CatResponse<CatAliasesRow>;
+
+/** Response for a [cat segments request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-segments.html). */
+pub type CatSegmentsResponse = CatResponse<CatSegmentsRow>;
+
+/** Response for a [cat thread pool request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-thread-pool.html). */
+pub type CatThreadPoolResponse = CatResponse<CatThreadPoolRow>;
+
+/**
+The rows returned by a cat request.
+
+Each row is deserialised from an object that maps column headers to values.
+Values in text output are always strings, and empty cells are left out of the row.
+*/
+#[derive(Deserialize, Debug)]
+pub struct CatResponse<TRow>(Vec<TRow>);
+
+impl<TRow> CatResponse<TRow> {
+    /** Iterate through the rows. */
+    pub fn iter(&self) -> Iter<TRow> {
+        self.0.iter()
+    }
+
+    /** The number of rows. */
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /** Whether or not there are any rows. */
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<TRow> IntoIterator for CatResponse<TRow> {
+    type Item = TRow;
+    type IntoIter = IntoIter<TRow>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<TRow> IsOk for CatResponse<TRow> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => {
+                let (text, body) = body.text()?;
+
+                match text.trim_left().chars().next() {
+                    Some('[') => Ok(MaybeOkResponse::ok(body)),
+                    _ => Ok(MaybeOkResponse::ok(parse_table(&text))),
+                }
+            }
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+Parse whitespace-aligned text with a header line into an array of objects.
+
+Columns are either left or right aligned, and a column header is aligned the same way as its values.
+That means a value always overlaps the header for its column.
+Values that don't overlap any header, like the second word in a value containing spaces, belong to the closest column on their left.
+*/
+fn parse_table(text: &str) -> Value {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+
+    let headers = match lines.next() {
+        Some(line) => words(line),
+        None => return Value::Array(Vec::new()),
+    };
+
+    let rows = lines
+        .map(|line| {
+            let mut row = Map::new();
+
+            for (start, end, value) in words(line) {
+                let column = headers
+                    .iter()
+                    .position(|&(header_start, header_end, _)| start < header_end && header_start < end)
+                    .or_else(|| headers.iter().rposition(|&(header_start, _, _)| header_start <= start))
+                    .unwrap_or(0);
+
+                let header = headers[column].2.clone();
+
+                let value = match row.remove(&header) {
+                    Some(Value::String(previous)) => format!("{} {}", previous, value),
+                    _ => value,
+                };
+
+                row.insert(header, Value::String(value));
+            }
+
+            Value::Object(row)
+        })
+        .collect();
+
+    Value::Array(rows)
+}
+
+/** Split a line into words along with their start and end character positions. */
+fn words(line: &str) -> Vec<(usize, usize, String)> {
+    let mut words = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+
+    for (position, c) in line.chars().enumerate() {
+        if c.is_whitespace() {
+            if let Some((start, word)) = current.take() {
+                words.push((start, position, word));
+            }
+        } else {
+            match current {
+                Some((_, ref mut word)) => word.push(c),
+                None => current = Some((position, c.to_string())),
+            }
+        }
+    }
+
+    if let Some((start, word)) = current {
+        let end = start + word.chars().count();
+        words.push((start, end, word));
+    }
+
+    words
+}
+
+/** A row from a cat indices request. */
+#[derive(Deserialize, Debug)]
+pub struct CatIndicesRow {
+    #[serde(default, deserialize_with = "deserialize_opt_health")] health: Option<ClusterHealthStatus>,
+    status: String,
+    index: String,
+    #[serde(default)] uuid: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_from_str")] pri: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_opt_from_str")] rep: Option<u32>,
+    #[serde(rename = "docs.count", default, deserialize_with = "deserialize_opt_from_str")] docs_count: Option<u64>,
+    #[serde(rename = "docs.deleted", default, deserialize_with = "deserialize_opt_from_str")] docs_deleted: Option<u64>,
+    #[serde(rename = "store.size", default)] store_size: Option<String>,
+    #[serde(rename = "pri.store.size", default)] pri_store_size: Option<String>,
+}
+
+impl CatIndicesRow {
+    /** The health of the index, which is only available for open indices. */
+    pub fn health(&self) -> Option<ClusterHealthStatus> {
+        self.health
+    }
+
+    /** The status of the index, like `open` or `close`. */
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    /** The name of the index. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The uuid of the index. */
+    pub fn uuid(&self) -> Option<&str> {
+        self.uuid.as_ref().map(|uuid| uuid.as_ref())
+    }
+
+    /** The number of primary shards. */
+    pub fn primaries(&self) -> Option<u32> {
+        self.pri
+    }
+
+    /** The number of replicas for each primary shard. */
+    pub fn replicas(&self) -> Option<u32> {
+        self.rep
+    }
+
+    /** The number of documents in the index. */
+    pub fn docs_count(&self) -> Option<u64> {
+        self.docs_count
+    }
+
+    /** The number of deleted documents in the index. */
+    pub fn docs_deleted(&self) -> Option<u64> {
+        self.docs_deleted
+    }
+
+    /** The size of all shards for the index, like `5.2kb`. */
+    pub fn store_size(&self) -> Option<&str> {
+        self.store_size.as_ref().map(|size| size.as_ref())
+    }
+
+    /** The size of the primary shards for the index, like `5.2kb`. */
+    pub fn primary_store_size(&self) -> Option<&str> {
+        self.pri_store_size.as_ref().map(|size| size.as_ref())
+    }
+}
+
+/** A row from a cat shards request. */
+#[derive(Deserialize, Debug)]
+pub struct CatShardsRow {
+    index: String,
+    #[serde(deserialize_with = "deserialize_from_str")] shard: u32,
+    prirep: String,
+    state: String,
+    #[serde(default, deserialize_with = "deserialize_opt_from_str")] docs: Option<u64>,
+    #[serde(default)] store: Option<String>,
+    #[serde(default)] ip: Option<String>,
+    #[serde(default)] node: Option<String>,
+}
+
+impl CatShardsRow {
+    /** The name of the index the shard belongs to. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The number of the shard. */
+    pub fn shard(&self) -> u32 {
+        self.shard
+    }
+
+    /** Whether the shard is a primary or a replica. */
+    pub fn is_primary(&self) -> bool {
+        self.prirep == "p"
+    }
+
+    /** The state of the shard, like `STARTED` or `UNASSIGNED`. */
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /** The number of documents in the shard. */
+    pub fn docs(&self) -> Option<u64> {
+        self.docs
+    }
+
+    /** The size of the shard, like `5.2kb`. */
+    pub fn store(&self) -> Option<&str> {
+        self.store.as_ref().map(|store| store.as_ref())
+    }
+
+    /** The ip address of the node the shard is allocated to. */
+    pub fn ip(&self) -> Option<&str> {
+        self.ip.as_ref().map(|ip| ip.as_ref())
+    }
+
+    /** The name of the node the shard is allocated to. */
+    pub fn node(&self) -> Option<&str> {
+        self.node.as_ref().map(|node| node.as_ref())
+    }
+}
+
+/** A row from a cat nodes request. */
+#[derive(Deserialize, Debug)]
+pub struct CatNodesRow {
+    ip: String,
+    #[serde(rename = "heap.percent", default, deserialize_with = "deserialize_opt_from_str")] heap_percent: Option<u32>,
+    #[serde(rename = "ram.percent", default, deserialize_with = "deserialize_opt_from_str")] ram_percent: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_opt_from_str")] cpu: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_opt_from_str")] load_1m: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_opt_from_str")] load_5m: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_opt_from_str")] load_15m: Option<f64>,
+    #[serde(rename = "node.role")] node_role: String,
+    master: String,
+    name: String,
+}
+
+impl CatNodesRow {
+    /** The ip address of the node. */
+    pub fn ip(&self) -> &str {
+        &self.ip
+    }
+
+    /** The percentage of heap memory used. */
+    pub fn heap_percent(&self) -> Option<u32> {
+        self.heap_percent
+    }
+
+    /** The percentage of total memory used. */
+    pub fn ram_percent(&self) -> Option<u32> {
+        self.ram_percent
+    }
+
+    /** The percentage of cpu used. */
+    pub fn cpu(&self) -> Option<u32> {
+        self.cpu
+    }
+
+    /** The average load over the last minute. */
+    pub fn load_1m(&self) -> Option<f64> {
+        self.load_1m
+    }
+
+    /** The average load over the last 5 minutes. */
+    pub fn load_5m(&self) -> Option<f64> {
+        self.load_5m
+    }
+
+    /** The average load over the last 15 minutes. */
+    pub fn load_15m(&self) -> Option<f64> {
+        self.load_15m
+    }
+
+    /**
+    The roles of the node as single characters.
+
+    Master eligible nodes have an `m`, data nodes have a `d` and ingest nodes have an `i`.
+    */
+    pub fn node_role(&self) -> &str {
+        &self.node_role
+    }
+
+    /** Whether or not the node is the elected master. */
+    pub fn is_master(&self) -> bool {
+        self.master == "*"
+    }
+
+    /** The name of the node. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/** A row from a cat allocation request. */
+#[derive(Deserialize, Debug)]
+pub struct CatAllocationRow {
+    #[serde(default, deserialize_with = "deserialize_opt_from_str")] shards: Option<u32>,
+    #[serde(rename = "disk.indices", default)] disk_indices: Option<String>,
+    #[serde(rename = "disk.used", default)] disk_used: Option<String>,
+    #[serde(rename = "disk.avail", default)] disk_avail: Option<String>,
+    #[serde(rename = "disk.total", default)] disk_total: Option<String>,
+    #[serde(rename = "disk.percent", default, deserialize_with = "deserialize_opt_from_str")] disk_percent: Option<u32>,
+    #[serde(default)] host: Option<String>,
+    #[serde(default)] ip: Option<String>,
+    node: String,
+}
+
+impl CatAllocationRow {
+    /** The number of shards allocated to the node. */
+    pub fn shards(&self) -> Option<u32> {
+        self.shards
+    }
+
+    /** The disk space used by indices on the node, like `5.2kb`. */
+    pub fn disk_indices(&self) -> Option<&str> {
+        self.disk_indices.as_ref().map(|size| size.as_ref())
+    }
+
+    /** The total disk space used on the node, like `5.2kb`. */
+    pub fn disk_used(&self) -> Option<&str> {
+        self.disk_used.as_ref().map(|size| size.as_ref())
+    }
+
+    /** The disk space available on the node, like `5.2kb`. */
+    pub fn disk_avail(&self) -> Option<&str> {
+        self.disk_avail.as_ref().map(|size| size.as_ref())
+    }
+
+    /** The total disk space on the node, like `5.2kb`. */
+    pub fn disk_total(&self) -> Option<&str> {
+        self.disk_total.as_ref().map(|size| size.as_ref())
+    }
+
+    /** The percentage of disk space used on the node. */
+    pub fn disk_percent(&self) -> Option<u32> {
+        self.disk_percent
+    }
+
+    /** The host of the node. */
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_ref().map(|host| host.as_ref())
+    }
+
+    /** The ip address of the node. */
+    pub fn ip(&self) -> Option<&str> {
+        self.ip.as_ref().map(|ip| ip.as_ref())
+    }
+
+    /**
+    The name of the node.
+
+    Shards that aren't allocated to any node are counted in a row for the node `UNASSIGNED`.
+    */
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+}
+
+/** A row from a cat health request. */
+#[derive(Deserialize, Debug)]
+pub struct CatHealthRow {
+    #[serde(deserialize_with = "deserialize_from_str")] epoch: u64,
+    timestamp: String,
+    cluster: String,
+    status: ClusterHealthStatus,
+    #[serde(rename = "node.total", deserialize_with = "deserialize_from_str")] node_total: u32,
+    #[serde(rename = "node.data", deserialize_with = "deserialize_from_str")] node_data: u32,
+    #[serde(deserialize_with = "deserialize_from_str")] shards: u32,
+    #[serde(deserialize_with = "deserialize_from_str")] pri: u32,
+    #[serde(deserialize_with = "deserialize_from_str")] relo: u32,
+    #[serde(deserialize_with = "deserialize_from_str")] init: u32,
+    #[serde(deserialize_with = "deserialize_from_str")] unassign: u32,
+    #[serde(default, deserialize_with = "deserialize_opt_from_str")] pending_tasks: Option<u32>,
+    #[serde(default)] max_task_wait_time: Option<String>,
+    #[serde(default)] active_shards_percent: Option<String>,
+}
+
+impl CatHealthRow {
+    /** The time the health was checked, in seconds since the epoch. */
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /** The time of day the health was checked, like `13:48:21`. */
+    pub fn timestamp(&self) -> &str {
+        &self.timestamp
+    }
+
+    /** The name of the cluster. */
+    pub fn cluster(&self) -> &str {
+        &self.cluster
+    }
+
+    /** The health status of the cluster. */
+    pub fn status(&self) -> ClusterHealthStatus {
+        self.status
+    }
+
+    /** The number of nodes in the cluster. */
+    pub fn node_total(&self) -> u32 {
+        self.node_total
+    }
+
+    /** The number of data nodes in the cluster. */
+    pub fn node_data(&self) -> u32 {
+        self.node_data
+    }
+
+    /** The number of active shards. */
+    pub fn shards(&self) -> u32 {
+        self.shards
+    }
+
+    /** The number of active primary shards. */
+    pub fn primaries(&self) -> u32 {
+        self.pri
+    }
+
+    /** The number of relocating shards. */
+    pub fn relocating(&self) -> u32 {
+        self.relo
+    }
+
+    /** The number of initialising shards. */
+    pub fn initializing(&self) -> u32 {
+        self.init
+    }
+
+    /** The number of unassigned shards. */
+    pub fn unassigned(&self) -> u32 {
+        self.unassign
+    }
+
+    /** The number of cluster-level changes that haven't been executed yet. */
+    pub fn pending_tasks(&self) -> Option<u32> {
+        self.pending_tasks
+    }
+
+    /** How long the oldest pending task has been waiting, if there are any pending tasks. */
+    pub fn max_task_wait_time(&self) -> Option<&str> {
+        none_if_dash(&self.max_task_wait_time)
+    }
+
+    /** The percentage of shards that are active, like `100.0%`. */
+    pub fn active_shards_percent(&self) -> Option<&str> {
+        self.active_shards_percent.as_ref().map(|percent| percent.as_ref())
+    }
+}
+
+/** A row from a cat aliases request. */
+#[derive(Deserialize, Debug)]
+pub struct CatAliasesRow {
+    alias: String,
+    index: String,
+    #[serde(default)] filter: Option<String>,
+    #[serde(rename = "routing.index", default)] routing_index: Option<String>,
+    #[serde(rename = "routing.search", default)] routing_search: Option<String>,
+}
+
+impl CatAliasesRow {
+    /** The name of the alias. */
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    /** The name of the index the alias points to. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** Whether or not the alias has a filter, which is shown as `*`. */
+    pub fn filter(&self) -> Option<&str> {
+        none_if_dash(&self.filter)
+    }
+
+    /** The routing used when indexing through the alias. */
+    pub fn routing_index(&self) -> Option<&str> {
+        none_if_dash(&self.routing_index)
+    }
+
+    /** The routing used when searching through the alias. */
+    pub fn routing_search(&self) -> Option<&str> {
+        none_if_dash(&self.routing_search)
+    }
+}
+
+/** A row from a cat segments request. */
+#[derive(Deserialize, Debug)]
+pub struct CatSegmentsRow {
+    index: String,
+    #[serde(deserialize_with = "deserialize_from_str")] shard: u32,
+    prirep: String,
+    ip: String,
+    segment: String,
+    #[serde(deserialize_with = "deserialize_from_str")] generation: u64,
+    #[serde(rename = "docs.count", deserialize_with = "deserialize_from_str")] docs_count: u64,
+    #[serde(rename = "docs.deleted", deserialize_with = "deserialize_from_str")] docs_deleted: u64,
+    size: String,
+    #[serde(rename = "size.memory", deserialize_with = "deserialize_from_str")] size_memory: u64,
+    #[serde(deserialize_with = "deserialize_from_str")] committed: bool,
+    #[serde(deserialize_with = "deserialize_from_str")] searchable: bool,
+    version: String,
+    #[serde(default, deserialize_with = "deserialize_opt_from_str")] compound: Option<bool>,
+}
+
+impl CatSegmentsRow {
+    /** The name of the index the segment belongs to. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The number of the shard the segment belongs to. */
+    pub fn shard(&self) -> u32 {
+        self.shard
+    }
+
+    /** Whether the shard is a primary or a replica. */
+    pub fn is_primary(&self) -> bool {
+        self.prirep == "p"
+    }
+
+    /** The ip address of the node the segment is on. */
+    pub fn ip(&self) -> &str {
+        &self.ip
+    }
+
+    /** The name of the segment, like `_0`. */
+    pub fn segment(&self) -> &str {
+        &self.segment
+    }
+
+    /** The generation number of the segment. */
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /** The number of documents in the segment. */
+    pub fn docs_count(&self) -> u64 {
+        self.docs_count
+    }
+
+    /** The number of deleted documents in the segment. */
+    pub fn docs_deleted(&self) -> u64 {
+        self.docs_deleted
+    }
+
+    /** The size of the segment on disk, like `5.2kb`. */
+    pub fn size(&self) -> &str {
+        &self.size
+    }
+
+    /** The number of bytes of heap memory used by the segment. */
+    pub fn size_memory(&self) -> u64 {
+        self.size_memory
+    }
+
+    /** Whether or not the segment has been committed to disk. */
+    pub fn committed(&self) -> bool {
+        self.committed
+    }
+
+    /** Whether or not the segment is searchable. */
+    pub fn searchable(&self) -> bool {
+        self.searchable
+    }
+
+    /** The version of Lucene that wrote the segment. */
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /** Whether or not the segment is stored in a compound file. */
+    pub fn compound(&self) -> Option<bool> {
+        self.compound
+    }
+}
+
+/** A row from a cat thread pool request. */
+#[derive(Deserialize, Debug)]
+pub struct CatThreadPoolRow {
+    node_name: String,
+    name: String,
+    #[serde(deserialize_with = "deserialize_from_str")] active: u32,
+    #[serde(deserialize_with = "deserialize_from_str")] queue: u32,
+    #[serde(deserialize_with = "deserialize_from_str")] rejected: u64,
+}
+
+impl CatThreadPoolRow {
+    /** The name of the node the thread pool is on. */
+    pub fn node_name(&self) -> &str {
+        &self.node_name
+    }
+
+    /** The name of the thread pool, like `bulk` or `search`. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** The number of active threads. */
+    pub fn active(&self) -> u32 {
+        self.active
+    }
+
+    /** The number of tasks waiting in the queue. */
+    pub fn queue(&self) -> u32 {
+        self.queue
+    }
+
+    /** The number of tasks that have been rejected. */
+    pub fn rejected(&self) -> u64 {
+        self.rejected
+    }
+}
+
+fn none_if_dash(value: &Option<String>) -> Option<&str> {
+    match *value {
+        Some(ref value) if value != NONE_VALUE => Some(value),
+        _ => None,
+    }
+}
+
+fn deserialize_opt_health<'de, D>(deserializer: D) -> Result<Option<ClusterHealthStatus>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::String(ref value)) if value.is_empty() => Ok(None),
+        Some(Value::Null) | None => Ok(None),
+        Some(value) => ClusterHealthStatus::deserialize(value).map(Some).map_err(D::Error::custom),
+    }
+}
+
+fn deserialize_from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    deserialize_opt_from_str(deserializer)?.ok_or_else(|| D::Error::custom("expected a value but got nothing"))
+}
+
+fn deserialize_opt_from_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let value = match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::String(value)) => value,
+        Some(Value::Null) | None => return Ok(None),
+        Some(value) => value.to_string(),
+    };
+
+    // Cells for values that aren't available are either empty or `-`
+    if value.is_empty() || value == NONE_VALUE {
+        return Ok(None);
+    }
+
+    value
+        .parse()
+        .map(Some)
+        .map_err(|e| D::Error::custom(format!("couldn't parse `{}`: {}", value, e)))
+}
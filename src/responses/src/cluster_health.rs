@@ -0,0 +1,144 @@
+/*!
+Response types for a [cluster health request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html).
+*/
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::fmt;
+
+/**
+Response for a [cluster health request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html).
+
+If the request waits for a condition that isn't met before the timeout then Elasticsearch returns a `408` status.
+That status is parsed as a successful response where `timed_out` is `true`.
+
+# Examples
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> ClusterHealthResponse { unimplemented!() }
+# fn main() {
+let response: ClusterHealthResponse = do_request();
+
+if response.status() >= ClusterHealthStatus::Yellow {
+    println!("{} is usable with {} nodes", response.cluster_name(), response.number_of_nodes());
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct ClusterHealthResponse {
+    cluster_name: String,
+    status: ClusterHealthStatus,
+    timed_out: bool,
+    number_of_nodes: u32,
+    number_of_data_nodes: u32,
+    active_primary_shards: u32,
+    active_shards: u32,
+    relocating_shards: u32,
+    initializing_shards: u32,
+    unassigned_shards: u32,
+    #[serde(default)] delayed_unassigned_shards: u32,
+    #[serde(default)] number_of_pending_tasks: u32,
+}
+
+impl ClusterHealthResponse {
+    /** The name of the cluster. */
+    pub fn cluster_name(&self) -> &str {
+        &self.cluster_name
+    }
+
+    /** The health status of the cluster, or the requested indices. */
+    pub fn status(&self) -> ClusterHealthStatus {
+        self.status
+    }
+
+    /** Whether or not the request timed out before the condition it was waiting for was met. */
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /** The number of nodes in the cluster. */
+    pub fn number_of_nodes(&self) -> u32 {
+        self.number_of_nodes
+    }
+
+    /** The number of data nodes in the cluster. */
+    pub fn number_of_data_nodes(&self) -> u32 {
+        self.number_of_data_nodes
+    }
+
+    /** The number of active primary shards. */
+    pub fn active_primary_shards(&self) -> u32 {
+        self.active_primary_shards
+    }
+
+    /** The number of active primary and replica shards. */
+    pub fn active_shards(&self) -> u32 {
+        self.active_shards
+    }
+
+    /** The number of shards that are moving between nodes. */
+    pub fn relocating_shards(&self) -> u32 {
+        self.relocating_shards
+    }
+
+    /** The number of shards that are being initialised. */
+    pub fn initializing_shards(&self) -> u32 {
+        self.initializing_shards
+    }
+
+    /** The number of shards that aren't allocated to a node. */
+    pub fn unassigned_shards(&self) -> u32 {
+        self.unassigned_shards
+    }
+
+    /** The number of unassigned shards whose allocation has been delayed. */
+    pub fn delayed_unassigned_shards(&self) -> u32 {
+        self.delayed_unassigned_shards
+    }
+
+    /** The number of cluster-level changes that haven't been executed yet. */
+    pub fn number_of_pending_tasks(&self) -> u32 {
+        self.number_of_pending_tasks
+    }
+}
+
+impl IsOk for ClusterHealthResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 | 408 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+The health status of a cluster.
+
+Statuses are ordered from least to most healthy, so `Red < Yellow < Green`.
+*/
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum ClusterHealthStatus {
+    /** At least one primary shard isn't allocated. */
+    Red,
+    /** All primary shards are allocated, but at least one replica isn't. */
+    Yellow,
+    /** All shards are allocated. */
+    Green,
+}
+
+impl fmt::Display for ClusterHealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = match *self {
+            ClusterHealthStatus::Red => "red",
+            ClusterHealthStatus::Yellow => "yellow",
+            ClusterHealthStatus::Green => "green",
+        };
+
+        f.write_str(status)
+    }
+}
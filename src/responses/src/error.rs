@@ -136,6 +136,48 @@ impl<'de> Deserialize<'de> for ApiError {
     }
 }
 
+/** Deserialise an error cause that isn't wrapped in an `error` key. */
+pub(crate) fn deserialize_required_cause<'de, D>(deserializer: D) -> Result<ApiError, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let cause = Value::deserialize(deserializer)?;
+
+    Ok(from_cause(cause))
+}
+
+/**
+Deserialise an optional error cause that isn't wrapped in an `error` key.
+
+Causes that aren't objects are ignored.
+*/
+pub(crate) fn deserialize_cause<'de, D>(deserializer: D) -> Result<Option<ApiError>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        Some(cause @ Value::Object(_)) => Ok(Some(from_cause(cause))),
+        _ => Ok(None),
+    }
+}
+
+/** Deserialise a list of error causes that aren't wrapped in an `error` key. */
+pub(crate) fn deserialize_causes<'de, D>(deserializer: D) -> Result<Vec<ApiError>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let causes = Vec::<Value>::deserialize(deserializer)?;
+
+    Ok(causes.into_iter().map(from_cause).collect())
+}
+
+fn from_cause(cause: Value) -> ApiError {
+    let mut obj = Map::new();
+    obj.insert("error".to_owned(), cause);
+
+    obj.into()
+}
+
 impl From<Map<String, Value>> for ApiError {
     fn from(mut value: Map<String, Value>) -> Self {
         let obj = {
@@ -0,0 +1,154 @@
+/*!
+Response types for an [explain request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html).
+*/
+
+use serde::de::DeserializeOwned;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::slice::Iter;
+
+/**
+Response for an [explain request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html).
+
+The response contains whether or not a document matched a query, and an explanation of how its score was computed.
+
+# Examples
+
+Print the explanation for a document's score:
+
+```no_run
+# extern crate serde_json;
+# extern crate elastic_responses;
+# use serde_json::Value;
+# use elastic_responses::*;
+# use elastic_responses::explain::Explanation;
+# fn do_request() -> ExplainResponse<Value> { unimplemented!() }
+# fn main() {
+let response: ExplainResponse<Value> = do_request();
+
+fn print(explanation: &Explanation, depth: usize) {
+    println!("{}{}: {}", "  ".repeat(depth), explanation.value(), explanation.description());
+
+    for detail in explanation.iter() {
+        print(detail, depth + 1);
+    }
+}
+
+if let Some(explanation) = response.explanation() {
+    print(explanation, 0);
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct ExplainResponse<T> {
+    #[serde(rename = "_index")] index: String,
+    #[serde(rename = "_type")] ty: String,
+    #[serde(rename = "_id")] id: String,
+    matched: bool,
+    explanation: Option<Explanation>,
+    get: Option<ExplainSource<T>>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ExplainSource<T> {
+    #[serde(rename = "_source")] source: Option<T>,
+}
+
+impl<T> ExplainResponse<T> {
+    /** Whether or not the document matched the query. */
+    pub fn matched(&self) -> bool {
+        self.matched
+    }
+
+    /**
+    The explanation for the document's score.
+
+    There's no explanation if the document doesn't exist.
+    */
+    pub fn explanation(&self) -> Option<&Explanation> {
+        self.explanation.as_ref()
+    }
+
+    /** Get a reference to the source document, if it was requested. */
+    pub fn document(&self) -> Option<&T> {
+        self.get.as_ref().and_then(|get| get.source.as_ref())
+    }
+
+    /** Convert the response into the source document, if it was requested. */
+    pub fn into_document(self) -> Option<T> {
+        self.get.and_then(|get| get.source)
+    }
+
+    /** The index for the document. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The type of the document. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The id of the document. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl<T: DeserializeOwned> IsOk for ExplainResponse<T> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            404 => {
+                // If we get a 404, it could be an IndexNotFound error or a missing document
+                // Check if the response contains a root 'error' node
+                let (maybe_err, body) = body.body()?;
+
+                let is_ok = maybe_err
+                    .as_object()
+                    .and_then(|maybe_err| maybe_err.get("error"))
+                    .is_none();
+
+                Ok(MaybeOkResponse::new(is_ok, body))
+            }
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+A step in the computation of a document's score.
+
+The `value` of an explanation is computed from the values of its `details`.
+*/
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Explanation {
+    value: f32,
+    description: String,
+    #[serde(default)] details: Vec<Explanation>,
+}
+
+impl Explanation {
+    /** The value computed by this step. */
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /** A description of how the value was computed, like `sum of:`. */
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /** The steps the value was computed from. */
+    pub fn details(&self) -> &[Explanation] {
+        &self.details
+    }
+
+    /** Iterate through the steps the value was computed from. */
+    pub fn iter(&self) -> Iter<Explanation> {
+        self.details.iter()
+    }
+}
@@ -0,0 +1,89 @@
+/*!
+Response types for a [get settings request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html).
+*/
+
+use serde::de::DeserializeOwned;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::collections::btree_map::{BTreeMap, Iter};
+
+/**
+Response for a [get settings request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html).
+
+The response maps indices to their settings.
+The settings are deserialised from the `index` object for each index, so `TSettings` doesn't need to account for that extra level of nesting.
+
+# Examples
+
+Get the number of replicas for an index:
+
+```no_run
+# extern crate serde_json;
+# extern crate elastic_responses;
+# use serde_json::Value;
+# use elastic_responses::*;
+# fn do_request() -> GetIndexSettingsResponse<Value> { unimplemented!() }
+# fn main() {
+let response: GetIndexSettingsResponse<Value> = do_request();
+
+if let Some(settings) = response.get("myindex") {
+    println!("myindex has {} replicas", settings["number_of_replicas"]);
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+#[serde(bound(deserialize = "TSettings: DeserializeOwned"))]
+pub struct GetIndexSettingsResponse<TSettings>(BTreeMap<String, IndexSettingsEntry<TSettings>>);
+
+#[derive(Deserialize, Debug)]
+#[serde(bound(deserialize = "TSettings: DeserializeOwned"))]
+struct IndexSettingsEntry<TSettings> {
+    settings: IndexSettingsInner<TSettings>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(bound(deserialize = "TSettings: DeserializeOwned"))]
+struct IndexSettingsInner<TSettings> {
+    index: TSettings,
+}
+
+impl<TSettings> GetIndexSettingsResponse<TSettings> {
+    /** Get the settings for an index. */
+    pub fn get(&self, index: &str) -> Option<&TSettings> {
+        self.0.get(index).map(|entry| &entry.settings.index)
+    }
+
+    /** Iterate through each index in the form `(index, settings)`. */
+    pub fn iter(&self) -> Settings<TSettings> {
+        Settings {
+            inner: self.0.iter(),
+        }
+    }
+}
+
+/** A borrowing iterator over the settings for each index. */
+pub struct Settings<'a, TSettings: 'a> {
+    inner: Iter<'a, String, IndexSettingsEntry<TSettings>>,
+}
+
+impl<'a, TSettings: 'a> Iterator for Settings<'a, TSettings> {
+    type Item = (&'a str, &'a TSettings);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(index, entry)| (index.as_ref(), &entry.settings.index))
+    }
+}
+
+impl<TSettings: DeserializeOwned> IsOk for GetIndexSettingsResponse<TSettings> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
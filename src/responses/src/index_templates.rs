@@ -0,0 +1,104 @@
+/*!
+Response types for a [get index template request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html).
+*/
+
+use serde_json::{Map, Value};
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::collections::btree_map::{BTreeMap, IntoIter, Iter};
+
+/**
+Response for a [get index template request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html).
+
+The response maps template names to their definitions.
+A `404` status is parsed as a successful response with no templates.
+
+# Examples
+
+Iterate through the returned templates:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> GetIndexTemplatesResponse { unimplemented!() }
+# fn main() {
+let response: GetIndexTemplatesResponse = do_request();
+
+for (name, template) in response.iter() {
+    println!("{} applies to indices matching {}", name, template.template());
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct GetIndexTemplatesResponse(BTreeMap<String, IndexTemplate>);
+
+/** The definition of a single index template. */
+#[derive(Deserialize, Debug)]
+pub struct IndexTemplate {
+    #[serde(default)] order: i32,
+    template: String,
+    #[serde(default)] settings: Map<String, Value>,
+    #[serde(default)] mappings: Map<String, Value>,
+    #[serde(default)] aliases: Map<String, Value>,
+}
+
+impl IndexTemplate {
+    /** The order the template is applied in when multiple templates match an index. */
+    pub fn order(&self) -> i32 {
+        self.order
+    }
+
+    /** The pattern for index names the template applies to. */
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /** The index settings applied by the template. */
+    pub fn settings(&self) -> &Map<String, Value> {
+        &self.settings
+    }
+
+    /** The document mappings applied by the template, keyed by type name. */
+    pub fn mappings(&self) -> &Map<String, Value> {
+        &self.mappings
+    }
+
+    /** The aliases applied by the template. */
+    pub fn aliases(&self) -> &Map<String, Value> {
+        &self.aliases
+    }
+}
+
+impl GetIndexTemplatesResponse {
+    /** Get the template with the given name. */
+    pub fn get(&self, name: &str) -> Option<&IndexTemplate> {
+        self.0.get(name)
+    }
+
+    /** Iterate through each template in the form `(name, template)`. */
+    pub fn iter(&self) -> Iter<String, IndexTemplate> {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for GetIndexTemplatesResponse {
+    type Item = (String, IndexTemplate);
+    type IntoIter = IntoIter<String, IndexTemplate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl IsOk for GetIndexTemplatesResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            404 => Ok(MaybeOkResponse::ok(json!({}))),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -1,24 +1,37 @@
 /*!
-Response types for an [index exists request](https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-exists.html).
+Response types for requests that check whether something exists, like an [index exists request](https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-exists.html).
 */
 
 use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
 use error::*;
 
-/** Response for an [index exists request](https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-exists.html). */
+/**
+Response for a request that checks whether something exists.
+
+A `404` status is parsed as a successful response that doesn't exist.
+*/
 #[derive(Deserialize, Debug)]
-pub struct IndicesExistsResponse {
+pub struct ExistsResponse {
     exists: bool
 }
 
-impl IndicesExistsResponse {
-    /** Whether or not the index exists. */
+/** Response for an [index exists request](https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-exists.html). */
+pub type IndicesExistsResponse = ExistsResponse;
+
+/** Response for an [alias exists request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html#alias-retrieving). */
+pub type AliasExistsResponse = ExistsResponse;
+
+/** Response for an [index template exists request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html). */
+pub type IndexTemplateExistsResponse = ExistsResponse;
+
+impl ExistsResponse {
+    /** Whether or not the index, alias or template exists. */
     pub fn exists(&self) -> bool {
         self.exists
     }
 }
 
-impl IsOk for IndicesExistsResponse {
+impl IsOk for ExistsResponse {
     fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
         match head.status() {
             200...299 => Ok(MaybeOkResponse::ok(json!({ "exists": true }))),
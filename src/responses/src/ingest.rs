@@ -0,0 +1,295 @@
+/*!
+Response types for [ingest pipeline requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/pipeline.html).
+*/
+
+use serde::de::{Deserialize, DeserializeOwned, Deserializer, Error as DeError};
+use serde_json::{self, Map, Value};
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::slice::Iter as SliceIter;
+use std::vec::IntoIter as VecIntoIter;
+use std::collections::btree_map::{BTreeMap, IntoIter, Iter};
+
+/**
+Response for a [get pipeline request](https://www.elastic.co/guide/en/elasticsearch/reference/current/get-pipeline-api.html).
+
+The response maps pipeline ids to their definitions.
+Both the `pipelines` array returned by Elasticsearch `5.x` and the map of ids returned by later versions are supported.
+A `404` status is parsed as a successful response with no pipelines.
+
+# Examples
+
+Iterate through the returned pipelines:
+
+```no_run
+# extern crate serde_json;
+# extern crate elastic_responses;
+# use serde_json::Value;
+# use elastic_responses::*;
+# fn do_request() -> GetPipelinesResponse<Value> { unimplemented!() }
+# fn main() {
+let response: GetPipelinesResponse<Value> = do_request();
+
+for (id, pipeline) in response.iter() {
+    println!("{}: {}", id, pipeline["description"]);
+}
+# }
+```
+*/
+#[derive(Debug)]
+pub struct GetPipelinesResponse<TPipeline>(BTreeMap<String, TPipeline>);
+
+#[derive(Deserialize)]
+struct PipelineEntry {
+    id: String,
+    config: Value,
+}
+
+impl<TPipeline> GetPipelinesResponse<TPipeline> {
+    /** Get the pipeline with the given id. */
+    pub fn get(&self, id: &str) -> Option<&TPipeline> {
+        self.0.get(id)
+    }
+
+    /** Iterate through each pipeline in the form `(id, pipeline)`. */
+    pub fn iter(&self) -> Iter<String, TPipeline> {
+        self.0.iter()
+    }
+}
+
+impl<TPipeline> IntoIterator for GetPipelinesResponse<TPipeline> {
+    type Item = (String, TPipeline);
+    type IntoIter = IntoIter<String, TPipeline>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'de, TPipeline> Deserialize<'de> for GetPipelinesResponse<TPipeline>
+where
+    TPipeline: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut pipelines = Map::deserialize(deserializer)?;
+
+        let entries: Vec<(String, Value)> = match pipelines.remove("pipelines") {
+            Some(Value::Array(entries)) => entries
+                .into_iter()
+                .map(|entry| serde_json::from_value::<PipelineEntry>(entry).map(|entry| (entry.id, entry.config)))
+                .collect::<Result<_, _>>()
+                .map_err(D::Error::custom)?,
+            Some(pipeline) => {
+                pipelines.insert("pipelines".to_owned(), pipeline);
+                pipelines.into_iter().collect()
+            }
+            None => pipelines.into_iter().collect(),
+        };
+
+        let pipelines = entries
+            .into_iter()
+            .map(|(id, pipeline)| serde_json::from_value(pipeline).map(|pipeline| (id, pipeline)))
+            .collect::<Result<_, _>>()
+            .map_err(D::Error::custom)?;
+
+        Ok(GetPipelinesResponse(pipelines))
+    }
+}
+
+impl<TPipeline> IsOk for GetPipelinesResponse<TPipeline> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            404 => Ok(MaybeOkResponse::ok(json!({}))),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+Response for a [simulate pipeline request](https://www.elastic.co/guide/en/elasticsearch/reference/current/simulate-pipeline-api.html).
+
+The response contains a result for each sample document in the request.
+If the request was `verbose` then each result also contains the document after each processor was applied.
+
+# Examples
+
+Print the result of each processor for each document:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> SimulatePipelineResponse { unimplemented!() }
+# fn main() {
+let response: SimulatePipelineResponse = do_request();
+
+for doc in response.iter() {
+    for result in doc.processor_results() {
+        match (result.doc(), result.error()) {
+            (Some(doc), _) => println!("{:?}: {}", result.tag(), doc.source()),
+            (_, Some(error)) => println!("{:?} failed: {}", result.tag(), error),
+            _ => (),
+        }
+    }
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct SimulatePipelineResponse {
+    docs: Vec<SimulatedDocument>,
+}
+
+impl SimulatePipelineResponse {
+    /** Iterate through the result for each sample document. */
+    pub fn iter(&self) -> SliceIter<SimulatedDocument> {
+        self.docs.iter()
+    }
+}
+
+impl IntoIterator for SimulatePipelineResponse {
+    type Item = SimulatedDocument;
+    type IntoIter = VecIntoIter<SimulatedDocument>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.docs.into_iter()
+    }
+}
+
+impl IsOk for SimulatePipelineResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/** The result of running a pipeline on a single sample document. */
+#[derive(Deserialize, Debug)]
+pub struct SimulatedDocument {
+    #[serde(default)] processor_results: Vec<ProcessorResult>,
+    doc: Option<IngestDocument>,
+    error: Option<Value>,
+}
+
+impl SimulatedDocument {
+    /**
+    The result of each processor in the pipeline.
+
+    Processor results are only returned for `verbose` requests.
+    */
+    pub fn processor_results(&self) -> &[ProcessorResult] {
+        &self.processor_results
+    }
+
+    /**
+    The document after the whole pipeline was applied.
+
+    For `verbose` requests this is the document from the last processor that succeeded.
+    */
+    pub fn doc(&self) -> Option<&IngestDocument> {
+        match self.doc {
+            Some(ref doc) => Some(doc),
+            None => self.processor_results
+                .iter()
+                .rev()
+                .filter_map(|result| result.doc())
+                .next(),
+        }
+    }
+
+    /**
+    The error that caused the pipeline to fail.
+
+    For `verbose` requests this is the error from the first processor that failed.
+    */
+    pub fn error(&self) -> Option<&Value> {
+        match self.error {
+            Some(ref error) => Some(error),
+            None => self.processor_results
+                .iter()
+                .filter_map(|result| result.error())
+                .next(),
+        }
+    }
+}
+
+/** The result of applying a single processor to a sample document. */
+#[derive(Deserialize, Debug)]
+pub struct ProcessorResult {
+    tag: Option<String>,
+    doc: Option<IngestDocument>,
+    error: Option<Value>,
+}
+
+impl ProcessorResult {
+    /** The tag of the processor. */
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_ref().map(|tag| tag.as_ref())
+    }
+
+    /** The document after the processor was applied, if it succeeded. */
+    pub fn doc(&self) -> Option<&IngestDocument> {
+        self.doc.as_ref()
+    }
+
+    /** The error returned by the processor, if it failed. */
+    pub fn error(&self) -> Option<&Value> {
+        self.error.as_ref()
+    }
+}
+
+/** A sample document that's been processed by a pipeline. */
+#[derive(Deserialize, Debug)]
+pub struct IngestDocument {
+    #[serde(rename = "_index")] index: String,
+    #[serde(rename = "_type")] ty: String,
+    #[serde(rename = "_id")] id: String,
+    #[serde(rename = "_source")] source: Value,
+    #[serde(rename = "_ingest", default)] ingest: Map<String, Value>,
+}
+
+impl IngestDocument {
+    /** The index of the document. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The type of the document. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The id of the document. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /** The source of the document. */
+    pub fn source(&self) -> &Value {
+        &self.source
+    }
+
+    /**
+    Deserialise the source of the document.
+
+    Processors can change the shape of a document, so the source might not match the document type that was sent.
+    */
+    pub fn source_as<TDocument>(&self) -> Result<TDocument, serde_json::Error>
+    where
+        TDocument: DeserializeOwned,
+    {
+        serde_json::from_value(self.source.clone())
+    }
+
+    /** The ingest metadata for the document, like the `timestamp` it was processed. */
+    pub fn ingest(&self) -> &Map<String, Value> {
+        &self.ingest
+    }
+}
@@ -144,11 +144,30 @@ mod common;
 mod command;
 mod ping;
 mod get;
+pub mod mget;
 mod delete;
 mod update;
 pub mod search;
+pub mod msearch;
+mod count;
 pub mod bulk;
+pub mod bulk_by_scroll;
+pub mod tasks;
+pub mod aliases;
+pub mod index_templates;
+pub mod mapping;
+pub mod index_settings;
+pub mod cluster_health;
+pub mod snapshot;
+pub mod ingest;
+pub mod cat;
+pub mod analyze;
+pub mod explain;
+pub mod validate;
+pub mod termvectors;
+pub mod search_template;
 mod index;
+pub mod nodes_info;
 
 mod indices_exists;
 
@@ -156,11 +175,30 @@ pub use self::common::*;
 pub use self::command::*;
 pub use self::ping::*;
 pub use self::get::*;
+pub use self::mget::MgetResponse;
 pub use self::delete::*;
 pub use self::update::*;
 pub use self::search::SearchResponse;
+pub use self::msearch::MsearchResponse;
+pub use self::count::*;
 pub use self::bulk::{BulkErrorsResponse, BulkResponse};
+pub use self::bulk_by_scroll::{BulkByScrollResponse, DeleteByQueryResponse, ReindexResponse, UpdateByQueryResponse};
+pub use self::tasks::StartedTaskResponse;
+pub use self::aliases::GetAliasesResponse;
+pub use self::index_templates::GetIndexTemplatesResponse;
+pub use self::mapping::GetMappingResponse;
+pub use self::index_settings::GetIndexSettingsResponse;
+pub use self::cluster_health::{ClusterHealthResponse, ClusterHealthStatus};
+pub use self::analyze::AnalyzeResponse;
+pub use self::explain::ExplainResponse;
+pub use self::validate::ValidateQueryResponse;
+pub use self::termvectors::{MtermvectorsResponse, TermvectorsResponse};
+pub use self::search_template::{GetSearchTemplateResponse, RenderSearchTemplateResponse};
+pub use self::cat::{CatAliasesResponse, CatAllocationResponse, CatHealthResponse, CatIndicesResponse, CatNodesResponse, CatResponse, CatSegmentsResponse, CatShardsResponse, CatThreadPoolResponse};
+pub use self::ingest::{GetPipelinesResponse, SimulatePipelineResponse};
+pub use self::snapshot::{CreateSnapshotResponse, GetRepositoriesResponse, GetSnapshotsResponse, RestoreSnapshotResponse, SnapshotStatusResponse};
 pub use self::index::*;
+pub use self::nodes_info::NodesInfoResponse;
 
 pub use self::indices_exists::*;
 
@@ -0,0 +1,311 @@
+/*!
+Response types for a [get mapping request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html).
+*/
+
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde_json::{self, Map, Value};
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::fmt;
+use std::collections::btree_map::{BTreeMap, Iter};
+
+const OBJECT_DATATYPE: &'static str = "object";
+
+/**
+Response for a [get mapping request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html).
+
+The response maps indices and document types to the mapping the cluster has for them.
+
+# Examples
+
+Check whether the live mapping for a type has drifted from the expected mapping:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# use elastic_responses::mapping::FieldMapping;
+# fn do_request() -> GetMappingResponse { unimplemented!() }
+# fn expected_mapping() -> FieldMapping { unimplemented!() }
+# fn main() {
+let response: GetMappingResponse = do_request();
+
+if let Some(mapping) = response.get("myindex", "mytype") {
+    for difference in mapping.diff(&expected_mapping()) {
+        println!("{}", difference);
+    }
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct GetMappingResponse(BTreeMap<String, IndexMappings>);
+
+#[derive(Deserialize, Debug)]
+struct IndexMappings {
+    #[serde(default)] mappings: BTreeMap<String, FieldMapping>,
+}
+
+impl GetMappingResponse {
+    /** Get the mapping for a document type on an index. */
+    pub fn get(&self, index: &str, ty: &str) -> Option<&FieldMapping> {
+        self.0.get(index).and_then(|mappings| mappings.mappings.get(ty))
+    }
+
+    /** Iterate through each index and type pair in the form `(index, type, mapping)`. */
+    pub fn iter(&self) -> Mappings {
+        Mappings {
+            indices: self.0.iter(),
+            current: None,
+        }
+    }
+}
+
+/** A borrowing iterator over index and type mappings. */
+pub struct Mappings<'a> {
+    indices: Iter<'a, String, IndexMappings>,
+    current: Option<(&'a str, Iter<'a, String, FieldMapping>)>,
+}
+
+impl<'a> Iterator for Mappings<'a> {
+    type Item = (&'a str, &'a str, &'a FieldMapping);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((index, ref mut mappings)) = self.current {
+                if let Some((ty, mapping)) = mappings.next() {
+                    return Some((index, ty, mapping));
+                }
+            }
+
+            match self.indices.next() {
+                Some((index, mappings)) => self.current = Some((index, mappings.mappings.iter())),
+                None => return None,
+            }
+        }
+    }
+}
+
+impl IsOk for GetMappingResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+A parsed mapping for a single field.
+
+The root mapping for a document type is also a `FieldMapping`, with its fields in `properties`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldMapping {
+    ty: Option<String>,
+    properties: BTreeMap<String, FieldMapping>,
+    fields: BTreeMap<String, FieldMapping>,
+    params: Map<String, Value>,
+}
+
+impl FieldMapping {
+    /**
+    The datatype of the field.
+
+    Fields with properties but no explicit type are objects.
+    */
+    pub fn ty(&self) -> Option<&str> {
+        match self.ty {
+            Some(ref ty) => Some(ty),
+            None if !self.properties.is_empty() => Some(OBJECT_DATATYPE),
+            None => None,
+        }
+    }
+
+    /** The mappings for the inner fields of an object. */
+    pub fn properties(&self) -> &BTreeMap<String, FieldMapping> {
+        &self.properties
+    }
+
+    /** The mappings for the multi-fields of a field. */
+    pub fn fields(&self) -> &BTreeMap<String, FieldMapping> {
+        &self.fields
+    }
+
+    /** Any other mapping parameters, like `analyzer` or `format`. */
+    pub fn params(&self) -> &Map<String, Value> {
+        &self.params
+    }
+
+    /**
+    Compare this mapping with an expected mapping.
+
+    Every field and parameter in the `expected` mapping must be in this mapping with the same value.
+    Fields and parameters that are only in this mapping aren't reported, because Elasticsearch may add its own.
+    */
+    pub fn diff(&self, expected: &FieldMapping) -> Vec<MappingDifference> {
+        let mut differences = Vec::new();
+
+        diff_params("", self, expected, &mut differences);
+        diff_fields("", &self.properties, &expected.properties, &mut differences);
+
+        differences
+    }
+
+    fn from_params(mut params: Map<String, Value>) -> Result<Self, serde_json::Error> {
+        let ty = match params.remove("type") {
+            Some(ty) => Some(serde_json::from_value(ty)?),
+            None => None,
+        };
+
+        let properties = match params.remove("properties") {
+            Some(properties) => serde_json::from_value(properties)?,
+            None => BTreeMap::new(),
+        };
+
+        let fields = match params.remove("fields") {
+            Some(fields) => serde_json::from_value(fields)?,
+            None => BTreeMap::new(),
+        };
+
+        Ok(FieldMapping {
+            ty: ty,
+            properties: properties,
+            fields: fields,
+            params: params,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldMapping {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let params = Map::deserialize(deserializer)?;
+
+        FieldMapping::from_params(params).map_err(D::Error::custom)
+    }
+}
+
+fn diff_fields(prefix: &str, actual: &BTreeMap<String, FieldMapping>, expected: &BTreeMap<String, FieldMapping>, differences: &mut Vec<MappingDifference>) {
+    for (name, expected) in expected {
+        let path = format!("{}{}", prefix, name);
+
+        let actual = match actual.get(name) {
+            Some(actual) => actual,
+            None => {
+                differences.push(MappingDifference::MissingField { path: path });
+                continue;
+            }
+        };
+
+        if actual.ty() != expected.ty() {
+            differences.push(MappingDifference::TypeMismatch {
+                path: path,
+                expected: expected.ty().map(Into::into),
+                actual: actual.ty().map(Into::into),
+            });
+            continue;
+        }
+
+        diff_params(&path, actual, expected, differences);
+
+        let prefix = format!("{}.", path);
+        diff_fields(&prefix, &actual.properties, &expected.properties, differences);
+        diff_fields(&prefix, &actual.fields, &expected.fields, differences);
+    }
+}
+
+fn diff_params(path: &str, actual: &FieldMapping, expected: &FieldMapping, differences: &mut Vec<MappingDifference>) {
+    for (param, expected) in &expected.params {
+        let actual = actual.params.get(param);
+
+        if actual != Some(expected) {
+            differences.push(MappingDifference::ParamMismatch {
+                path: path.to_owned(),
+                param: param.to_owned(),
+                expected: expected.clone(),
+                actual: actual.cloned(),
+            });
+        }
+    }
+}
+
+/**
+A difference between a live mapping and an expected mapping.
+
+Paths to fields are separated by dots, so a multi-field `raw` on a field `title` has the path `title.raw`.
+Parameters on the root of the mapping have an empty path.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum MappingDifference {
+    /** A field in the expected mapping is missing from the live mapping. */
+    MissingField {
+        /** The path to the field. */
+        path: String,
+    },
+    /** A field has a different datatype in the live mapping. */
+    TypeMismatch {
+        /** The path to the field. */
+        path: String,
+        /** The expected datatype. */
+        expected: Option<String>,
+        /** The datatype in the live mapping. */
+        actual: Option<String>,
+    },
+    /** A mapping parameter has a different value in the live mapping. */
+    ParamMismatch {
+        /** The path to the field. */
+        path: String,
+        /** The name of the parameter. */
+        param: String,
+        /** The expected value. */
+        expected: Value,
+        /** The value in the live mapping, if it's set. */
+        actual: Option<Value>,
+    },
+}
+
+impl MappingDifference {
+    /** The path to the field that's different. */
+    pub fn path(&self) -> &str {
+        match *self {
+            MappingDifference::MissingField { ref path } |
+            MappingDifference::TypeMismatch { ref path, .. } |
+            MappingDifference::ParamMismatch { ref path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for MappingDifference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MappingDifference::MissingField { ref path } => write!(f, "field `{}` is missing", path),
+            MappingDifference::TypeMismatch {
+                ref path,
+                ref expected,
+                ref actual,
+            } => write!(
+                f,
+                "field `{}` has type `{}` but expected `{}`",
+                path,
+                actual.as_ref().map(AsRef::as_ref).unwrap_or("none"),
+                expected.as_ref().map(AsRef::as_ref).unwrap_or("none")
+            ),
+            MappingDifference::ParamMismatch {
+                ref path,
+                ref param,
+                ref expected,
+                actual: Some(ref actual),
+            } => write!(f, "field `{}` has `{}` set to `{}` but expected `{}`", path, param, actual, expected),
+            MappingDifference::ParamMismatch {
+                ref path,
+                ref param,
+                ref expected,
+                actual: None,
+            } => write!(f, "field `{}` doesn't have `{}` set but expected `{}`", path, param, expected),
+        }
+    }
+}
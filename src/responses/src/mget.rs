@@ -0,0 +1,200 @@
+/*!
+Response types for a [multi get request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-get.html).
+*/
+
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+use get::GetResponse;
+
+use std::fmt;
+use std::slice::Iter;
+use std::vec::IntoIter;
+use std::error::Error;
+
+/**
+Response for a [multi get request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-get.html).
+
+Individual documents are a `Result` of [`GetResponse`](../struct.GetResponse.html) or [`ErrorItem`](struct.ErrorItem.html) and can be iterated over.
+A document that doesn't exist is returned as an `Ok(GetResponse)` where `found` is `false`.
+A document that couldn't be fetched, for instance because its index doesn't exist, is returned as an `Err(ErrorItem)`.
+
+# Examples
+
+Send a multi get request and iterate through the documents:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> MgetResponse<Value> { unimplemented!() }
+# fn main() {
+let response: MgetResponse<Value> = do_request();
+
+for doc in response {
+    match doc {
+        Ok(ref doc) if doc.found() => {
+            // Do something with the found documents
+            println!("found: {:?}", doc.document())
+        },
+        Ok(doc) => {
+            // Do something with the missing documents
+            println!("missing: {}", doc.id())
+        },
+        Err(doc) => {
+            // Do something with the `ErrorItem`s
+            println!("err: {:?}", doc)
+        }
+    }
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+#[serde(bound(deserialize = "T: DeserializeOwned"))]
+pub struct MgetResponse<T> {
+    #[serde(deserialize_with = "deserialize_mget_docs")] docs: Vec<DocResult<T>>,
+}
+
+impl<T> MgetResponse<T> {
+    /** Returns `true` if all documents were fetched without errors, even if some weren't found. */
+    pub fn is_ok(&self) -> bool {
+        self.docs.iter().all(|doc| doc.is_ok())
+    }
+
+    /** Returns `true` if any documents couldn't be fetched. */
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+
+    /**
+    Iterate through the documents.
+
+    The items in this iterator are in the same order as the documents in the request.
+
+    To move out of the documents in an `MgetResponse` instead of borrowing them, call `into_iter`.
+    */
+    pub fn iter(&self) -> ResultIter<T> {
+        ResultIter(self.docs.iter())
+    }
+}
+
+impl<T> IntoIterator for MgetResponse<T> {
+    type Item = <Self::IntoIter as Iterator>::Item;
+    type IntoIter = ResultIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ResultIntoIter(self.docs.into_iter())
+    }
+}
+
+/** An owning iterator for a document that may have been fetched or failed. */
+pub struct ResultIntoIter<T>(IntoIter<DocResult<T>>);
+
+impl<T> Iterator for ResultIntoIter<T> {
+    type Item = DocResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/** A borrowing iterator for a document that may have been fetched or failed. */
+pub struct ResultIter<'a, T: 'a>(Iter<'a, DocResult<T>>);
+
+impl<'a, T: 'a> Iterator for ResultIter<'a, T> {
+    type Item = DocResultBrw<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|doc| doc.as_ref())
+    }
+}
+
+type DocResult<T> = Result<GetResponse<T>, ErrorItem>;
+type DocResultBrw<'a, T> = Result<&'a GetResponse<T>, &'a ErrorItem>;
+
+/** A document that couldn't be fetched. */
+#[derive(Deserialize, Debug)]
+pub struct ErrorItem {
+    #[serde(rename = "_index")] index: String,
+    #[serde(rename = "_type")] ty: String,
+    #[serde(rename = "_id")] id: String,
+    #[serde(deserialize_with = "deserialize_required_cause")] error: ApiError,
+}
+
+impl ErrorItem {
+    /** The index for this document. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The type of this document. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The id of this document. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /** The error returned for this document. */
+    pub fn error(&self) -> &ApiError {
+        &self.error
+    }
+}
+
+impl fmt::Display for ErrorItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "multi get document failed. Details: index: {}, type: {}, id: {}, inner error: {}",
+            self.index,
+            self.ty,
+            self.id,
+            self.error
+        )
+    }
+}
+
+impl Error for ErrorItem {
+    fn description(&self) -> &str {
+        "multi get document failed"
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        Some(&self.error)
+    }
+}
+
+// A failed document has an `error` field, so it's tried first.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DocResultDe<T> {
+    Err(ErrorItem),
+    Ok(GetResponse<T>),
+}
+
+fn deserialize_mget_docs<'de, D, T>(deserializer: D) -> Result<Vec<DocResult<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let docs = Vec::<DocResultDe<T>>::deserialize(deserializer)?;
+
+    Ok(docs.into_iter()
+        .map(|doc| match doc {
+            DocResultDe::Ok(doc) => Ok(doc),
+            DocResultDe::Err(doc) => Err(doc),
+        })
+        .collect())
+}
+
+impl<T: DeserializeOwned> IsOk for MgetResponse<T> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
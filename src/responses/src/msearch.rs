@@ -0,0 +1,136 @@
+/*!
+Response types for a [multi search request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html).
+*/
+
+use serde::de::{Deserialize, DeserializeOwned, Deserializer, Error as DeError};
+use serde_json::{self, Map, Value};
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+use search::SearchResponse;
+
+use std::slice::Iter;
+use std::vec::IntoIter;
+
+/**
+Response for a [multi search request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html).
+
+Individual search responses are a `Result` of [`SearchResponse`](../struct.SearchResponse.html) or [`ApiError`](../error/enum.ApiError.html) and can be iterated over.
+The responses are in the same order as the searches in the request.
+
+# Examples
+
+Send a multi search request and iterate through the responses:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> MsearchResponse<Value> { unimplemented!() }
+# fn main() {
+let response: MsearchResponse<Value> = do_request();
+
+for search in response {
+    match search {
+        Ok(search) => {
+            // Do something with the `SearchResponse`s
+            println!("total hits: {}", search.total())
+        },
+        Err(err) => {
+            // Do something with the `ApiError`s
+            println!("err: {:?}", err)
+        }
+    }
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+#[serde(bound(deserialize = "T: DeserializeOwned"))]
+pub struct MsearchResponse<T> {
+    #[serde(deserialize_with = "deserialize_msearch_responses")] responses: Vec<SearchResult<T>>,
+}
+
+impl<T> MsearchResponse<T> {
+    /** Returns `true` if all searches succeeded. */
+    pub fn is_ok(&self) -> bool {
+        self.responses.iter().all(|res| res.is_ok())
+    }
+
+    /** Returns `true` if any searches failed. */
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+
+    /**
+    Iterate through the search responses.
+
+    To move out of the responses in an `MsearchResponse` instead of borrowing them, call `into_iter`.
+    */
+    pub fn iter(&self) -> ResultIter<T> {
+        ResultIter(self.responses.iter())
+    }
+}
+
+impl<T> IntoIterator for MsearchResponse<T> {
+    type Item = <Self::IntoIter as Iterator>::Item;
+    type IntoIter = ResultIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ResultIntoIter(self.responses.into_iter())
+    }
+}
+
+/** An owning iterator for a search that may have succeeded or failed. */
+pub struct ResultIntoIter<T>(IntoIter<SearchResult<T>>);
+
+impl<T> Iterator for ResultIntoIter<T> {
+    type Item = SearchResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/** A borrowing iterator for a search that may have succeeded or failed. */
+pub struct ResultIter<'a, T: 'a>(Iter<'a, SearchResult<T>>);
+
+impl<'a, T: 'a> Iterator for ResultIter<'a, T> {
+    type Item = SearchResultBrw<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|res| res.as_ref())
+    }
+}
+
+type SearchResult<T> = Result<SearchResponse<T>, ApiError>;
+type SearchResultBrw<'a, T> = Result<&'a SearchResponse<T>, &'a ApiError>;
+
+fn deserialize_msearch_responses<'de, D, T>(deserializer: D) -> Result<Vec<SearchResult<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let responses = Vec::<Map<String, Value>>::deserialize(deserializer)?;
+
+    responses
+        .into_iter()
+        .map(|res| {
+            if res.contains_key("error") {
+                Ok(Err(res.into()))
+            } else {
+                serde_json::from_value(Value::Object(res))
+                    .map(Ok)
+                    .map_err(D::Error::custom)
+            }
+        })
+        .collect()
+}
+
+impl<T: DeserializeOwned> IsOk for MsearchResponse<T> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+/*!
+Response types for a [nodes info request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-nodes-info.html).
+*/
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::collections::btree_map::{BTreeMap, Iter};
+
+/** Response for a [nodes info request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-nodes-info.html). */
+#[derive(Deserialize, Debug)]
+pub struct NodesInfoResponse {
+    cluster_name: Option<String>,
+    #[serde(default)] nodes: BTreeMap<String, NodeInfo>,
+}
+
+/** Metadata for a single node in the cluster. */
+#[derive(Deserialize, Debug)]
+pub struct NodeInfo {
+    name: Option<String>,
+    host: Option<String>,
+    version: Option<String>,
+    http: Option<NodeHttpInfo>,
+}
+
+#[derive(Deserialize, Debug)]
+struct NodeHttpInfo {
+    publish_address: Option<String>,
+}
+
+impl NodesInfoResponse {
+    /** The name of the cluster the nodes belong to. */
+    pub fn cluster_name(&self) -> Option<&str> {
+        self.cluster_name.as_ref().map(|name| name.as_ref())
+    }
+
+    /** Iterate over the nodes in the cluster along with their ids. */
+    pub fn nodes(&self) -> Nodes {
+        Nodes {
+            inner: self.nodes.iter(),
+        }
+    }
+}
+
+impl NodeInfo {
+    /** The name of the node. */
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(|name| name.as_ref())
+    }
+
+    /** The host of the node. */
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_ref().map(|host| host.as_ref())
+    }
+
+    /** The Elasticsearch version of the node. */
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_ref().map(|version| version.as_ref())
+    }
+
+    /**
+    The address the node publishes for HTTP clients to connect to.
+
+    This is only returned when the `http` metric is requested and the node has HTTP enabled.
+    The address is returned as-is, which can be either `ip:port` or `hostname/ip:port`.
+    */
+    pub fn http_publish_address(&self) -> Option<&str> {
+        self.http
+            .as_ref()
+            .and_then(|http| http.publish_address.as_ref())
+            .map(|address| address.as_ref())
+    }
+}
+
+/** A borrowing iterator over the nodes in a nodes info response. */
+pub struct Nodes<'a> {
+    inner: Iter<'a, String, NodeInfo>,
+}
+
+impl<'a> Iterator for Nodes<'a> {
+    type Item = (&'a str, &'a NodeInfo);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(id, node)| (id.as_ref(), node))
+    }
+}
+
+impl IsOk for NodesInfoResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -137,6 +137,13 @@ where
     /** Buffer the response body to a json value and return a new buffered representation. */
     fn body(self) -> Result<(Value, Self::Buffered), ParseResponseError>;
 
+    /**
+    Buffer the response body to a string and return a new buffered representation.
+
+    Invalid utf8 sequences are replaced rather than returned as an error.
+    */
+    fn text(self) -> Result<(String, Self::Buffered), ParseResponseError>;
+
     /** Parse the body as a success result. */
     fn parse_ok<T: DeserializeOwned>(self) -> Result<T, ParseResponseError>;
 
@@ -158,6 +165,15 @@ impl<B: Read> ResponseBody for ReadBody<B> {
         Ok((body, SliceBody(buf)))
     }
 
+    fn text(mut self) -> Result<(String, Self::Buffered), ParseResponseError> {
+        let mut buf = Vec::new();
+        self.0.read_to_end(&mut buf)?;
+
+        let text = String::from_utf8_lossy(&buf).into_owned();
+
+        Ok((text, SliceBody(buf)))
+    }
+
     fn parse_ok<T: DeserializeOwned>(self) -> Result<T, ParseResponseError> {
         serde_json::from_reader(self.0).map_err(|e| e.into())
     }
@@ -180,6 +196,14 @@ impl<B: AsRef<[u8]>> ResponseBody for SliceBody<B> {
         Ok((body, SliceBody(buf)))
     }
 
+    fn text(self) -> Result<(String, Self::Buffered), ParseResponseError> {
+        let buf = self.0;
+
+        let text = String::from_utf8_lossy(buf.as_ref()).into_owned();
+
+        Ok((text, SliceBody(buf)))
+    }
+
     fn parse_ok<T: DeserializeOwned>(self) -> Result<T, ParseResponseError> {
         serde_json::from_slice(self.0.as_ref()).map_err(|e| e.into())
     }
@@ -198,6 +222,12 @@ impl ResponseBody for Value {
         Ok((self, value))
     }
 
+    fn text(self) -> Result<(String, Self::Buffered), ParseResponseError> {
+        let text = self.to_string();
+
+        Ok((text, self))
+    }
+
     fn parse_ok<T: DeserializeOwned>(self) -> Result<T, ParseResponseError> {
         serde_json::from_value(self).map_err(|e| e.into())
     }
@@ -327,6 +357,11 @@ impl<B: ResponseBody> Unbuffered<B> {
     pub fn body(self) -> Result<(Value, Buffered<B>), ParseResponseError> {
         self.0.body().map(|(value, body)| (value, Buffered(body)))
     }
+
+    /** Buffer the response body to a string and return a new buffered representation. */
+    pub fn text(self) -> Result<(String, Buffered<B>), ParseResponseError> {
+        self.0.text().map(|(text, body)| (text, Buffered(body)))
+    }
 }
 
 /** A response body that has been buffered. */
@@ -56,6 +56,7 @@ for hit in response.hits() {
 pub struct SearchResponse<T> {
     took: u64,
     timed_out: bool,
+    #[serde(rename = "_scroll_id")] scroll_id: Option<String>,
     #[serde(rename = "_shards")] shards: Shards,
     hits: HitsWrapper<T>,
     aggregations: Option<AggsWrapper>,
@@ -91,6 +92,15 @@ impl<T> SearchResponse<T> {
         self.status.clone()
     }
 
+    /** 
+    The scroll id for fetching the next page of hits.
+    
+    This is only returned for search requests that set a `scroll` keep-alive.
+    */
+    pub fn scroll_id(&self) -> Option<&str> {
+        self.scroll_id.as_ref().map(|scroll_id| scroll_id.as_ref())
+    }
+
     /** The total number of documents that matched the search query. */
     pub fn total(&self) -> u64 {
         self.hits.total
@@ -240,6 +250,7 @@ impl<T> Iterator for IntoDocuments<T> {
 pub struct Hit<T> {
     #[serde(rename = "_index")] index: String,
     #[serde(rename = "_type")] ty: String,
+    #[serde(rename = "_id")] id: String,
     #[serde(rename = "_version")] version: Option<u32>,
     #[serde(rename = "_score")] score: Option<f32>,
     #[serde(rename = "_source")] source: Option<T>,
@@ -267,6 +278,11 @@ impl<T> Hit<T> {
         &self.ty
     }
 
+    /** The id of the hit. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
     /** The version of the hit. */
     pub fn version(&self) -> Option<u32> {
         self.version.clone()
@@ -0,0 +1,145 @@
+/*!
+Response types for [search template requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html).
+
+Searching with a template returns a regular [`SearchResponse`](../search/struct.SearchResponse.html).
+*/
+
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/**
+Response for a [render search template request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html).
+
+The response contains the search body the template expands to.
+
+# Examples
+
+Print the query a template expands to:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> RenderSearchTemplateResponse { unimplemented!() }
+# fn main() {
+let response: RenderSearchTemplateResponse = do_request();
+
+println!("{}", response.template_output());
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct RenderSearchTemplateResponse {
+    template_output: Value,
+}
+
+impl RenderSearchTemplateResponse {
+    /** The search body the template expands to. */
+    pub fn template_output(&self) -> &Value {
+        &self.template_output
+    }
+
+    /** Convert the response into the search body the template expands to. */
+    pub fn into_template_output(self) -> Value {
+        self.template_output
+    }
+}
+
+impl IsOk for RenderSearchTemplateResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+Response for a [get stored search template request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html#pre-registered-templates).
+
+# Examples
+
+Print the source of a stored template:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> GetSearchTemplateResponse { unimplemented!() }
+# fn main() {
+let response: GetSearchTemplateResponse = do_request();
+
+if let Some(template) = response.template() {
+    println!("{}: {}", response.id(), template);
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct GetSearchTemplateResponse {
+    #[serde(rename = "_id")] id: String,
+    found: bool,
+    lang: Option<String>,
+    template: Option<Value>,
+    script: Option<StoredScript>,
+}
+
+#[derive(Deserialize, Debug)]
+struct StoredScript {
+    lang: Option<String>,
+    code: Option<Value>,
+    source: Option<Value>,
+}
+
+impl GetSearchTemplateResponse {
+    /** The id of the template. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /** Whether or not a matching template was found. */
+    pub fn found(&self) -> bool {
+        self.found
+    }
+
+    /** The language of the template, like `mustache`. */
+    pub fn lang(&self) -> Option<&str> {
+        match self.script {
+            Some(StoredScript { lang: Some(ref lang), .. }) => Some(lang),
+            _ => self.lang.as_ref().map(|lang| lang.as_ref()),
+        }
+    }
+
+    /**
+    The source of the template.
+
+    Templates are usually stored as strings, even if they were put as objects.
+    */
+    pub fn template(&self) -> Option<&Value> {
+        match self.script {
+            Some(StoredScript { source: Some(ref source), .. }) | Some(StoredScript { code: Some(ref source), .. }) => Some(source),
+            _ => self.template.as_ref(),
+        }
+    }
+}
+
+impl IsOk for GetSearchTemplateResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            404 => {
+                // If we get a 404, it could be an error or a missing template
+                // Check if the response contains a root 'error' node
+                let (maybe_err, body) = body.body()?;
+
+                let is_ok = maybe_err
+                    .as_object()
+                    .and_then(|maybe_err| maybe_err.get("error"))
+                    .is_none();
+
+                Ok(MaybeOkResponse::new(is_ok, body))
+            }
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
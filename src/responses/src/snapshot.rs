@@ -0,0 +1,600 @@
+/*!
+Response types for [snapshot and restore requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html).
+*/
+
+use serde_json::{Map, Value};
+
+use common::Shards;
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::slice::Iter as SliceIter;
+use std::vec::IntoIter as VecIntoIter;
+use std::collections::btree_map::{BTreeMap, IntoIter, Iter};
+
+/**
+Response for a [get snapshot repository request](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_repositories).
+
+The response maps repository names to their definitions.
+A `404` status is parsed as a successful response with no repositories.
+
+# Examples
+
+Iterate through the returned repositories:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> GetRepositoriesResponse { unimplemented!() }
+# fn main() {
+let response: GetRepositoriesResponse = do_request();
+
+for (name, repository) in response.iter() {
+    println!("{} is a {} repository", name, repository.ty());
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct GetRepositoriesResponse(BTreeMap<String, RepositoryInfo>);
+
+/** The definition of a single snapshot repository. */
+#[derive(Deserialize, Debug)]
+pub struct RepositoryInfo {
+    #[serde(rename = "type")] ty: String,
+    #[serde(default)] settings: Map<String, Value>,
+}
+
+impl RepositoryInfo {
+    /** The type of repository, like `fs` or `url`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The settings for the repository, like its `location`. */
+    pub fn settings(&self) -> &Map<String, Value> {
+        &self.settings
+    }
+}
+
+impl GetRepositoriesResponse {
+    /** Get the repository with the given name. */
+    pub fn get(&self, name: &str) -> Option<&RepositoryInfo> {
+        self.0.get(name)
+    }
+
+    /** Iterate through each repository in the form `(name, repository)`. */
+    pub fn iter(&self) -> Iter<String, RepositoryInfo> {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for GetRepositoriesResponse {
+    type Item = (String, RepositoryInfo);
+    type IntoIter = IntoIter<String, RepositoryInfo>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl IsOk for GetRepositoriesResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            404 => Ok(MaybeOkResponse::ok(json!({}))),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+Response for a [create snapshot request](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot).
+
+If the request waited for the snapshot to complete then the response contains the details of the snapshot.
+Otherwise the snapshot is only accepted and will continue in the background.
+
+# Examples
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> CreateSnapshotResponse { unimplemented!() }
+# fn main() {
+let response: CreateSnapshotResponse = do_request();
+
+match response.snapshot() {
+    Some(snapshot) => println!("snapshot {} finished with state {:?}", snapshot.name(), snapshot.state()),
+    None => println!("snapshot accepted: {}", response.accepted()),
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct CreateSnapshotResponse {
+    #[serde(default)] accepted: bool,
+    snapshot: Option<SnapshotInfo>,
+}
+
+impl CreateSnapshotResponse {
+    /** Whether or not the snapshot was accepted. */
+    pub fn accepted(&self) -> bool {
+        self.accepted || self.snapshot.is_some()
+    }
+
+    /** The details of the snapshot, if the request waited for it to complete. */
+    pub fn snapshot(&self) -> Option<&SnapshotInfo> {
+        self.snapshot.as_ref()
+    }
+}
+
+impl IsOk for CreateSnapshotResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+Response for a [get snapshot request](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot).
+
+# Examples
+
+List the snapshots in a repository:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> GetSnapshotsResponse { unimplemented!() }
+# fn main() {
+let response: GetSnapshotsResponse = do_request();
+
+for snapshot in response.iter() {
+    println!("{} contains {:?}", snapshot.name(), snapshot.indices());
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct GetSnapshotsResponse {
+    snapshots: Vec<SnapshotInfo>,
+}
+
+impl GetSnapshotsResponse {
+    /** Get the snapshot with the given name. */
+    pub fn get(&self, name: &str) -> Option<&SnapshotInfo> {
+        self.snapshots.iter().find(|snapshot| snapshot.name() == name)
+    }
+
+    /** Iterate through the snapshots. */
+    pub fn iter(&self) -> SliceIter<SnapshotInfo> {
+        self.snapshots.iter()
+    }
+}
+
+impl IntoIterator for GetSnapshotsResponse {
+    type Item = SnapshotInfo;
+    type IntoIter = VecIntoIter<SnapshotInfo>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.snapshots.into_iter()
+    }
+}
+
+impl IsOk for GetSnapshotsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/** The details of a single snapshot. */
+#[derive(Deserialize, Debug)]
+pub struct SnapshotInfo {
+    snapshot: String,
+    uuid: Option<String>,
+    #[serde(default)] indices: Vec<String>,
+    state: SnapshotState,
+    start_time_in_millis: Option<u64>,
+    end_time_in_millis: Option<u64>,
+    duration_in_millis: Option<u64>,
+    #[serde(default)] failures: Vec<Value>,
+    shards: Option<Shards>,
+}
+
+impl SnapshotInfo {
+    /** The name of the snapshot. */
+    pub fn name(&self) -> &str {
+        &self.snapshot
+    }
+
+    /** The unique id of the snapshot. */
+    pub fn uuid(&self) -> Option<&str> {
+        self.uuid.as_ref().map(|uuid| uuid.as_ref())
+    }
+
+    /** The indices included in the snapshot. */
+    pub fn indices(&self) -> &[String] {
+        &self.indices
+    }
+
+    /** The state of the snapshot. */
+    pub fn state(&self) -> SnapshotState {
+        self.state
+    }
+
+    /** When the snapshot started, in milliseconds since the epoch. */
+    pub fn start_time_in_millis(&self) -> Option<u64> {
+        self.start_time_in_millis
+    }
+
+    /** When the snapshot finished, in milliseconds since the epoch. */
+    pub fn end_time_in_millis(&self) -> Option<u64> {
+        self.end_time_in_millis
+    }
+
+    /** How long the snapshot took, in milliseconds. */
+    pub fn duration_in_millis(&self) -> Option<u64> {
+        self.duration_in_millis
+    }
+
+    /** Any shard failures that occurred while taking the snapshot. */
+    pub fn failures(&self) -> &[Value] {
+        &self.failures
+    }
+
+    /** The number of shards that were snapshotted. */
+    pub fn shards(&self) -> Option<&Shards> {
+        self.shards.as_ref()
+    }
+}
+
+/** The state of a snapshot. */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum SnapshotState {
+    /** The snapshot is currently running. */
+    #[serde(rename = "IN_PROGRESS")]
+    InProgress,
+    /** The snapshot finished and all shards were stored successfully. */
+    #[serde(rename = "SUCCESS")]
+    Success,
+    /** The snapshot finished with an error and failed to store any data. */
+    #[serde(rename = "FAILED")]
+    Failed,
+    /** The global cluster state was stored, but data of at least one shard wasn't. */
+    #[serde(rename = "PARTIAL")]
+    Partial,
+    /** The snapshot was created with an old version of Elasticsearch and can't be restored. */
+    #[serde(rename = "INCOMPATIBLE")]
+    Incompatible,
+}
+
+/**
+Response for a [restore snapshot request](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_restore).
+
+If the request waited for the restore to complete then the response contains the restored indices and shards.
+Otherwise the restore is only accepted and will continue in the background.
+
+# Examples
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> RestoreSnapshotResponse { unimplemented!() }
+# fn main() {
+let response: RestoreSnapshotResponse = do_request();
+
+for index in response.indices() {
+    println!("restored {}", index);
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct RestoreSnapshotResponse {
+    #[serde(default)] accepted: bool,
+    snapshot: Option<RestoreInfo>,
+}
+
+#[derive(Deserialize, Debug)]
+struct RestoreInfo {
+    #[serde(default)] indices: Vec<String>,
+    shards: Shards,
+}
+
+impl RestoreSnapshotResponse {
+    /** Whether or not the restore was accepted. */
+    pub fn accepted(&self) -> bool {
+        self.accepted || self.snapshot.is_some()
+    }
+
+    /** The names of the restored indices, if the request waited for the restore to complete. */
+    pub fn indices(&self) -> &[String] {
+        self.snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.indices.as_ref())
+            .unwrap_or(&[])
+    }
+
+    /** The number of restored shards, if the request waited for the restore to complete. */
+    pub fn shards(&self) -> Option<&Shards> {
+        self.snapshot.as_ref().map(|snapshot| &snapshot.shards)
+    }
+}
+
+impl IsOk for RestoreSnapshotResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+Response for a [snapshot status request](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot_status).
+
+The status includes the progress of each shard in each index in the snapshot.
+
+# Examples
+
+Print the progress of each shard:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> SnapshotStatusResponse { unimplemented!() }
+# fn main() {
+let response: SnapshotStatusResponse = do_request();
+
+for snapshot in response.iter() {
+    for (index, status) in snapshot.indices() {
+        for (shard, status) in status.shards() {
+            println!(
+                "{}/{}[{}] is {}: {}/{} bytes",
+                snapshot.name(),
+                index,
+                shard,
+                status.stage(),
+                status.stats().processed_size_in_bytes(),
+                status.stats().total_size_in_bytes()
+            );
+        }
+    }
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct SnapshotStatusResponse {
+    snapshots: Vec<SnapshotStatus>,
+}
+
+impl SnapshotStatusResponse {
+    /** Get the status of the snapshot with the given name. */
+    pub fn get(&self, name: &str) -> Option<&SnapshotStatus> {
+        self.snapshots.iter().find(|snapshot| snapshot.name() == name)
+    }
+
+    /** Iterate through the status of each snapshot. */
+    pub fn iter(&self) -> SliceIter<SnapshotStatus> {
+        self.snapshots.iter()
+    }
+}
+
+impl IntoIterator for SnapshotStatusResponse {
+    type Item = SnapshotStatus;
+    type IntoIter = VecIntoIter<SnapshotStatus>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.snapshots.into_iter()
+    }
+}
+
+impl IsOk for SnapshotStatusResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/** The status of a single snapshot. */
+#[derive(Deserialize, Debug)]
+pub struct SnapshotStatus {
+    snapshot: String,
+    repository: String,
+    state: String,
+    shards_stats: ShardsStats,
+    stats: SnapshotStats,
+    #[serde(default)] indices: BTreeMap<String, IndexSnapshotStatus>,
+}
+
+impl SnapshotStatus {
+    /** The name of the snapshot. */
+    pub fn name(&self) -> &str {
+        &self.snapshot
+    }
+
+    /** The name of the repository the snapshot is stored in. */
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /** The state of the snapshot, like `STARTED` or `SUCCESS`. */
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /** The number of shards in each stage of the snapshot. */
+    pub fn shards_stats(&self) -> &ShardsStats {
+        &self.shards_stats
+    }
+
+    /** The progress of the snapshot across all shards. */
+    pub fn stats(&self) -> &SnapshotStats {
+        &self.stats
+    }
+
+    /** The status of each index in the snapshot. */
+    pub fn indices(&self) -> Iter<String, IndexSnapshotStatus> {
+        self.indices.iter()
+    }
+
+    /** Get the status of an index in the snapshot. */
+    pub fn index(&self, index: &str) -> Option<&IndexSnapshotStatus> {
+        self.indices.get(index)
+    }
+}
+
+/** The status of a single index in a snapshot. */
+#[derive(Deserialize, Debug)]
+pub struct IndexSnapshotStatus {
+    shards_stats: ShardsStats,
+    stats: SnapshotStats,
+    #[serde(default)] shards: BTreeMap<u32, ShardSnapshotStatus>,
+}
+
+impl IndexSnapshotStatus {
+    /** The number of shards in each stage of the snapshot for this index. */
+    pub fn shards_stats(&self) -> &ShardsStats {
+        &self.shards_stats
+    }
+
+    /** The progress of the snapshot across all shards for this index. */
+    pub fn stats(&self) -> &SnapshotStats {
+        &self.stats
+    }
+
+    /** The status of each shard in the form `(shard, status)`. */
+    pub fn shards(&self) -> Iter<u32, ShardSnapshotStatus> {
+        self.shards.iter()
+    }
+
+    /** Get the status of a shard. */
+    pub fn shard(&self, shard: u32) -> Option<&ShardSnapshotStatus> {
+        self.shards.get(&shard)
+    }
+}
+
+/** The status of a single shard in a snapshot. */
+#[derive(Deserialize, Debug)]
+pub struct ShardSnapshotStatus {
+    stage: String,
+    stats: SnapshotStats,
+    node: Option<String>,
+    reason: Option<String>,
+}
+
+impl ShardSnapshotStatus {
+    /** The stage of the shard, like `STARTED` or `DONE`. */
+    pub fn stage(&self) -> &str {
+        &self.stage
+    }
+
+    /** The progress of the snapshot for this shard. */
+    pub fn stats(&self) -> &SnapshotStats {
+        &self.stats
+    }
+
+    /** The id of the node that's snapshotting the shard. */
+    pub fn node(&self) -> Option<&str> {
+        self.node.as_ref().map(|node| node.as_ref())
+    }
+
+    /** The reason the shard failed, if it did. */
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_ref().map(|reason| reason.as_ref())
+    }
+}
+
+/** The number of shards in each stage of a snapshot. */
+#[derive(Deserialize, Debug)]
+pub struct ShardsStats {
+    initializing: u32,
+    started: u32,
+    finalizing: u32,
+    done: u32,
+    failed: u32,
+    total: u32,
+}
+
+impl ShardsStats {
+    /** The number of shards that are initialising. */
+    pub fn initializing(&self) -> u32 {
+        self.initializing
+    }
+
+    /** The number of shards that are copying files. */
+    pub fn started(&self) -> u32 {
+        self.started
+    }
+
+    /** The number of shards that are finalising. */
+    pub fn finalizing(&self) -> u32 {
+        self.finalizing
+    }
+
+    /** The number of shards that are done. */
+    pub fn done(&self) -> u32 {
+        self.done
+    }
+
+    /** The number of shards that failed. */
+    pub fn failed(&self) -> u32 {
+        self.failed
+    }
+
+    /** The total number of shards. */
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+}
+
+/** The progress of copying files for a snapshot. */
+#[derive(Deserialize, Debug)]
+pub struct SnapshotStats {
+    number_of_files: u64,
+    processed_files: u64,
+    total_size_in_bytes: u64,
+    processed_size_in_bytes: u64,
+    start_time_in_millis: u64,
+    time_in_millis: u64,
+}
+
+impl SnapshotStats {
+    /** The number of files to copy. */
+    pub fn number_of_files(&self) -> u64 {
+        self.number_of_files
+    }
+
+    /** The number of files that have been copied. */
+    pub fn processed_files(&self) -> u64 {
+        self.processed_files
+    }
+
+    /** The total size of the files to copy. */
+    pub fn total_size_in_bytes(&self) -> u64 {
+        self.total_size_in_bytes
+    }
+
+    /** The size of the files that have been copied. */
+    pub fn processed_size_in_bytes(&self) -> u64 {
+        self.processed_size_in_bytes
+    }
+
+    /** When copying started, in milliseconds since the epoch. */
+    pub fn start_time_in_millis(&self) -> u64 {
+        self.start_time_in_millis
+    }
+
+    /** How long copying has taken, in milliseconds. */
+    pub fn time_in_millis(&self) -> u64 {
+        self.time_in_millis
+    }
+}
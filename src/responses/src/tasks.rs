@@ -0,0 +1,330 @@
+/*!
+Response types for requests that run as a [task](https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html).
+*/
+
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
+use std::collections::BTreeMap;
+use std::slice::Iter;
+use std::vec::IntoIter;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/**
+Response for a request that was started in the background as a task.
+
+Requests like delete by query return this response instead of waiting for completion when `wait_for_completion` is `false`.
+*/
+#[derive(Deserialize, Debug)]
+pub struct StartedTaskResponse {
+    task: String,
+}
+
+impl StartedTaskResponse {
+    /** The id of the task in the form `node_id:task_number`. */
+    pub fn task(&self) -> &str {
+        &self.task
+    }
+}
+
+impl IsOk for StartedTaskResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+Response for a [get task request](https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html).
+
+The response has two generic parameters:
+
+- `TResponse`: the response for the request the task is running, like a [`ReindexResponse`](../bulk_by_scroll/type.ReindexResponse.html).
+It's only available once the task has completed.
+- `TStatus`: the progress of the task while it's running, like a [`BulkByScrollStatus`](../bulk_by_scroll/struct.BulkByScrollStatus.html).
+
+# Examples
+
+Check whether a reindex task has completed:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# use elastic_responses::bulk_by_scroll::BulkByScrollStatus;
+# use elastic_responses::tasks::GetTaskResponse;
+# fn do_request() -> GetTaskResponse<ReindexResponse, BulkByScrollStatus> { unimplemented!() }
+# fn main() {
+let response: GetTaskResponse<ReindexResponse, BulkByScrollStatus> = do_request();
+
+match response.response() {
+    Some(response) => println!("reindexed {} documents", response.created()),
+    None => {
+        if let Some(status) = response.task().status() {
+            println!("processed {} of {} documents", status.processed(), status.total());
+        }
+    }
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+#[serde(bound(deserialize = "TResponse: DeserializeOwned, TStatus: DeserializeOwned"))]
+pub struct GetTaskResponse<TResponse, TStatus> {
+    #[serde(default)] completed: bool,
+    task: TaskInfo<TStatus>,
+    response: Option<TResponse>,
+    #[serde(default, deserialize_with = "deserialize_cause")] error: Option<ApiError>,
+}
+
+impl<TResponse, TStatus> GetTaskResponse<TResponse, TStatus> {
+    /** Whether or not the task has completed. */
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    /** Information about the task. */
+    pub fn task(&self) -> &TaskInfo<TStatus> {
+        &self.task
+    }
+
+    /**
+    The response for the request the task was running.
+
+    This will be `None` until the task has completed.
+    */
+    pub fn response(&self) -> Option<&TResponse> {
+        self.response.as_ref()
+    }
+
+    /** The error that caused the task to fail, if any. */
+    pub fn error(&self) -> Option<&ApiError> {
+        self.error.as_ref()
+    }
+
+    /** Take the response or error for the task, if it has completed. */
+    pub fn into_result(self) -> Option<Result<TResponse, ApiError>> {
+        match (self.response, self.error) {
+            (_, Some(error)) => Some(Err(error)),
+            (Some(response), None) => Some(Ok(response)),
+            (None, None) => None,
+        }
+    }
+}
+
+/**
+Information about a task.
+
+The `TStatus` generic parameter is the progress of the task, like a [`BulkByScrollStatus`](../bulk_by_scroll/struct.BulkByScrollStatus.html).
+Use a `Value` when the kind of task isn't known.
+*/
+#[derive(Deserialize, Debug)]
+#[serde(bound(deserialize = "TStatus: DeserializeOwned"))]
+pub struct TaskInfo<TStatus> {
+    node: String,
+    id: u64,
+    #[serde(rename = "type")] ty: String,
+    action: String,
+    status: Option<TStatus>,
+    description: Option<String>,
+    start_time_in_millis: u64,
+    running_time_in_nanos: u64,
+    cancellable: bool,
+    parent_task_id: Option<String>,
+}
+
+impl<TStatus> TaskInfo<TStatus> {
+    /** The id of the task in the form `node_id:task_number`. */
+    pub fn task_id(&self) -> String {
+        format!("{}:{}", self.node, self.id)
+    }
+
+    /** The id of the node the task is running on. */
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+
+    /** The number of the task on its node. */
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /** The type of the task, like `transport`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The action the task is running, like `indices:data/write/reindex`. */
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /**
+    The progress of the task.
+
+    Not all tasks report their progress.
+    */
+    pub fn status(&self) -> Option<&TStatus> {
+        self.status.as_ref()
+    }
+
+    /** A description of the request the task is running. */
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_ref().map(|description| description.as_ref())
+    }
+
+    /** The time the task started as milliseconds since the epoch. */
+    pub fn start_time_in_millis(&self) -> u64 {
+        self.start_time_in_millis
+    }
+
+    /** The time the task has been running for in nanoseconds. */
+    pub fn running_time_in_nanos(&self) -> u64 {
+        self.running_time_in_nanos
+    }
+
+    /** Whether or not the task can be cancelled. */
+    pub fn cancellable(&self) -> bool {
+        self.cancellable
+    }
+
+    /** The id of the task that started this one, if any. */
+    pub fn parent_task_id(&self) -> Option<&str> {
+        self.parent_task_id.as_ref().map(|id| id.as_ref())
+    }
+}
+
+/**
+Response for a [list tasks request](https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html).
+
+Tasks are returned for all nodes that responded.
+Nodes that failed to respond are returned as [`ApiError`](../error/enum.ApiError.html)s.
+
+# Examples
+
+Iterate through the tasks running in a cluster:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# use elastic_responses::tasks::ListTasksResponse;
+# fn do_request() -> ListTasksResponse<Value> { unimplemented!() }
+# fn main() {
+let response: ListTasksResponse<Value> = do_request();
+
+for task in response.iter() {
+    println!("{}: {} (running for {}ns)", task.task_id(), task.action(), task.running_time_in_nanos());
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+#[serde(bound(deserialize = "TStatus: DeserializeOwned"))]
+pub struct ListTasksResponse<TStatus> {
+    #[serde(rename = "nodes", default, deserialize_with = "deserialize_node_tasks")] tasks: Vec<TaskInfo<TStatus>>,
+    #[serde(default, deserialize_with = "deserialize_causes")] node_failures: Vec<ApiError>,
+}
+
+/**
+Response for a [cancel task request](https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html#_task_cancellation).
+
+The response contains the tasks that were cancelled.
+*/
+pub type CancelTasksResponse<TStatus> = ListTasksResponse<TStatus>;
+
+impl<TStatus> ListTasksResponse<TStatus> {
+    /** Returns `true` if all nodes responded. */
+    pub fn is_ok(&self) -> bool {
+        self.node_failures.is_empty()
+    }
+
+    /**
+    Iterate through the tasks.
+
+    To move out of the tasks in a `ListTasksResponse` instead of borrowing them, call `into_iter`.
+    */
+    pub fn iter(&self) -> Tasks<TStatus> {
+        Tasks(self.tasks.iter())
+    }
+
+    /** Iterate through the errors for nodes that failed to respond. */
+    pub fn node_failures(&self) -> Iter<ApiError> {
+        self.node_failures.iter()
+    }
+}
+
+impl<TStatus> IntoIterator for ListTasksResponse<TStatus> {
+    type Item = TaskInfo<TStatus>;
+    type IntoIter = IntoTasks<TStatus>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoTasks(self.tasks.into_iter())
+    }
+}
+
+/** A borrowing iterator over tasks. */
+pub struct Tasks<'a, TStatus: 'a>(Iter<'a, TaskInfo<TStatus>>);
+
+impl<'a, TStatus: 'a> Iterator for Tasks<'a, TStatus> {
+    type Item = &'a TaskInfo<TStatus>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/** An owning iterator over tasks. */
+pub struct IntoTasks<TStatus>(IntoIter<TaskInfo<TStatus>>);
+
+impl<TStatus> Iterator for IntoTasks<TStatus> {
+    type Item = TaskInfo<TStatus>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "TStatus: DeserializeOwned"))]
+struct NodeTasks<TStatus> {
+    #[serde(default)] tasks: BTreeMap<String, TaskInfo<TStatus>>,
+}
+
+fn deserialize_node_tasks<'de, D, TStatus>(deserializer: D) -> Result<Vec<TaskInfo<TStatus>>, D::Error>
+where
+    D: Deserializer<'de>,
+    TStatus: DeserializeOwned,
+{
+    let nodes = BTreeMap::<String, NodeTasks<TStatus>>::deserialize(deserializer)?;
+
+    Ok(nodes
+        .into_iter()
+        .flat_map(|(_, node)| node.tasks.into_iter().map(|(_, task)| task))
+        .collect())
+}
+
+impl<TStatus> IsOk for ListTasksResponse<TStatus>
+where
+    TStatus: DeserializeOwned,
+{
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+impl<TResponse, TStatus> IsOk for GetTaskResponse<TResponse, TStatus>
+where
+    TResponse: DeserializeOwned,
+    TStatus: DeserializeOwned,
+{
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
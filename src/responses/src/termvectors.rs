@@ -0,0 +1,284 @@
+/*!
+Response types for a [term vectors request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html) and a [multi term vectors request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-termvectors.html).
+*/
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::collections::btree_map::{BTreeMap, Iter as MapIter};
+use std::slice::Iter;
+use std::vec::IntoIter;
+
+/**
+Response for a [term vectors request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html).
+
+The response maps each field in the document to the terms it contains.
+The terms map each term to its frequencies and the tokens it was produced from.
+
+# Examples
+
+Print the frequencies for each term in a field:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> TermvectorsResponse { unimplemented!() }
+# fn main() {
+let response: TermvectorsResponse = do_request();
+
+if let Some(field) = response.field("title") {
+    for (term, info) in field.iter() {
+        println!("{}: {} in the document, {:?} in the index", term, info.term_freq(), info.doc_freq());
+    }
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct TermvectorsResponse {
+    #[serde(rename = "_index")] index: String,
+    #[serde(rename = "_type")] ty: String,
+    #[serde(rename = "_id")] id: Option<String>,
+    #[serde(rename = "_version")] version: Option<u32>,
+    #[serde(default)] found: bool,
+    #[serde(default)] term_vectors: BTreeMap<String, FieldTerms>,
+}
+
+impl TermvectorsResponse {
+    /** Whether or not a matching document was found. */
+    pub fn found(&self) -> bool {
+        self.found
+    }
+
+    /** The index for the document. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The type of the document. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /**
+    The id of the document.
+
+    Artificial documents don't have an id.
+    */
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_ref().map(|id| id.as_ref())
+    }
+
+    /** The version of the document. */
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+
+    /** Get the terms for a field. */
+    pub fn field(&self, field: &str) -> Option<&FieldTerms> {
+        self.term_vectors.get(field)
+    }
+
+    /** The terms for each field in the document. */
+    pub fn fields(&self) -> &BTreeMap<String, FieldTerms> {
+        &self.term_vectors
+    }
+
+    /** Iterate through each field in the form `(field, terms)`. */
+    pub fn iter(&self) -> MapIter<String, FieldTerms> {
+        self.term_vectors.iter()
+    }
+}
+
+impl IsOk for TermvectorsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+Response for a [multi term vectors request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-termvectors.html).
+
+The response contains a term vectors response for each requested document, in the order they were requested.
+
+# Examples
+
+Print the fields for each document:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> MtermvectorsResponse { unimplemented!() }
+# fn main() {
+let response: MtermvectorsResponse = do_request();
+
+for doc in response {
+    let fields: Vec<_> = doc.fields().keys().collect();
+
+    println!("{:?}: {:?}", doc.id(), fields);
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct MtermvectorsResponse {
+    docs: Vec<TermvectorsResponse>,
+}
+
+impl MtermvectorsResponse {
+    /** Iterate through the term vectors for each document. */
+    pub fn iter(&self) -> Iter<TermvectorsResponse> {
+        self.docs.iter()
+    }
+}
+
+impl IntoIterator for MtermvectorsResponse {
+    type Item = TermvectorsResponse;
+    type IntoIter = IntoIter<TermvectorsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.docs.into_iter()
+    }
+}
+
+impl IsOk for MtermvectorsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/** The terms for a single field. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct FieldTerms {
+    field_statistics: Option<FieldStatistics>,
+    #[serde(default)] terms: BTreeMap<String, TermInfo>,
+}
+
+impl FieldTerms {
+    /** Statistics for the field across the index, if the request was made with `field_statistics`. */
+    pub fn field_statistics(&self) -> Option<&FieldStatistics> {
+        self.field_statistics.as_ref()
+    }
+
+    /** Get the info for a term. */
+    pub fn term(&self, term: &str) -> Option<&TermInfo> {
+        self.terms.get(term)
+    }
+
+    /** The info for each term in the field. */
+    pub fn terms(&self) -> &BTreeMap<String, TermInfo> {
+        &self.terms
+    }
+
+    /** Iterate through each term in the form `(term, info)`. */
+    pub fn iter(&self) -> MapIter<String, TermInfo> {
+        self.terms.iter()
+    }
+}
+
+/**
+Statistics for a field across the index.
+
+Values are `-1` if the statistic isn't available for the field.
+*/
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FieldStatistics {
+    sum_doc_freq: i64,
+    doc_count: i64,
+    sum_ttf: i64,
+}
+
+impl FieldStatistics {
+    /** The sum of the document frequencies for all terms in the field. */
+    pub fn sum_doc_freq(&self) -> i64 {
+        self.sum_doc_freq
+    }
+
+    /** The number of documents that contain at least one term in the field. */
+    pub fn doc_count(&self) -> i64 {
+        self.doc_count
+    }
+
+    /** The sum of the total term frequencies for all terms in the field. */
+    pub fn sum_ttf(&self) -> i64 {
+        self.sum_ttf
+    }
+}
+
+/** The frequencies and tokens for a single term. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TermInfo {
+    term_freq: u32,
+    doc_freq: Option<i64>,
+    ttf: Option<i64>,
+    score: Option<f32>,
+    #[serde(default)] tokens: Vec<TermToken>,
+}
+
+impl TermInfo {
+    /** The number of times the term appears in the field of this document. */
+    pub fn term_freq(&self) -> u32 {
+        self.term_freq
+    }
+
+    /** The number of documents in the index that contain the term, if the request was made with `term_statistics`. */
+    pub fn doc_freq(&self) -> Option<i64> {
+        self.doc_freq
+    }
+
+    /** The number of times the term appears in the index, if the request was made with `term_statistics`. */
+    pub fn ttf(&self) -> Option<i64> {
+        self.ttf
+    }
+
+    /** The score of the term, if the request was made with a `filter`. */
+    pub fn score(&self) -> Option<f32> {
+        self.score
+    }
+
+    /** The tokens the term was produced from. */
+    pub fn tokens(&self) -> &[TermToken] {
+        &self.tokens
+    }
+}
+
+/**
+A single occurrence of a term in a field.
+
+Each value is only returned if the request was made with the corresponding option.
+*/
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TermToken {
+    position: Option<u32>,
+    start_offset: Option<u32>,
+    end_offset: Option<u32>,
+    payload: Option<String>,
+}
+
+impl TermToken {
+    /** The position of the token, if the request was made with `positions`. */
+    pub fn position(&self) -> Option<u32> {
+        self.position
+    }
+
+    /** The offset of the first character of the token, if the request was made with `offsets`. */
+    pub fn start_offset(&self) -> Option<u32> {
+        self.start_offset
+    }
+
+    /** The offset after the last character of the token, if the request was made with `offsets`. */
+    pub fn end_offset(&self) -> Option<u32> {
+        self.end_offset
+    }
+
+    /** The base64 encoded payload of the token, if the request was made with `payloads`. */
+    pub fn payload(&self) -> Option<&str> {
+        self.payload.as_ref().map(|payload| payload.as_ref())
+    }
+}
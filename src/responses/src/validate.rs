@@ -0,0 +1,115 @@
+/*!
+Response types for a [validate query request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html).
+*/
+
+use common::Shards;
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::slice::Iter;
+
+/**
+Response for a [validate query request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html).
+
+The response contains whether or not the query is valid.
+If the request was made with `explain` or `rewrite` then the response also contains an explanation for each index or shard.
+
+# Examples
+
+Print why a query isn't valid:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::*;
+# fn do_request() -> ValidateQueryResponse { unimplemented!() }
+# fn main() {
+let response: ValidateQueryResponse = do_request();
+
+if !response.valid() {
+    for explanation in response.iter() {
+        if let Some(error) = explanation.error() {
+            println!("invalid query on {}: {}", explanation.index(), error);
+        }
+    }
+}
+# }
+```
+*/
+#[derive(Deserialize, Debug)]
+pub struct ValidateQueryResponse {
+    valid: bool,
+    #[serde(rename = "_shards")] shards: Option<Shards>,
+    #[serde(default)] explanations: Vec<QueryExplanation>,
+}
+
+impl ValidateQueryResponse {
+    /** Whether or not the query is valid. */
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> Option<&Shards> {
+        self.shards.as_ref()
+    }
+
+    /** The explanations for each index or shard, if the request was made with `explain` or `rewrite`. */
+    pub fn explanations(&self) -> &[QueryExplanation] {
+        &self.explanations
+    }
+
+    /** Iterate through the explanations for each index or shard. */
+    pub fn iter(&self) -> Iter<QueryExplanation> {
+        self.explanations.iter()
+    }
+}
+
+impl IsOk for ValidateQueryResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/** The explanation for a query on a single index or shard. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct QueryExplanation {
+    index: String,
+    shard: Option<u32>,
+    valid: bool,
+    explanation: Option<String>,
+    error: Option<String>,
+}
+
+impl QueryExplanation {
+    /** The index the query was validated against. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The shard the query was validated against, if the request was made with `all_shards`. */
+    pub fn shard(&self) -> Option<u32> {
+        self.shard
+    }
+
+    /** Whether or not the query is valid for this index or shard. */
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    /**
+    The query as it will be executed.
+
+    If the request was made with `rewrite` then this is the rewritten query.
+    */
+    pub fn explanation(&self) -> Option<&str> {
+        self.explanation.as_ref().map(|explanation| explanation.as_ref())
+    }
+
+    /** The reason the query isn't valid. */
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_ref().map(|error| error.as_ref())
+    }
+}
@@ -0,0 +1,43 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_get_aliases_response() {
+    let f = load_file("tests/samples/get_aliases.json");
+    let deserialized = parse::<GetAliasesResponse>().from_reader(200, f).unwrap();
+
+    let aliases: Vec<_> = deserialized.iter().map(|(index, alias, _)| (index, alias)).collect();
+
+    let expected = vec![
+        ("logs-2017-09", "logs"),
+        ("logs-2017-09", "logs-errors"),
+        ("logs-2017-10", "logs"),
+    ];
+
+    assert_eq!(expected, aliases);
+}
+
+#[test]
+fn success_parse_get_aliases_lookups() {
+    let f = load_file("tests/samples/get_aliases.json");
+    let deserialized = parse::<GetAliasesResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(vec!["logs-2017-09", "logs-2017-10"], deserialized.indices_for("logs"));
+    assert_eq!(vec!["logs", "logs-errors"], deserialized.aliases_for("logs-2017-09"));
+    assert!(deserialized.aliases_for("metrics").is_empty());
+}
+
+#[test]
+fn success_parse_get_aliases_info() {
+    let f = load_file("tests/samples/get_aliases.json");
+    let deserialized = parse::<GetAliasesResponse>().from_reader(200, f).unwrap();
+
+    let info = deserialized.get("logs-2017-09", "logs-errors").unwrap();
+
+    assert_eq!(Some(&json!({ "term": { "level": "error" } })), info.filter());
+    assert_eq!(Some("1"), info.index_routing());
+    assert_eq!(Some("1,2"), info.search_routing());
+}
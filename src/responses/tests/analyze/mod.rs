@@ -0,0 +1,60 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_analyze_response() {
+    let f = load_file("tests/samples/analyze.json");
+    let deserialized = parse::<AnalyzeResponse>().from_reader(200, f).unwrap();
+
+    let tokens: Vec<_> = deserialized.iter().map(|token| token.token()).collect();
+    assert_eq!(vec!["quick", "brown", "fox"], tokens);
+
+    let token = &deserialized.tokens()[0];
+
+    assert_eq!(4, token.start_offset());
+    assert_eq!(9, token.end_offset());
+    assert_eq!("<ALPHANUM>", token.ty());
+    assert_eq!(1, token.position());
+    assert_eq!(None, token.position_length());
+    assert!(deserialized.detail().is_none());
+}
+
+#[test]
+fn success_parse_analyze_explain_response() {
+    let f = load_file("tests/samples/analyze_explain.json");
+    let deserialized = parse::<AnalyzeResponse>().from_reader(200, f).unwrap();
+
+    let tokens: Vec<_> = deserialized.iter().map(|token| token.token()).collect();
+    assert_eq!(vec!["quick", "foxes"], tokens);
+
+    let detail = deserialized.detail().unwrap();
+
+    assert!(detail.custom_analyzer());
+    assert!(detail.analyzer().is_none());
+    assert_eq!("html_strip", detail.char_filters()[0].name());
+    assert_eq!("\nQuick Foxes", detail.char_filters()[0].filtered_text()[0]);
+
+    let tokenizer = detail.tokenizer().unwrap();
+
+    assert_eq!("standard", tokenizer.name());
+    assert_eq!("Quick", tokenizer.tokens()[0].token());
+    assert_eq!(Some(1), tokenizer.tokens()[0].position_length());
+    assert_eq!("lowercase", detail.token_filters()[0].name());
+}
+
+#[test]
+fn success_parse_analyze_explain_named_analyzer() {
+    let deserialized = parse::<AnalyzeResponse>()
+        .from_slice(
+            200,
+            br#"{ "detail": { "custom_analyzer": false, "analyzer": { "name": "standard", "tokens": [ { "token": "fox", "start_offset": 0, "end_offset": 3, "type": "<ALPHANUM>", "position": 0 } ] } } }"#,
+        )
+        .unwrap();
+
+    assert!(!deserialized.detail().unwrap().custom_analyzer());
+    assert_eq!("standard", deserialized.detail().unwrap().analyzer().unwrap().name());
+    assert_eq!("fox", deserialized.tokens()[0].token());
+}
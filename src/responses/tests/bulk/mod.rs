@@ -72,6 +72,16 @@ fn success_parse_with_errors() {
     assert_eq!(1, deserialized.iter().filter(Result::is_ok).count());
 }
 
+#[test]
+fn success_parse_with_errors_status() {
+    let f = load_file("tests/samples/bulk_error.json");
+    let deserialized = parse::<BulkErrorsResponse>().from_reader(200, f).unwrap();
+
+    let item = deserialized.iter().next().unwrap();
+
+    assert_eq!(409, item.status());
+}
+
 #[test]
 fn success_parse_with_errors_errors_only() {
     let f = load_file("tests/samples/bulk_error.json");
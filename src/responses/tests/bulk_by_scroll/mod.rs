@@ -0,0 +1,63 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_delete_by_query_response() {
+    let f = load_file("tests/samples/delete_by_query.json");
+    let deserialized = parse::<DeleteByQueryResponse>().from_reader(409, f).unwrap();
+
+    assert!(!deserialized.is_ok());
+    assert_eq!(119, deserialized.total());
+    assert_eq!(118, deserialized.deleted());
+    assert_eq!(0, deserialized.updated());
+    assert_eq!(1, deserialized.batches());
+    assert_eq!(1, deserialized.version_conflicts());
+}
+
+#[test]
+fn success_parse_delete_by_query_failures() {
+    let f = load_file("tests/samples/delete_by_query.json");
+    let deserialized = parse::<DeleteByQueryResponse>().from_reader(409, f).unwrap();
+
+    let failure = deserialized.failures().next().unwrap();
+
+    assert_eq!(Some("testindex"), failure.index());
+    assert_eq!(Some("testtype"), failure.ty());
+    assert_eq!(Some("1"), failure.id());
+    assert_eq!(Some(409), failure.status());
+
+    let valid = match failure.cause() {
+        Some(&ApiError::Other(ref cause)) => cause.get("type").and_then(|ty| ty.as_str()) == Some("version_conflict_engine_exception"),
+        _ => false,
+    };
+
+    assert!(valid);
+}
+
+#[test]
+fn success_parse_update_by_query_response() {
+    let f = load_file("tests/samples/update_by_query.json");
+    let deserialized = parse::<UpdateByQueryResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.is_ok());
+    assert_eq!(9, deserialized.updated());
+    assert_eq!(1, deserialized.noops());
+    assert_eq!(0, deserialized.failures().count());
+}
+
+#[test]
+fn error_parse_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<DeleteByQueryResponse>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
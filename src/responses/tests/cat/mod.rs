@@ -0,0 +1,226 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_cat_indices_text() {
+    let f = load_file("tests/samples/cat_indices.txt");
+    let deserialized = parse::<CatIndicesResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(3, deserialized.len());
+
+    let rows: Vec<_> = deserialized.iter().collect();
+
+    assert_eq!(Some(ClusterHealthStatus::Yellow), rows[0].health());
+    assert_eq!("open", rows[0].status());
+    assert_eq!("twitter", rows[0].index());
+    assert_eq!(Some("u8FNjxh8Rfy_awN11oDKYQ"), rows[0].uuid());
+    assert_eq!(Some(1), rows[0].primaries());
+    assert_eq!(Some(1), rows[0].replicas());
+    assert_eq!(Some(1200), rows[0].docs_count());
+    assert_eq!(Some(0), rows[0].docs_deleted());
+    assert_eq!(Some("88.1kb"), rows[0].store_size());
+    assert_eq!(Some("88.1kb"), rows[0].primary_store_size());
+
+    assert_eq!(Some(5), rows[1].primaries());
+    assert_eq!(Some("260b"), rows[1].store_size());
+
+    assert_eq!(None, rows[2].health());
+    assert_eq!("close", rows[2].status());
+    assert_eq!("archive", rows[2].index());
+    assert_eq!(None, rows[2].primaries());
+    assert_eq!(None, rows[2].docs_count());
+    assert_eq!(None, rows[2].store_size());
+}
+
+#[test]
+fn success_parse_cat_indices_json() {
+    let f = load_file("tests/samples/cat_indices.json");
+    let deserialized = parse::<CatIndicesResponse>().from_reader(200, f).unwrap();
+
+    let rows: Vec<_> = deserialized.iter().collect();
+
+    assert_eq!(2, rows.len());
+    assert_eq!(Some(ClusterHealthStatus::Yellow), rows[0].health());
+    assert_eq!(Some(1200), rows[0].docs_count());
+    assert_eq!("88.1kb", rows[0].store_size().unwrap());
+    assert_eq!(None, rows[1].health());
+    assert_eq!(None, rows[1].docs_count());
+}
+
+#[test]
+fn success_parse_cat_empty() {
+    let deserialized = parse::<CatIndicesResponse>().from_slice(200, b"").unwrap();
+    assert!(deserialized.is_empty());
+
+    let deserialized = parse::<CatIndicesResponse>().from_slice(200, b"[]").unwrap();
+    assert!(deserialized.is_empty());
+}
+
+#[test]
+fn success_parse_cat_shards_text() {
+    let f = load_file("tests/samples/cat_shards.txt");
+    let deserialized = parse::<CatShardsResponse>().from_reader(200, f).unwrap();
+
+    let rows: Vec<_> = deserialized.iter().collect();
+
+    assert_eq!("twitter", rows[0].index());
+    assert_eq!(0, rows[0].shard());
+    assert!(rows[0].is_primary());
+    assert_eq!("STARTED", rows[0].state());
+    assert_eq!(Some(1200), rows[0].docs());
+    assert_eq!(Some("88.1kb"), rows[0].store());
+    assert_eq!(Some("127.0.0.1"), rows[0].ip());
+    assert_eq!(Some("H5dfFeA"), rows[0].node());
+
+    assert!(!rows[1].is_primary());
+    assert_eq!("UNASSIGNED", rows[1].state());
+    assert_eq!(None, rows[1].docs());
+    assert_eq!(None, rows[1].node());
+}
+
+#[test]
+fn success_parse_cat_nodes_text() {
+    let f = load_file("tests/samples/cat_nodes.txt");
+    let deserialized = parse::<CatNodesResponse>().from_reader(200, f).unwrap();
+
+    let rows: Vec<_> = deserialized.iter().collect();
+
+    assert_eq!("192.168.56.10", rows[0].ip());
+    assert_eq!(Some(9), rows[0].heap_percent());
+    assert_eq!(Some(78), rows[0].ram_percent());
+    assert_eq!(Some(22), rows[0].cpu());
+    assert_eq!(Some(1.80), rows[0].load_1m());
+    assert_eq!(Some(2.05), rows[0].load_5m());
+    assert_eq!(Some(1.91), rows[0].load_15m());
+    assert_eq!("mdi", rows[0].node_role());
+    assert!(rows[0].is_master());
+    assert_eq!("H5dfFeA", rows[0].name());
+
+    assert!(!rows[1].is_master());
+    assert_eq!("data node 1", rows[1].name());
+
+    assert_eq!(Some(12), rows[2].heap_percent());
+    assert_eq!(None, rows[2].cpu());
+    assert_eq!(None, rows[2].load_1m());
+    assert_eq!(None, rows[2].load_15m());
+    assert_eq!("i", rows[2].node_role());
+    assert_eq!("ingest node", rows[2].name());
+}
+
+#[test]
+fn success_parse_cat_allocation_text() {
+    let f = load_file("tests/samples/cat_allocation.txt");
+    let deserialized = parse::<CatAllocationResponse>().from_reader(200, f).unwrap();
+
+    let rows: Vec<_> = deserialized.iter().collect();
+
+    assert_eq!(Some(5), rows[0].shards());
+    assert_eq!(Some("260b"), rows[0].disk_indices());
+    assert_eq!(Some("47.3gb"), rows[0].disk_used());
+    assert_eq!(Some("43.4gb"), rows[0].disk_avail());
+    assert_eq!(Some("100.7gb"), rows[0].disk_total());
+    assert_eq!(Some(46), rows[0].disk_percent());
+    assert_eq!(Some("127.0.0.1"), rows[0].host());
+    assert_eq!("H5dfFeA", rows[0].node());
+
+    assert_eq!(Some(5), rows[1].shards());
+    assert_eq!(None, rows[1].disk_used());
+    assert_eq!("UNASSIGNED", rows[1].node());
+}
+
+#[test]
+fn success_parse_cat_health_text() {
+    let f = load_file("tests/samples/cat_health.txt");
+    let deserialized = parse::<CatHealthResponse>().from_reader(200, f).unwrap();
+
+    let row = deserialized.iter().next().unwrap();
+
+    assert_eq!(1475871424, row.epoch());
+    assert_eq!("16:17:04", row.timestamp());
+    assert_eq!("elasticsearch", row.cluster());
+    assert_eq!(ClusterHealthStatus::Yellow, row.status());
+    assert_eq!(1, row.node_total());
+    assert_eq!(1, row.node_data());
+    assert_eq!(5, row.shards());
+    assert_eq!(5, row.primaries());
+    assert_eq!(0, row.relocating());
+    assert_eq!(0, row.initializing());
+    assert_eq!(5, row.unassigned());
+    assert_eq!(Some(0), row.pending_tasks());
+    assert_eq!(None, row.max_task_wait_time());
+    assert_eq!(Some("50.0%"), row.active_shards_percent());
+}
+
+#[test]
+fn success_parse_cat_aliases_text() {
+    let f = load_file("tests/samples/cat_aliases.txt");
+    let deserialized = parse::<CatAliasesResponse>().from_reader(200, f).unwrap();
+
+    let rows: Vec<_> = deserialized.iter().collect();
+
+    assert_eq!("alias1", rows[0].alias());
+    assert_eq!("test1", rows[0].index());
+    assert_eq!(None, rows[0].filter());
+    assert_eq!(None, rows[0].routing_index());
+
+    assert_eq!(Some("*"), rows[1].filter());
+
+    assert_eq!(Some("1"), rows[2].routing_index());
+    assert_eq!(Some("1,2"), rows[2].routing_search());
+}
+
+#[test]
+fn success_parse_cat_segments_text() {
+    let f = load_file("tests/samples/cat_segments.txt");
+    let deserialized = parse::<CatSegmentsResponse>().from_reader(200, f).unwrap();
+
+    let rows: Vec<_> = deserialized.iter().collect();
+
+    assert_eq!("test", rows[0].index());
+    assert_eq!(0, rows[0].shard());
+    assert!(rows[0].is_primary());
+    assert_eq!("127.0.0.1", rows[0].ip());
+    assert_eq!("_0", rows[0].segment());
+    assert_eq!(0, rows[0].generation());
+    assert_eq!(1, rows[0].docs_count());
+    assert_eq!(0, rows[0].docs_deleted());
+    assert_eq!("3kb", rows[0].size());
+    assert_eq!(2042, rows[0].size_memory());
+    assert!(!rows[0].committed());
+    assert!(rows[0].searchable());
+    assert_eq!("6.5.1", rows[0].version());
+    assert_eq!(Some(true), rows[0].compound());
+
+    assert!(rows[1].committed());
+}
+
+#[test]
+fn success_parse_cat_thread_pool_text() {
+    let f = load_file("tests/samples/cat_thread_pool.txt");
+    let deserialized = parse::<CatThreadPoolResponse>().from_reader(200, f).unwrap();
+
+    let rows: Vec<_> = deserialized.iter().collect();
+
+    assert_eq!("H5dfFeA", rows[1].node_name());
+    assert_eq!("search", rows[1].name());
+    assert_eq!(2, rows[1].active());
+    assert_eq!(12, rows[1].queue());
+    assert_eq!(1337, rows[1].rejected());
+}
+
+#[test]
+fn error_parse_cat_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<CatIndicesResponse>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
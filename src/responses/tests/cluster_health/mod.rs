@@ -0,0 +1,35 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_cluster_health_response() {
+    let f = load_file("tests/samples/cluster_health.json");
+    let deserialized = parse::<ClusterHealthResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!("testcluster", deserialized.cluster_name());
+    assert_eq!(ClusterHealthStatus::Yellow, deserialized.status());
+    assert!(!deserialized.timed_out());
+    assert_eq!(1, deserialized.number_of_nodes());
+    assert_eq!(5, deserialized.active_primary_shards());
+    assert_eq!(5, deserialized.unassigned_shards());
+}
+
+#[test]
+fn success_parse_cluster_health_timed_out() {
+    let f = load_file("tests/samples/cluster_health_timed_out.json");
+    let deserialized = parse::<ClusterHealthResponse>().from_reader(408, f).unwrap();
+
+    assert_eq!(ClusterHealthStatus::Red, deserialized.status());
+    assert!(deserialized.timed_out());
+    assert_eq!(2, deserialized.initializing_shards());
+}
+
+#[test]
+fn cluster_health_status_is_ordered() {
+    assert!(ClusterHealthStatus::Green > ClusterHealthStatus::Yellow);
+    assert!(ClusterHealthStatus::Yellow > ClusterHealthStatus::Red);
+    assert_eq!("yellow", ClusterHealthStatus::Yellow.to_string());
+}
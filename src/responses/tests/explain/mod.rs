@@ -0,0 +1,74 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use serde_json::Value;
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[derive(Deserialize, Debug)]
+struct Tweet {
+    user: String,
+    message: String,
+}
+
+#[test]
+fn success_parse_explain_response() {
+    let f = load_file("tests/samples/explain.json");
+    let deserialized = parse::<ExplainResponse<Tweet>>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.matched());
+    assert_eq!("twitter", deserialized.index());
+    assert_eq!("tweet", deserialized.ty());
+    assert_eq!("0", deserialized.id());
+    assert_eq!("kimchy", deserialized.document().unwrap().user);
+
+    let explanation = deserialized.explanation().unwrap();
+
+    assert_eq!(1.6943599, explanation.value());
+    assert!(explanation.description().starts_with("weight(message:elasticsearch"));
+
+    let idf = &explanation.details()[0].details()[0];
+
+    assert_eq!(1.3862944, idf.value());
+    assert_eq!(vec!["docFreq", "docCount"], idf.iter().map(|detail| detail.description()).collect::<Vec<_>>());
+    assert!(idf.details()[0].details().is_empty());
+
+    assert_eq!("trying out Elasticsearch", deserialized.into_document().unwrap().message);
+}
+
+#[test]
+fn success_parse_explain_without_source() {
+    let deserialized = parse::<ExplainResponse<Value>>()
+        .from_slice(
+            200,
+            br#"{ "_index": "twitter", "_type": "tweet", "_id": "1", "matched": false, "explanation": { "value": 0.0, "description": "no matching term" } }"#,
+        )
+        .unwrap();
+
+    assert!(!deserialized.matched());
+    assert!(deserialized.document().is_none());
+    assert!(deserialized.explanation().unwrap().details().is_empty());
+}
+
+#[test]
+fn success_parse_explain_not_found() {
+    let f = load_file("tests/samples/explain_not_found.json");
+    let deserialized = parse::<ExplainResponse<Value>>().from_reader(404, f).unwrap();
+
+    assert!(!deserialized.matched());
+    assert!(deserialized.explanation().is_none());
+}
+
+#[test]
+fn error_parse_explain_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<ExplainResponse<Value>>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
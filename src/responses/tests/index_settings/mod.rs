@@ -0,0 +1,37 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use serde_json::Value;
+use elastic_responses::*;
+use load_file;
+
+#[derive(Deserialize, Debug)]
+struct MySettings {
+    number_of_shards: String,
+    refresh_interval: String,
+}
+
+#[test]
+fn success_parse_get_index_settings_response() {
+    let f = load_file("tests/samples/get_index_settings.json");
+    let deserialized = parse::<GetIndexSettingsResponse<Value>>().from_reader(200, f).unwrap();
+
+    let indices: Vec<_> = deserialized.iter().map(|(index, _)| index).collect();
+    assert_eq!(vec!["myindex"], indices);
+
+    let settings = deserialized.get("myindex").unwrap();
+
+    assert_eq!(json!("1"), settings["number_of_replicas"]);
+    assert_eq!(json!("stemmer"), settings["analysis"]["filter"]["english_stemmer"]["type"]);
+}
+
+#[test]
+fn success_parse_get_index_settings_typed() {
+    let f = load_file("tests/samples/get_index_settings.json");
+    let deserialized = parse::<GetIndexSettingsResponse<MySettings>>().from_reader(200, f).unwrap();
+
+    let settings = deserialized.get("myindex").unwrap();
+
+    assert_eq!("5", settings.number_of_shards);
+    assert_eq!("30s", settings.refresh_interval);
+}
@@ -0,0 +1,29 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_get_index_templates_response() {
+    let f = load_file("tests/samples/get_index_templates.json");
+    let deserialized = parse::<GetIndexTemplatesResponse>().from_reader(200, f).unwrap();
+
+    let names: Vec<_> = deserialized.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(vec!["logs", "metrics"], names);
+
+    let template = deserialized.get("logs").unwrap();
+
+    assert_eq!(1, template.order());
+    assert_eq!("logs-*", template.template());
+    assert_eq!(Some(&json!({ "number_of_shards": "2" })), template.settings().get("index"));
+    assert!(template.mappings().contains_key("entry"));
+    assert!(template.aliases().contains_key("logs"));
+}
+
+#[test]
+fn success_parse_get_index_templates_not_found() {
+    let deserialized = parse::<GetIndexTemplatesResponse>().from_slice(404, b"{}").unwrap();
+
+    assert!(deserialized.iter().next().is_none());
+}
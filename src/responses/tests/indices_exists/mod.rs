@@ -16,3 +16,10 @@ fn success_parse_response_not_exists() {
 
     assert!(!deserialized.exists());
 }
+
+#[test]
+fn success_parse_alias_exists() {
+    let deserialized = parse::<AliasExistsResponse>().from_slice(200, b"").unwrap();
+
+    assert!(deserialized.exists());
+}
@@ -0,0 +1,90 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use serde_json::Value;
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_get_pipelines_response() {
+    let f = load_file("tests/samples/get_pipelines.json");
+    let deserialized = parse::<GetPipelinesResponse<Value>>().from_reader(200, f).unwrap();
+
+    let ids: Vec<_> = deserialized.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(vec!["logs", "my-pipeline-id"], ids);
+
+    let pipeline = deserialized.get("my-pipeline-id").unwrap();
+
+    assert_eq!(json!("describe pipeline"), pipeline["description"]);
+    assert_eq!(json!("foo"), pipeline["processors"][0]["set"]["field"]);
+}
+
+#[test]
+fn success_parse_get_pipelines_array_response() {
+    let f = load_file("tests/samples/get_pipelines_5x.json");
+    let deserialized = parse::<GetPipelinesResponse<Value>>().from_reader(200, f).unwrap();
+
+    let ids: Vec<_> = deserialized.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(vec!["my-pipeline-id"], ids);
+
+    let pipeline = deserialized.get("my-pipeline-id").unwrap();
+
+    assert_eq!(json!("describe pipeline"), pipeline["description"]);
+}
+
+#[test]
+fn success_parse_get_pipelines_not_found() {
+    let deserialized = parse::<GetPipelinesResponse<Value>>().from_slice(404, b"{}").unwrap();
+
+    assert!(deserialized.iter().next().is_none());
+}
+
+#[test]
+fn success_parse_simulate_pipeline_response() {
+    #[derive(Deserialize)]
+    struct Doc {
+        title: String,
+        status: String,
+    }
+
+    let f = load_file("tests/samples/simulate_pipeline.json");
+    let deserialized = parse::<SimulatePipelineResponse>().from_reader(200, f).unwrap();
+
+    let docs: Vec<_> = deserialized.iter().collect();
+    assert_eq!(2, docs.len());
+
+    let failed = docs[0];
+    let results = failed.processor_results();
+
+    assert_eq!(Some("set_status"), results[0].tag());
+    assert_eq!("mytype", results[0].doc().unwrap().ty());
+    assert!(results[0].error().is_none());
+    assert_eq!(Some("parse_date"), results[1].tag());
+    assert!(results[1].doc().is_none());
+    assert_eq!(Some(&json!("illegal_argument_exception")), failed.error().map(|error| &error["type"]));
+
+    let succeeded = docs[1];
+    let doc = succeeded.doc().unwrap();
+    let source: Doc = doc.source_as().unwrap();
+
+    assert!(succeeded.error().is_none());
+    assert_eq!(json!("2017-08-28T00:00:00.000Z"), doc.source()["timestamp"]);
+    assert_eq!("Another title", source.title);
+    assert_eq!("new", source.status);
+    assert_eq!(Some(&json!("2017-08-28T02:21:45.126Z")), doc.ingest().get("timestamp"));
+}
+
+#[test]
+fn success_parse_simulate_pipeline_not_verbose() {
+    let deserialized = parse::<SimulatePipelineResponse>()
+        .from_slice(
+            200,
+            br#"{ "docs": [ { "doc": { "_index": "_index", "_type": "mytype", "_id": "_id", "_source": { "status": "new" } } } ] }"#,
+        )
+        .unwrap();
+
+    let doc = deserialized.iter().next().unwrap();
+
+    assert!(doc.processor_results().is_empty());
+    assert_eq!(json!("new"), doc.doc().unwrap().source()["status"]);
+}
@@ -0,0 +1,96 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::mapping::{FieldMapping, MappingDifference};
+use load_file;
+
+#[test]
+fn success_parse_get_mapping_response() {
+    let f = load_file("tests/samples/get_mapping.json");
+    let deserialized = parse::<GetMappingResponse>().from_reader(200, f).unwrap();
+
+    let types: Vec<_> = deserialized.iter().map(|(index, ty, _)| (index, ty)).collect();
+    assert_eq!(vec![("myindex", "mytype")], types);
+
+    let mapping = deserialized.get("myindex", "mytype").unwrap();
+    let title = &mapping.properties()["title"];
+
+    assert_eq!(Some("object"), mapping.ty());
+    assert_eq!(Some("text"), title.ty());
+    assert_eq!(Some(&json!("english")), title.params().get("analyzer"));
+    assert_eq!(Some("keyword"), title.fields()["raw"].ty());
+    assert_eq!(Some("object"), mapping.properties()["author"].ty());
+}
+
+#[test]
+fn success_diff_same_mapping() {
+    let f = load_file("tests/samples/get_mapping.json");
+    let deserialized = parse::<GetMappingResponse>().from_reader(200, f).unwrap();
+
+    let mapping = deserialized.get("myindex", "mytype").unwrap();
+
+    assert!(mapping.diff(mapping).is_empty());
+}
+
+#[test]
+fn success_diff_drifted_mapping() {
+    let f = load_file("tests/samples/get_mapping.json");
+    let deserialized = parse::<GetMappingResponse>().from_reader(200, f).unwrap();
+
+    let mapping = deserialized.get("myindex", "mytype").unwrap();
+
+    let expected: FieldMapping = serde_json::from_value(json!({
+        "properties": {
+            "title": {
+                "type": "text",
+                "analyzer": "standard",
+                "fields": {
+                    "raw": {
+                        "type": "keyword"
+                    }
+                }
+            },
+            "timestamp": {
+                "type": "long"
+            },
+            "author": {
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "keyword"
+                    },
+                    "email": {
+                        "type": "keyword"
+                    }
+                }
+            },
+            "tags": {
+                "type": "keyword",
+                "index": false
+            }
+        }
+    })).unwrap();
+
+    let expected_differences = vec![
+        MappingDifference::MissingField {
+            path: "author.email".to_owned(),
+        },
+        MappingDifference::MissingField {
+            path: "tags".to_owned(),
+        },
+        MappingDifference::TypeMismatch {
+            path: "timestamp".to_owned(),
+            expected: Some("long".to_owned()),
+            actual: Some("date".to_owned()),
+        },
+        MappingDifference::ParamMismatch {
+            path: "title".to_owned(),
+            param: "analyzer".to_owned(),
+            expected: json!("standard"),
+            actual: Some(json!("english")),
+        },
+    ];
+
+    assert_eq!(expected_differences, mapping.diff(&expected));
+}
@@ -0,0 +1,66 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use serde_json::Value;
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_mget_response() {
+    let f = load_file("tests/samples/mget.json");
+    let deserialized = parse::<MgetResponse<Value>>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.is_err());
+    assert_eq!(3, deserialized.iter().count());
+}
+
+#[test]
+fn success_parse_mget_found_and_not_found() {
+    let f = load_file("tests/samples/mget.json");
+    let deserialized = parse::<MgetResponse<Value>>().from_reader(200, f).unwrap();
+
+    let found: Vec<_> = deserialized
+        .iter()
+        .filter_map(Result::ok)
+        .map(|doc| (doc.id(), doc.found()))
+        .collect();
+
+    assert_eq!(vec![("1", true), ("100", false)], found);
+}
+
+#[test]
+fn success_parse_mget_into_documents() {
+    let f = load_file("tests/samples/mget.json");
+    let deserialized = parse::<MgetResponse<Value>>().from_reader(200, f).unwrap();
+
+    let docs: Vec<_> = deserialized
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter_map(GetResponse::into_document)
+        .collect();
+
+    assert_eq!(vec![json!({ "id": 1 })], docs);
+}
+
+#[test]
+fn success_parse_mget_error_item() {
+    let f = load_file("tests/samples/mget.json");
+    let deserialized = parse::<MgetResponse<Value>>().from_reader(200, f).unwrap();
+
+    let err = deserialized
+        .iter()
+        .filter_map(|doc| doc.err())
+        .next()
+        .unwrap();
+
+    assert_eq!("carrots", err.index());
+    assert_eq!("1", err.id());
+
+    let valid = match *err.error() {
+        ApiError::IndexNotFound { ref index } if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
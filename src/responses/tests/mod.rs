@@ -16,4 +16,23 @@ pub mod get;
 pub mod search;
 pub mod bulk;
 pub mod index;
-pub mod indices_exists;
\ No newline at end of file
+pub mod indices_exists;
+pub mod nodes_info;
+pub mod mget;
+pub mod msearch;
+pub mod count;
+pub mod bulk_by_scroll;
+pub mod tasks;
+pub mod aliases;
+pub mod index_templates;
+pub mod mapping;
+pub mod index_settings;
+pub mod cluster_health;
+pub mod snapshot;
+pub mod ingest;
+pub mod cat;
+pub mod analyze;
+pub mod explain;
+pub mod validate;
+pub mod termvectors;
+pub mod search_template;
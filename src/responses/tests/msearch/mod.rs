@@ -0,0 +1,48 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use serde_json::Value;
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_msearch_response() {
+    let f = load_file("tests/samples/msearch.json");
+    let deserialized = parse::<MsearchResponse<Value>>().from_reader(200, f).unwrap();
+
+    let totals: Vec<_> = deserialized
+        .iter()
+        .map(|res| res.ok().map(|res| res.total()))
+        .collect();
+
+    assert!(deserialized.is_err());
+    assert_eq!(vec![Some(1), None, Some(0)], totals);
+}
+
+#[test]
+fn success_parse_msearch_hits() {
+    let f = load_file("tests/samples/msearch.json");
+    let deserialized = parse::<MsearchResponse<Value>>().from_reader(200, f).unwrap();
+
+    let docs: Vec<_> = deserialized
+        .into_iter()
+        .filter_map(Result::ok)
+        .flat_map(|res| res.into_documents())
+        .collect();
+
+    assert_eq!(vec![json!({ "id": 1 })], docs);
+}
+
+#[test]
+fn success_parse_msearch_error() {
+    let f = load_file("tests/samples/msearch.json");
+    let deserialized = parse::<MsearchResponse<Value>>().from_reader(200, f).unwrap();
+
+    let valid = match deserialized.iter().nth(1) {
+        Some(Err(&ApiError::IndexNotFound { ref index })) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
@@ -0,0 +1,19 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_nodes_info_response() {
+    let f = load_file("tests/samples/nodes_info_http.json");
+    let deserialized = parse::<NodesInfoResponse>().from_reader(200, f).unwrap();
+
+    let addresses: Vec<_> = deserialized
+        .nodes()
+        .filter_map(|(_, node)| node.http_publish_address())
+        .collect();
+
+    assert_eq!(Some("elasticsearch"), deserialized.cluster_name());
+    assert_eq!(vec!["10.0.0.1:9200", "es-node-2/10.0.0.2:9200"], addresses);
+}
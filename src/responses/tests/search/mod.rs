@@ -73,6 +73,29 @@ fn success_parse_hits_bank_sample() {
     assert_eq!(deserialized.hits().into_iter().count(), 10);
 }
 
+#[test]
+fn success_parse_hits_scroll() {
+    let f = load_file("tests/samples/search_scroll.json");
+    let deserialized = parse::<SearchResponse<Value>>()
+        .from_reader(200, f)
+        .unwrap();
+
+    let ids: Vec<_> = deserialized.hits().map(|hit| hit.id()).collect();
+
+    assert!(deserialized.scroll_id().is_some());
+    assert_eq!(vec!["25", "44"], ids);
+}
+
+#[test]
+fn success_parse_hits_no_scroll() {
+    let f = load_file("tests/samples/search_hits_only.json");
+    let deserialized = parse::<SearchResponse<Value>>()
+        .from_reader(200, f)
+        .unwrap();
+
+    assert_eq!(None, deserialized.scroll_id());
+}
+
 #[test]
 fn success_aggs_when_not_present() {
     let f = load_file("tests/samples/search_hits_only.json");
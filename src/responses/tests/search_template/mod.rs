@@ -0,0 +1,51 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_render_search_template_response() {
+    let f = load_file("tests/samples/render_search_template.json");
+    let deserialized = parse::<RenderSearchTemplateResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(10, deserialized.template_output()["size"]);
+    assert_eq!("some title", deserialized.into_template_output()["query"]["match"]["title"]);
+}
+
+#[test]
+fn success_parse_get_search_template_response() {
+    let f = load_file("tests/samples/get_search_template.json");
+    let deserialized = parse::<GetSearchTemplateResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.found());
+    assert_eq!("title_search", deserialized.id());
+    assert_eq!(Some("mustache"), deserialized.lang());
+    assert_eq!(
+        Some("{\"query\":{\"match\":{\"title\":\"{{query_string}}\"}}}"),
+        deserialized.template().and_then(|template| template.as_str())
+    );
+}
+
+#[test]
+fn success_parse_get_search_template_script_response() {
+    let deserialized = parse::<GetSearchTemplateResponse>()
+        .from_slice(
+            200,
+            br#"{ "_id": "title_search", "found": true, "script": { "lang": "mustache", "code": "{\"query\":{}}" } }"#,
+        )
+        .unwrap();
+
+    assert_eq!(Some("mustache"), deserialized.lang());
+    assert_eq!(Some("{\"query\":{}}"), deserialized.template().and_then(|template| template.as_str()));
+}
+
+#[test]
+fn success_parse_get_search_template_not_found() {
+    let deserialized = parse::<GetSearchTemplateResponse>()
+        .from_slice(404, br#"{ "_id": "title_search", "found": false }"#)
+        .unwrap();
+
+    assert!(!deserialized.found());
+    assert!(deserialized.template().is_none());
+}
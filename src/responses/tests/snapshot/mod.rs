@@ -0,0 +1,94 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::snapshot::SnapshotState;
+use load_file;
+
+#[test]
+fn success_parse_get_repositories_response() {
+    let f = load_file("tests/samples/get_repositories.json");
+    let deserialized = parse::<GetRepositoriesResponse>().from_reader(200, f).unwrap();
+
+    let names: Vec<_> = deserialized.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(vec!["my_backup", "readonly"], names);
+
+    let repository = deserialized.get("my_backup").unwrap();
+
+    assert_eq!("fs", repository.ty());
+    assert_eq!(Some(&json!("/mount/backups/my_backup")), repository.settings().get("location"));
+}
+
+#[test]
+fn success_parse_get_repositories_not_found() {
+    let deserialized = parse::<GetRepositoriesResponse>().from_slice(404, b"{}").unwrap();
+
+    assert!(deserialized.iter().next().is_none());
+}
+
+#[test]
+fn success_parse_create_snapshot_accepted() {
+    let deserialized = parse::<CreateSnapshotResponse>().from_slice(200, br#"{ "accepted": true }"#).unwrap();
+
+    assert!(deserialized.accepted());
+    assert!(deserialized.snapshot().is_none());
+}
+
+#[test]
+fn success_parse_create_snapshot_completed() {
+    let f = load_file("tests/samples/create_snapshot.json");
+    let deserialized = parse::<CreateSnapshotResponse>().from_reader(200, f).unwrap();
+
+    let snapshot = deserialized.snapshot().unwrap();
+
+    assert!(deserialized.accepted());
+    assert_eq!("snapshot_1", snapshot.name());
+    assert_eq!(SnapshotState::Success, snapshot.state());
+    assert_eq!(&["index_1".to_owned(), "index_2".to_owned()], snapshot.indices());
+    assert_eq!(10, snapshot.shards().unwrap().successful());
+}
+
+#[test]
+fn success_parse_get_snapshots_response() {
+    let f = load_file("tests/samples/get_snapshots.json");
+    let deserialized = parse::<GetSnapshotsResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(2, deserialized.iter().count());
+
+    let snapshot = deserialized.get("snapshot_2").unwrap();
+
+    assert_eq!(SnapshotState::InProgress, snapshot.state());
+    assert_eq!(None, snapshot.end_time_in_millis());
+}
+
+#[test]
+fn success_parse_restore_snapshot_response() {
+    let f = load_file("tests/samples/restore_snapshot.json");
+    let deserialized = parse::<RestoreSnapshotResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.accepted());
+    assert_eq!(&["restored_index_1".to_owned()], deserialized.indices());
+    assert_eq!(5, deserialized.shards().unwrap().total());
+}
+
+#[test]
+fn success_parse_snapshot_status_response() {
+    let f = load_file("tests/samples/snapshot_status.json");
+    let deserialized = parse::<SnapshotStatusResponse>().from_reader(200, f).unwrap();
+
+    let snapshot = deserialized.get("snapshot_2").unwrap();
+
+    assert_eq!("my_backup", snapshot.repository());
+    assert_eq!("STARTED", snapshot.state());
+    assert_eq!(2, snapshot.shards_stats().total());
+
+    let index = snapshot.index("index_1").unwrap();
+    let stages: Vec<_> = index.shards().map(|(shard, status)| (*shard, status.stage())).collect();
+
+    assert_eq!(vec![(0, "DONE"), (1, "STARTED")], stages);
+
+    let shard = index.shard(1).unwrap();
+
+    assert_eq!(526, shard.stats().processed_size_in_bytes());
+    assert_eq!(Some("DbPcDTXlRoWZJcXjQykVxg"), shard.node());
+}
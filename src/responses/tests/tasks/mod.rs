@@ -0,0 +1,117 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::bulk_by_scroll::BulkByScrollStatus;
+use elastic_responses::error::*;
+use elastic_responses::tasks::{CancelTasksResponse, GetTaskResponse, ListTasksResponse};
+use load_file;
+
+#[test]
+fn success_parse_started_task_response() {
+    let f = load_file("tests/samples/started_task.json");
+    let deserialized = parse::<StartedTaskResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!("oTUltX4IQMOUUVeiohTt8A:12345", deserialized.task());
+}
+
+#[test]
+fn success_parse_running_task_response() {
+    let f = load_file("tests/samples/get_task_running.json");
+    let deserialized = parse::<GetTaskResponse<ReindexResponse, BulkByScrollStatus>>().from_reader(200, f).unwrap();
+
+    assert!(!deserialized.is_completed());
+    assert!(deserialized.response().is_none());
+    assert!(deserialized.into_result().is_none());
+}
+
+#[test]
+fn success_parse_running_task_status() {
+    let f = load_file("tests/samples/get_task_running.json");
+    let deserialized = parse::<GetTaskResponse<ReindexResponse, BulkByScrollStatus>>().from_reader(200, f).unwrap();
+
+    let task = deserialized.task();
+
+    assert_eq!("oTUltX4IQMOUUVeiohTt8A", task.node());
+    assert_eq!(12345, task.id());
+    assert_eq!("indices:data/write/reindex", task.action());
+
+    let status = task.status().unwrap();
+
+    assert_eq!(6154, status.total());
+    assert_eq!(3500, status.updated());
+    assert_eq!(3500, status.processed());
+    assert_eq!(4, status.batches());
+}
+
+#[test]
+fn success_parse_completed_task_response() {
+    let f = load_file("tests/samples/get_task_completed.json");
+    let deserialized = parse::<GetTaskResponse<ReindexResponse, BulkByScrollStatus>>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.is_completed());
+
+    let response = deserialized.into_result().unwrap().unwrap();
+
+    assert!(response.is_ok());
+    assert_eq!(6154, response.created());
+}
+
+#[test]
+fn success_parse_task_info() {
+    let f = load_file("tests/samples/get_task_running.json");
+    let deserialized = parse::<GetTaskResponse<ReindexResponse, Value>>().from_reader(200, f).unwrap();
+
+    let task = deserialized.task();
+
+    assert_eq!("oTUltX4IQMOUUVeiohTt8A:12345", task.task_id());
+    assert_eq!("transport", task.ty());
+    assert_eq!(Some("reindex from [source_index] to [dest_index]"), task.description());
+    assert_eq!(1505355617853, task.start_time_in_millis());
+    assert_eq!(1306224021, task.running_time_in_nanos());
+    assert!(task.cancellable());
+    assert_eq!(None, task.parent_task_id());
+}
+
+#[test]
+fn success_parse_list_tasks_response() {
+    let f = load_file("tests/samples/list_tasks.json");
+    let deserialized = parse::<ListTasksResponse<Value>>().from_reader(200, f).unwrap();
+
+    let tasks: Vec<_> = deserialized.iter().map(|task| task.task_id()).collect();
+
+    assert_eq!(vec!["oTUltX4IQMOUUVeiohTt8A:12345", "oTUltX4IQMOUUVeiohTt8A:12346"], tasks);
+
+    let child = deserialized.iter().nth(1).unwrap();
+
+    assert!(child.status().is_none());
+    assert!(!child.cancellable());
+    assert_eq!(Some("oTUltX4IQMOUUVeiohTt8A:12347"), child.parent_task_id());
+}
+
+#[test]
+fn success_parse_list_tasks_node_failures() {
+    let f = load_file("tests/samples/list_tasks.json");
+    let deserialized = parse::<ListTasksResponse<Value>>().from_reader(200, f).unwrap();
+
+    assert!(!deserialized.is_ok());
+
+    let valid = match deserialized.node_failures().next() {
+        Some(&ApiError::Other(ref cause)) => cause.get("type").and_then(|ty| ty.as_str()) == Some("failed_node_exception"),
+        _ => false,
+    };
+
+    assert!(valid);
+}
+
+#[test]
+fn success_parse_cancel_tasks_response() {
+    let f = load_file("tests/samples/cancel_task.json");
+    let deserialized = parse::<CancelTasksResponse<Value>>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.is_ok());
+
+    let tasks: Vec<_> = deserialized.into_iter().map(|task| task.task_id()).collect();
+
+    assert_eq!(vec!["oTUltX4IQMOUUVeiohTt8A:12345"], tasks);
+}
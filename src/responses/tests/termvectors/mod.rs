@@ -0,0 +1,80 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_termvectors_response() {
+    let f = load_file("tests/samples/termvectors.json");
+    let deserialized = parse::<TermvectorsResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.found());
+    assert_eq!("twitter", deserialized.index());
+    assert_eq!("tweet", deserialized.ty());
+    assert_eq!(Some("1"), deserialized.id());
+    assert_eq!(Some(1), deserialized.version());
+
+    let field = deserialized.field("text").unwrap();
+    let stats = field.field_statistics().unwrap();
+
+    assert_eq!(4, stats.sum_doc_freq());
+    assert_eq!(2, stats.doc_count());
+    assert_eq!(6, stats.sum_ttf());
+
+    let terms: Vec<_> = field.iter().map(|(term, _)| &**term).collect();
+    assert_eq!(vec!["test", "twitter"], terms);
+
+    let test = field.term("test").unwrap();
+
+    assert_eq!(3, test.term_freq());
+    assert_eq!(Some(2), test.doc_freq());
+    assert_eq!(Some(4), test.ttf());
+    assert_eq!(None, test.score());
+    assert_eq!(3, test.tokens().len());
+
+    let token = &test.tokens()[0];
+
+    assert_eq!(Some(1), token.position());
+    assert_eq!(Some(8), token.start_offset());
+    assert_eq!(Some(12), token.end_offset());
+    assert_eq!(Some("d29yZA=="), token.payload());
+}
+
+#[test]
+fn success_parse_termvectors_not_found() {
+    let deserialized = parse::<TermvectorsResponse>()
+        .from_slice(
+            200,
+            br#"{ "_index": "twitter", "_type": "tweet", "_id": "5", "found": false, "took": 0 }"#,
+        )
+        .unwrap();
+
+    assert!(!deserialized.found());
+    assert!(deserialized.fields().is_empty());
+}
+
+#[test]
+fn success_parse_mtermvectors_response() {
+    let f = load_file("tests/samples/mtermvectors.json");
+    let deserialized = parse::<MtermvectorsResponse>().from_reader(200, f).unwrap();
+
+    let ids: Vec<_> = deserialized.iter().map(|doc| doc.id()).collect();
+    assert_eq!(vec![Some("2"), None, Some("3")], ids);
+
+    let docs: Vec<_> = deserialized.into_iter().collect();
+
+    let term = docs[0].field("message").unwrap().term("elasticsearch").unwrap();
+
+    assert!(docs[0].field("message").unwrap().field_statistics().is_none());
+    assert_eq!(None, term.doc_freq());
+    assert_eq!(Some(2), term.tokens()[0].position());
+    assert_eq!(None, term.tokens()[0].start_offset());
+
+    let artificial = docs[1].field("user").unwrap();
+
+    assert_eq!(-1, artificial.field_statistics().unwrap().sum_ttf());
+    assert_eq!(Some(4), artificial.term("john").unwrap().tokens()[0].end_offset());
+
+    assert!(!docs[2].found());
+}
@@ -0,0 +1,47 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_validate_query_response() {
+    let f = load_file("tests/samples/validate_query.json");
+    let deserialized = parse::<ValidateQueryResponse>().from_reader(200, f).unwrap();
+
+    assert!(!deserialized.valid());
+    assert_eq!(1, deserialized.shards().unwrap().total());
+
+    let explanation = &deserialized.explanations()[0];
+
+    assert_eq!("twitter", explanation.index());
+    assert_eq!(None, explanation.shard());
+    assert!(!explanation.valid());
+    assert!(explanation.error().unwrap().contains("NumberFormatException"));
+    assert_eq!(None, explanation.explanation());
+}
+
+#[test]
+fn success_parse_validate_query_rewrite_response() {
+    let f = load_file("tests/samples/validate_query_rewrite.json");
+    let deserialized = parse::<ValidateQueryResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.valid());
+
+    let shards: Vec<_> = deserialized.iter().map(|explanation| explanation.shard()).collect();
+    assert_eq!(vec![Some(0), Some(1)], shards);
+
+    assert_eq!(
+        Some("+(user:kimchy)^0.9 #ConstantScore(+ConstantScore(_type:tweet))"),
+        deserialized.explanations()[1].explanation()
+    );
+}
+
+#[test]
+fn success_parse_validate_query_valid_only() {
+    let deserialized = parse::<ValidateQueryResponse>().from_slice(200, br#"{ "valid": true }"#).unwrap();
+
+    assert!(deserialized.valid());
+    assert!(deserialized.shards().is_none());
+    assert!(deserialized.explanations().is_empty());
+}
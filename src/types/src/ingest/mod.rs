@@ -0,0 +1,203 @@
+/*!
+Implementation of Elasticsearch ingest pipelines.
+
+An ingest pipeline is a sequence of processors that are applied to documents before they're indexed.
+The same `Pipeline` can be serialised into the body of a put pipeline or simulate pipeline request, or deserialised from a get pipeline response.
+
+# Examples
+
+Define a pipeline that parses a log line and cleans up the result:
+
+```
+# extern crate elastic_types;
+# use elastic_types::ingest::prelude::*;
+# fn main() {
+let pipeline = Pipeline::new()
+    .description("parse apache logs")
+    .processor(Processor::grok("message", vec!["%{COMMONAPACHELOG}"]))
+    .processor(Processor::date("timestamp", vec!["dd/MMM/YYYY:HH:mm:ss Z"]))
+    .processor(Processor::convert("bytes", ConvertType::Integer))
+    .processor(Processor::remove("message"))
+    .on_failure(Processor::set("error", "{{ _ingest.on_failure_message }}"));
+# }
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/pipeline.html)
+- [Processors](https://www.elastic.co/guide/en/elasticsearch/reference/current/ingest-processors.html)
+*/
+
+pub mod processor;
+
+use self::processor::Processor;
+
+/**
+An ingest pipeline.
+
+Processors are applied to a document in the order they're added.
+If a processor fails then the `on_failure` processors for the pipeline are applied instead of the remaining processors.
+*/
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Pipeline {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default)]
+    processors: Vec<Processor>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    on_failure: Vec<Processor>,
+}
+
+impl Pipeline {
+    /** Create a new pipeline with no processors. */
+    pub fn new() -> Self {
+        Pipeline::default()
+    }
+
+    /** A description of what the pipeline does. */
+    pub fn get_description(&self) -> Option<&str> {
+        self.description.as_ref().map(|description| description.as_ref())
+    }
+
+    /** The processors that are applied to documents. */
+    pub fn get_processors(&self) -> &[Processor] {
+        &self.processors
+    }
+
+    /** The processors that are applied when one of the pipeline processors fails. */
+    pub fn get_on_failure(&self) -> &[Processor] {
+        &self.on_failure
+    }
+
+    /** Set a description of what the pipeline does. */
+    pub fn description<I>(mut self, description: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /** Add a processor to the end of the pipeline. */
+    pub fn processor(mut self, processor: Processor) -> Self {
+        self.processors.push(processor);
+        self
+    }
+
+    /** Add a processor to apply when one of the pipeline processors fails. */
+    pub fn on_failure(mut self, processor: Processor) -> Self {
+        self.on_failure.push(processor);
+        self
+    }
+}
+
+pub mod prelude {
+    /*!
+    Includes all types for ingest pipelines.
+
+    This is a convenience module to make it easy to build pipelines without too many `use` statements.
+    */
+
+    pub use super::Pipeline;
+    pub use super::processor::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+
+    use ingest::prelude::*;
+
+    #[test]
+    fn serialise_pipeline_default() {
+        let ser = serde_json::to_string(&Pipeline::new()).unwrap();
+
+        assert_eq!(r#"{"processors":[]}"#, ser);
+    }
+
+    #[test]
+    fn serialise_pipeline_custom() {
+        let pipeline = Pipeline::new()
+            .description("parse logs")
+            .processor(Processor::grok("message", vec!["%{COMMONAPACHELOG}"]).tag("parse"))
+            .processor(Processor::rename("clientip", "client_ip").on_failure(Processor::set("client_ip", Value::Null)))
+            .processor(Processor::convert("bytes", ConvertType::Integer).ignore_failure(true))
+            .on_failure(Processor::set("error", "{{ _ingest.on_failure_message }}"));
+
+        let ser = serde_json::to_string(&pipeline).unwrap();
+
+        let expected = json_str!({
+            "description": "parse logs",
+            "processors": [
+                {
+                    "grok": {
+                        "field": "message",
+                        "patterns": ["%{COMMONAPACHELOG}"],
+                        "tag": "parse"
+                    }
+                },
+                {
+                    "rename": {
+                        "field": "clientip",
+                        "target_field": "client_ip",
+                        "on_failure": [
+                            {
+                                "set": {
+                                    "field": "client_ip",
+                                    "value": null
+                                }
+                            }
+                        ]
+                    }
+                },
+                {
+                    "convert": {
+                        "field": "bytes",
+                        "ignore_failure": true,
+                        "type": "integer"
+                    }
+                }
+            ],
+            "on_failure": [
+                {
+                    "set": {
+                        "field": "error",
+                        "value": "{{ _ingest.on_failure_message }}"
+                    }
+                }
+            ]
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn deserialise_pipeline() {
+        let pipeline: Pipeline = serde_json::from_str(&json_str!({
+            "description": "parse logs",
+            "processors": [
+                {
+                    "date": {
+                        "field": "timestamp",
+                        "formats": ["ISO8601"],
+                        "on_failure": [
+                            {
+                                "remove": {
+                                    "field": "timestamp"
+                                }
+                            }
+                        ]
+                    }
+                }
+            ]
+        })).unwrap();
+
+        let processor = &pipeline.get_processors()[0];
+
+        assert_eq!(Some("parse logs"), pipeline.get_description());
+        assert_eq!("date", processor.ty());
+        assert_eq!(Some(&Value::from("timestamp")), processor.options().get("field"));
+        assert_eq!(Processor::remove("timestamp"), processor.get_on_failure()[0]);
+        assert!(pipeline.get_on_failure().is_empty());
+    }
+}
@@ -0,0 +1,283 @@
+/*!
+Processors for ingest pipelines.
+
+Each processor has a `type`, like `set` or `grok`, and a set of options for that type.
+Constructors are provided for common processors, and any other processor can be created with [`Processor::new`](struct.Processor.html#method.new).
+*/
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde_json::{self, Map, Value};
+
+const ON_FAILURE: &'static str = "on_failure";
+
+/**
+A single processor in an ingest pipeline.
+
+# Examples
+
+Set a field to a value:
+
+```
+# use elastic_types::ingest::prelude::*;
+let processor = Processor::set("indexed", true);
+```
+
+Parse a field with grok patterns, ignoring documents that don't match:
+
+```
+# use elastic_types::ingest::prelude::*;
+let processor = Processor::grok("message", vec!["%{IP:client} %{WORD:method}"])
+    .ignore_failure(true);
+```
+
+Use a processor that doesn't have a constructor:
+
+```
+# use elastic_types::ingest::prelude::*;
+let processor = Processor::new("lowercase").option("field", "method");
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Processor {
+    ty: String,
+    options: Map<String, Value>,
+    on_failure: Vec<Processor>,
+}
+
+impl Processor {
+    /** Create a processor with the given `type` and no options. */
+    pub fn new<I>(ty: I) -> Self
+    where
+        I: Into<String>,
+    {
+        Processor {
+            ty: ty.into(),
+            options: Map::new(),
+            on_failure: Vec::new(),
+        }
+    }
+
+    /** Create a `set` processor that sets a field to a value. */
+    pub fn set<IField, IValue>(field: IField, value: IValue) -> Self
+    where
+        IField: Into<String>,
+        IValue: Into<Value>,
+    {
+        Processor::new("set").option("field", field.into()).option("value", value)
+    }
+
+    /** Create a `rename` processor that renames a field. */
+    pub fn rename<IField, ITarget>(field: IField, target_field: ITarget) -> Self
+    where
+        IField: Into<String>,
+        ITarget: Into<String>,
+    {
+        Processor::new("rename")
+            .option("field", field.into())
+            .option("target_field", target_field.into())
+    }
+
+    /** Create a `remove` processor that removes a field. */
+    pub fn remove<IField>(field: IField) -> Self
+    where
+        IField: Into<String>,
+    {
+        Processor::new("remove").option("field", field.into())
+    }
+
+    /**
+    Create a `date` processor that parses a date field using the given formats.
+
+    The parsed date is stored in the `@timestamp` field unless a `target_field` option is set.
+    */
+    pub fn date<IField, IFormats>(field: IField, formats: IFormats) -> Self
+    where
+        IField: Into<String>,
+        IFormats: IntoIterator,
+        IFormats::Item: Into<String>,
+    {
+        Processor::new("date")
+            .option("field", field.into())
+            .option("formats", strings(formats))
+    }
+
+    /** Create a `grok` processor that extracts fields from a text field using the given patterns. */
+    pub fn grok<IField, IPatterns>(field: IField, patterns: IPatterns) -> Self
+    where
+        IField: Into<String>,
+        IPatterns: IntoIterator,
+        IPatterns::Item: Into<String>,
+    {
+        Processor::new("grok")
+            .option("field", field.into())
+            .option("patterns", strings(patterns))
+    }
+
+    /** Create a `convert` processor that converts a field to a different type. */
+    pub fn convert<IField>(field: IField, ty: ConvertType) -> Self
+    where
+        IField: Into<String>,
+    {
+        Processor::new("convert")
+            .option("field", field.into())
+            .option("type", ty.as_str())
+    }
+
+    /**
+    Create a `script` processor that runs an inline script.
+
+    The document source can be accessed through `ctx` in the script.
+    */
+    pub fn script<IScript>(inline: IScript) -> Self
+    where
+        IScript: Into<String>,
+    {
+        Processor::new("script").option("inline", inline.into())
+    }
+
+    /** Set an option for the processor. */
+    pub fn option<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        self.options.insert(key.into(), value.into());
+        self
+    }
+
+    /** Set a tag that identifies the processor in errors and simulate results. */
+    pub fn tag<I>(self, tag: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.option("tag", tag.into())
+    }
+
+    /** Set whether or not failures in the processor should be ignored. */
+    pub fn ignore_failure(self, ignore_failure: bool) -> Self {
+        self.option("ignore_failure", ignore_failure)
+    }
+
+    /** Add a processor to apply when this processor fails. */
+    pub fn on_failure(mut self, processor: Processor) -> Self {
+        self.on_failure.push(processor);
+        self
+    }
+
+    /** The `type` of the processor. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The options for the processor. */
+    pub fn options(&self) -> &Map<String, Value> {
+        &self.options
+    }
+
+    /** The processors that are applied when this processor fails. */
+    pub fn get_on_failure(&self) -> &[Processor] {
+        &self.on_failure
+    }
+}
+
+fn strings<I>(values: I) -> Value
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    Value::Array(values.into_iter().map(|value| Value::String(value.into())).collect())
+}
+
+impl Serialize for Processor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Options<'a>(&'a Processor);
+
+        impl<'a> Serialize for Options<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let processor = self.0;
+                let len = processor.options.len() + if processor.on_failure.is_empty() { 0 } else { 1 };
+
+                let mut state = serializer.serialize_map(Some(len))?;
+
+                for (key, value) in &processor.options {
+                    state.serialize_entry(key, value)?;
+                }
+
+                if !processor.on_failure.is_empty() {
+                    state.serialize_entry(ON_FAILURE, &processor.on_failure)?;
+                }
+
+                state.end()
+            }
+        }
+
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(&self.ty, &Options(self))?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Processor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let processor = Map::deserialize(deserializer)?;
+
+        if processor.len() != 1 {
+            return Err(D::Error::custom(format!("expected a single processor but got {}", processor.len())));
+        }
+
+        let (ty, options) = processor.into_iter().next().expect("processor has a single entry");
+
+        let mut options = match options {
+            Value::Object(options) => options,
+            options => return Err(D::Error::custom(format!("expected processor options to be an object but got `{}`", options))),
+        };
+
+        let on_failure = match options.remove(ON_FAILURE) {
+            Some(on_failure) => serde_json::from_value(on_failure).map_err(D::Error::custom)?,
+            None => Vec::new(),
+        };
+
+        Ok(Processor {
+            ty: ty,
+            options: options,
+            on_failure: on_failure,
+        })
+    }
+}
+
+/** The type to convert a field to in a `convert` processor. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConvertType {
+    /** Convert to an integer. */
+    Integer,
+    /** Convert to a float. */
+    Float,
+    /** Convert to a string. */
+    String,
+    /** Convert to a boolean. */
+    Boolean,
+    /** Convert strings to the most appropriate type. */
+    Auto,
+}
+
+impl ConvertType {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            ConvertType::Integer => "integer",
+            ConvertType::Float => "float",
+            ConvertType::String => "string",
+            ConvertType::Boolean => "boolean",
+            ConvertType::Auto => "auto",
+        }
+    }
+}
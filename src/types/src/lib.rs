@@ -537,6 +537,8 @@ pub mod geo;
 pub mod ip;
 pub mod number;
 pub mod string;
+pub mod settings;
+pub mod ingest;
 
 #[doc(hidden)]
 pub mod derive;
@@ -546,6 +548,7 @@ pub mod prelude {
     Includes all data types.
     
     This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    Index settings and ingest pipelines aren't included, use [`settings::prelude`](../settings/prelude/index.html) and [`ingest::prelude`](../ingest/prelude/index.html) for those.
     */
 
     pub use document::prelude::*;
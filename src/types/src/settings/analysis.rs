@@ -0,0 +1,335 @@
+/*!
+Analysis components for index settings.
+
+Each component has a `type`, like `custom` or `edge_ngram`, and a set of parameters for that type.
+Components are added to [`IndexSettings`](../struct.IndexSettings.html) by name, and can be referenced by that name in field mappings or other components.
+*/
+
+use std::collections::BTreeMap;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde_json::{self, Map, Value};
+
+const CUSTOM_TYPE: &'static str = "custom";
+
+/** The analysis configuration for an index. */
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Analysis {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    analyzer: BTreeMap<String, Analyzer>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    tokenizer: BTreeMap<String, Tokenizer>,
+    #[serde(rename = "filter", default, skip_serializing_if = "BTreeMap::is_empty")]
+    token_filter: BTreeMap<String, TokenFilter>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    char_filter: BTreeMap<String, CharFilter>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    normalizer: BTreeMap<String, Normalizer>,
+}
+
+impl Analysis {
+    /** Create a new analysis configuration with no components. */
+    pub fn new() -> Self {
+        Analysis::default()
+    }
+
+    /** Whether or not any components have been added. */
+    pub fn is_empty(&self) -> bool {
+        self.analyzer.is_empty() && self.tokenizer.is_empty() && self.token_filter.is_empty() && self.char_filter.is_empty() && self.normalizer.is_empty()
+    }
+
+    /** The named analyzers. */
+    pub fn analyzers(&self) -> &BTreeMap<String, Analyzer> {
+        &self.analyzer
+    }
+
+    /** The named tokenizers. */
+    pub fn tokenizers(&self) -> &BTreeMap<String, Tokenizer> {
+        &self.tokenizer
+    }
+
+    /** The named token filters. */
+    pub fn token_filters(&self) -> &BTreeMap<String, TokenFilter> {
+        &self.token_filter
+    }
+
+    /** The named character filters. */
+    pub fn char_filters(&self) -> &BTreeMap<String, CharFilter> {
+        &self.char_filter
+    }
+
+    /** The named normalizers. */
+    pub fn normalizers(&self) -> &BTreeMap<String, Normalizer> {
+        &self.normalizer
+    }
+
+    /** Add a named analyzer. */
+    pub fn analyzer<I>(mut self, name: I, analyzer: Analyzer) -> Self
+    where
+        I: Into<String>,
+    {
+        self.analyzer.insert(name.into(), analyzer);
+        self
+    }
+
+    /** Add a named tokenizer. */
+    pub fn tokenizer<I>(mut self, name: I, tokenizer: Tokenizer) -> Self
+    where
+        I: Into<String>,
+    {
+        self.tokenizer.insert(name.into(), tokenizer);
+        self
+    }
+
+    /** Add a named token filter. */
+    pub fn token_filter<I>(mut self, name: I, filter: TokenFilter) -> Self
+    where
+        I: Into<String>,
+    {
+        self.token_filter.insert(name.into(), filter);
+        self
+    }
+
+    /** Add a named character filter. */
+    pub fn char_filter<I>(mut self, name: I, filter: CharFilter) -> Self
+    where
+        I: Into<String>,
+    {
+        self.char_filter.insert(name.into(), filter);
+        self
+    }
+
+    /** Add a named normalizer. */
+    pub fn normalizer<I>(mut self, name: I, normalizer: Normalizer) -> Self
+    where
+        I: Into<String>,
+    {
+        self.normalizer.insert(name.into(), normalizer);
+        self
+    }
+}
+
+macro_rules! analysis_component {
+    ($(#[$attr:meta])* pub struct $component:ident) => (
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $component {
+            ty: String,
+            params: Map<String, Value>,
+        }
+
+        impl $component {
+            /** Create a component with the given `type`. */
+            pub fn new<I>(ty: I) -> Self
+            where
+                I: Into<String>,
+            {
+                $component {
+                    ty: ty.into(),
+                    params: Map::new(),
+                }
+            }
+
+            /** Set a parameter for the component. */
+            pub fn param<K, V>(mut self, key: K, value: V) -> Self
+            where
+                K: Into<String>,
+                V: Into<Value>,
+            {
+                self.params.insert(key.into(), value.into());
+                self
+            }
+
+            /** The `type` of the component. */
+            pub fn ty(&self) -> &str {
+                &self.ty
+            }
+
+            /** The parameters for the component. */
+            pub fn params(&self) -> &Map<String, Value> {
+                &self.params
+            }
+        }
+
+        impl Serialize for $component {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_map(Some(self.params.len() + 1))?;
+
+                state.serialize_entry("type", &self.ty)?;
+
+                for (key, value) in &self.params {
+                    state.serialize_entry(key, value)?;
+                }
+
+                state.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $component {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let mut params = Map::deserialize(deserializer)?;
+
+                let ty = match params.remove("type") {
+                    Some(ty) => serde_json::from_value(ty).map_err(D::Error::custom)?,
+                    None => CUSTOM_TYPE.to_owned(),
+                };
+
+                Ok($component {
+                    ty: ty,
+                    params: params,
+                })
+            }
+        }
+    )
+}
+
+macro_rules! custom_chain {
+    ($component:ident) => (
+        impl $component {
+            /** Add a named token filter to the end of a `custom` chain. */
+            pub fn filter<I>(self, name: I) -> Self
+            where
+                I: Into<String>,
+            {
+                self.push_param("filter", name.into())
+            }
+
+            /** Add a named character filter to the end of a `custom` chain. */
+            pub fn char_filter<I>(self, name: I) -> Self
+            where
+                I: Into<String>,
+            {
+                self.push_param("char_filter", name.into())
+            }
+
+            fn push_param(mut self, key: &str, name: String) -> Self {
+                let mut names = match self.params.remove(key) {
+                    Some(Value::Array(names)) => names,
+                    Some(name) => vec![name],
+                    None => Vec::new(),
+                };
+
+                names.push(Value::String(name));
+                self.params.insert(key.to_owned(), Value::Array(names));
+
+                self
+            }
+        }
+    )
+}
+
+analysis_component!(
+    /**
+    An analyzer that converts text into terms.
+
+    Missing `type`s are deserialised as `custom`.
+
+    # Examples
+
+    A `custom` analyzer with a tokenizer and token filters:
+
+    ```
+    # use elastic_types::settings::prelude::*;
+    let analyzer = Analyzer::custom("standard")
+        .filter("lowercase")
+        .filter("asciifolding");
+    ```
+
+    A built-in analyzer with parameters:
+
+    ```
+    # use elastic_types::settings::prelude::*;
+    let analyzer = Analyzer::new("standard").param("stopwords", "_english_");
+    ```
+    */
+    pub struct Analyzer
+);
+
+custom_chain!(Analyzer);
+
+impl Analyzer {
+    /** Create a `custom` analyzer with the given named tokenizer. */
+    pub fn custom<I>(tokenizer: I) -> Self
+    where
+        I: Into<String>,
+    {
+        Analyzer::new(CUSTOM_TYPE).param("tokenizer", tokenizer.into())
+    }
+}
+
+analysis_component!(
+    /**
+    A tokenizer that splits text into tokens.
+
+    # Examples
+
+    ```
+    # use elastic_types::settings::prelude::*;
+    let tokenizer = Tokenizer::new("edge_ngram")
+        .param("min_gram", 2)
+        .param("max_gram", 10);
+    ```
+    */
+    pub struct Tokenizer
+);
+
+analysis_component!(
+    /**
+    A token filter that adds, removes or changes tokens.
+
+    # Examples
+
+    ```
+    # use elastic_types::settings::prelude::*;
+    let filter = TokenFilter::new("stemmer").param("language", "english");
+    ```
+    */
+    pub struct TokenFilter
+);
+
+analysis_component!(
+    /**
+    A character filter that preprocesses text before it's tokenised.
+
+    # Examples
+
+    ```
+    # use elastic_types::settings::prelude::*;
+    let filter = CharFilter::new("html_strip");
+    ```
+    */
+    pub struct CharFilter
+);
+
+analysis_component!(
+    /**
+    A normalizer that applies character and token filters to a `keyword` field as a single token.
+
+    Missing `type`s are deserialised as `custom`.
+
+    # Examples
+
+    ```
+    # use elastic_types::settings::prelude::*;
+    let normalizer = Normalizer::custom()
+        .char_filter("strip_html")
+        .filter("lowercase");
+    ```
+    */
+    pub struct Normalizer
+);
+
+custom_chain!(Normalizer);
+
+impl Normalizer {
+    /** Create a `custom` normalizer. */
+    pub fn custom() -> Self {
+        Normalizer::new(CUSTOM_TYPE)
+    }
+}
@@ -0,0 +1,315 @@
+/*!
+Implementation of Elasticsearch index settings.
+
+Index settings configure the number of shards and replicas for an index, along with the analysis chain used by `text` and `keyword` fields.
+The same `IndexSettings` can be serialised into the body of a create index request, sent in a put settings request or deserialised from a get settings response.
+
+# Examples
+
+Define settings with a custom analyzer:
+
+```
+# extern crate elastic_types;
+# use elastic_types::settings::prelude::*;
+# fn main() {
+let settings = IndexSettings::new()
+    .number_of_shards(3)
+    .number_of_replicas(1)
+    .refresh_interval("30s")
+    .token_filter("english_stemmer", TokenFilter::new("stemmer").param("language", "english"))
+    .analyzer("english_text", Analyzer::custom("standard").filter("lowercase").filter("english_stemmer"));
+# }
+```
+
+A `text` field can then refer to the analyzer by name:
+
+```
+# extern crate elastic_types;
+# use elastic_types::prelude::*;
+# fn main() {
+#[derive(Default)]
+struct MyTextMapping;
+impl TextMapping for MyTextMapping {
+    fn analyzer() -> Option<&'static str> {
+        Some("english_text")
+    }
+}
+# }
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html)
+- [Analysis](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis.html)
+*/
+
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde_json::Value;
+
+pub mod analysis;
+
+use self::analysis::{Analysis, Analyzer, CharFilter, Normalizer, TokenFilter, Tokenizer};
+
+/**
+The settings for an index.
+
+When deserialising, numeric settings can be either numbers or strings, because Elasticsearch returns them as strings.
+Settings that aren't modelled here, like the index `uuid`, are ignored.
+*/
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IndexSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_opt_u32")]
+    number_of_shards: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_opt_u32")]
+    number_of_replicas: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    refresh_interval: Option<String>,
+    #[serde(default, skip_serializing_if = "Analysis::is_empty")]
+    analysis: Analysis,
+}
+
+impl IndexSettings {
+    /** Create a new set of index settings with nothing configured. */
+    pub fn new() -> Self {
+        IndexSettings::default()
+    }
+
+    /** The number of primary shards. */
+    pub fn get_number_of_shards(&self) -> Option<u32> {
+        self.number_of_shards
+    }
+
+    /** The number of replicas for each primary shard. */
+    pub fn get_number_of_replicas(&self) -> Option<u32> {
+        self.number_of_replicas
+    }
+
+    /** How often the index is refreshed, like `1s`. */
+    pub fn get_refresh_interval(&self) -> Option<&str> {
+        self.refresh_interval.as_ref().map(|interval| interval.as_ref())
+    }
+
+    /** The analysis configuration. */
+    pub fn get_analysis(&self) -> &Analysis {
+        &self.analysis
+    }
+
+    /**
+    Set the number of primary shards.
+
+    The number of shards can only be set when an index is created.
+    */
+    pub fn number_of_shards(mut self, shards: u32) -> Self {
+        self.number_of_shards = Some(shards);
+        self
+    }
+
+    /** Set the number of replicas for each primary shard. */
+    pub fn number_of_replicas(mut self, replicas: u32) -> Self {
+        self.number_of_replicas = Some(replicas);
+        self
+    }
+
+    /**
+    Set how often the index is refreshed, like `1s`.
+
+    Use `-1` to disable refreshing.
+    */
+    pub fn refresh_interval<I>(mut self, interval: I) -> Self
+    where
+        I: ToString,
+    {
+        self.refresh_interval = Some(interval.to_string());
+        self
+    }
+
+    /** Set the analysis configuration, replacing any analysis components that were already added. */
+    pub fn analysis(mut self, analysis: Analysis) -> Self {
+        self.analysis = analysis;
+        self
+    }
+
+    /**
+    Add a named analyzer.
+
+    The name can be referenced by the `analyzer` or `search_analyzer` of a field mapping.
+    */
+    pub fn analyzer<I>(mut self, name: I, analyzer: Analyzer) -> Self
+    where
+        I: Into<String>,
+    {
+        self.analysis = self.analysis.analyzer(name, analyzer);
+        self
+    }
+
+    /** Add a named tokenizer. */
+    pub fn tokenizer<I>(mut self, name: I, tokenizer: Tokenizer) -> Self
+    where
+        I: Into<String>,
+    {
+        self.analysis = self.analysis.tokenizer(name, tokenizer);
+        self
+    }
+
+    /** Add a named token filter. */
+    pub fn token_filter<I>(mut self, name: I, filter: TokenFilter) -> Self
+    where
+        I: Into<String>,
+    {
+        self.analysis = self.analysis.token_filter(name, filter);
+        self
+    }
+
+    /** Add a named character filter. */
+    pub fn char_filter<I>(mut self, name: I, filter: CharFilter) -> Self
+    where
+        I: Into<String>,
+    {
+        self.analysis = self.analysis.char_filter(name, filter);
+        self
+    }
+
+    /**
+    Add a named normalizer.
+
+    The name can be referenced by the `normalizer` of a `keyword` field mapping.
+    */
+    pub fn normalizer<I>(mut self, name: I, normalizer: Normalizer) -> Self
+    where
+        I: Into<String>,
+    {
+        self.analysis = self.analysis.normalizer(name, normalizer);
+        self
+    }
+}
+
+fn deserialize_opt_u32<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::Number(value)) => match value.as_u64() {
+            Some(value) if value <= u32::max_value() as u64 => Ok(Some(value as u32)),
+            _ => Err(D::Error::custom(format!("expected an unsigned integer but got `{}`", value))),
+        },
+        Some(Value::String(value)) => value
+            .parse()
+            .map(Some)
+            .map_err(|_| D::Error::custom(format!("expected an unsigned integer but got `{}`", value))),
+        Some(Value::Null) | None => Ok(None),
+        Some(value) => Err(D::Error::custom(format!("expected an unsigned integer but got `{}`", value))),
+    }
+}
+
+pub mod prelude {
+    /*!
+    Includes all types for index settings.
+
+    This is a convenience module to make it easy to build index settings without too many `use` statements.
+    */
+
+    pub use super::IndexSettings;
+    pub use super::analysis::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+
+    use settings::prelude::*;
+
+    #[test]
+    fn serialise_settings_default() {
+        let ser = serde_json::to_string(&IndexSettings::new()).unwrap();
+
+        assert_eq!("{}", ser);
+    }
+
+    #[test]
+    fn serialise_settings_custom() {
+        let settings = IndexSettings::new()
+            .number_of_shards(3)
+            .number_of_replicas(1)
+            .refresh_interval("30s")
+            .char_filter("strip_html", CharFilter::new("html_strip"))
+            .tokenizer("autocomplete", Tokenizer::new("edge_ngram").param("min_gram", 2).param("max_gram", 10))
+            .token_filter("english_stemmer", TokenFilter::new("stemmer").param("language", "english"))
+            .analyzer(
+                "english_text",
+                Analyzer::custom("standard")
+                    .char_filter("strip_html")
+                    .filter("lowercase")
+                    .filter("english_stemmer"),
+            )
+            .normalizer("lowercase_keyword", Normalizer::custom().filter("lowercase"));
+
+        let ser = serde_json::to_string(&settings).unwrap();
+
+        let expected = json_str!({
+            "number_of_shards": 3,
+            "number_of_replicas": 1,
+            "refresh_interval": "30s",
+            "analysis": {
+                "analyzer": {
+                    "english_text": {
+                        "type": "custom",
+                        "char_filter": ["strip_html"],
+                        "filter": ["lowercase", "english_stemmer"],
+                        "tokenizer": "standard"
+                    }
+                },
+                "tokenizer": {
+                    "autocomplete": {
+                        "type": "edge_ngram",
+                        "max_gram": 10,
+                        "min_gram": 2
+                    }
+                },
+                "filter": {
+                    "english_stemmer": {
+                        "type": "stemmer",
+                        "language": "english"
+                    }
+                },
+                "char_filter": {
+                    "strip_html": {
+                        "type": "html_strip"
+                    }
+                },
+                "normalizer": {
+                    "lowercase_keyword": {
+                        "type": "custom",
+                        "filter": ["lowercase"]
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn deserialise_settings_from_strings() {
+        let settings: IndexSettings = serde_json::from_str(&json_str!({
+            "number_of_shards": "5",
+            "number_of_replicas": "1",
+            "uuid": "KkrB3OfCQnWA-Lx3Y1QELg",
+            "analysis": {
+                "analyzer": {
+                    "english_text": {
+                        "filter": ["lowercase"],
+                        "tokenizer": "standard"
+                    }
+                }
+            }
+        })).unwrap();
+
+        let analyzer = &settings.get_analysis().analyzers()["english_text"];
+
+        assert_eq!(Some(5), settings.get_number_of_shards());
+        assert_eq!(Some(1), settings.get_number_of_replicas());
+        assert_eq!(None, settings.get_refresh_interval());
+        assert_eq!("custom", analyzer.ty());
+        assert_eq!(Some(&Value::from("standard")), analyzer.params().get("tokenizer"));
+    }
+}
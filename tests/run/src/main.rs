@@ -26,6 +26,7 @@ use term_painter::Color::*;
 mod document;
 mod search;
 mod index;
+mod snapshot;
 mod run_tests;
 mod build_client;
 mod build_container;
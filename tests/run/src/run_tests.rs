@@ -87,14 +87,17 @@ pub fn call(client: AsyncClient, max_concurrent_tests: usize) -> Box<Future<Item
     use search;
     use document;
     use index;
+    use snapshot;
 
     let document_tests = document::tests().into_iter();
     let search_tests = search::tests().into_iter();
     let index_tests = index::tests().into_iter();
+    let snapshot_tests = snapshot::tests().into_iter();
 
     let all_tests = document_tests
         .chain(search_tests)
         .chain(index_tests)
+        .chain(snapshot_tests)
         .map(move |t| t(client.clone()));
 
     let test_stream = stream::futures_unordered(all_tests)
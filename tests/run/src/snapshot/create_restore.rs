@@ -0,0 +1,93 @@
+use futures::Future;
+use elastic::prelude::*;
+use elastic::error::Error;
+use run_tests::IntegrationTest;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CreateRestore;
+
+const INDEX: &'static str = "snapshot_idx";
+const RESTORED_INDEX: &'static str = "restored_snapshot_idx";
+const REPOSITORY: &'static str = "snapshot_repo";
+const SNAPSHOT: &'static str = "snapshot_1";
+const LOCATION: &'static str = "/tmp/snapshots/snapshot_repo";
+
+impl IntegrationTest for CreateRestore {
+    type Response = (SnapshotStatusResponse, RestoreSnapshotResponse);
+
+    fn kind() -> &'static str {
+        "snapshot"
+    }
+    fn name() -> &'static str {
+        "create_restore"
+    }
+
+    // Ensure the index exists and there's an `fs` repository to snapshot it into without a snapshot from a previous run
+    fn prepare(&self, client: AsyncClient) -> Box<Future<Item = (), Error = Error>> {
+        let delete_res = client.index_delete(index(RESTORED_INDEX)).send().then(|_| Ok(()));
+
+        let create_client = client.clone();
+        let repository_client = client.clone();
+        let snapshot_client = client.clone();
+
+        Box::new(
+            delete_res
+                .and_then(move |_| create_client.index_create(index(INDEX)).send().then(|_| Ok(())))
+                .and_then(move |_| {
+                    repository_client
+                        .snapshot_repository_put(repository(REPOSITORY), SnapshotRepository::fs(LOCATION))
+                        .send()
+                        .map(|_| ())
+                })
+                .and_then(move |_| {
+                    snapshot_client
+                        .snapshot_delete(repository(REPOSITORY), snapshot(SNAPSHOT))
+                        .send()
+                        .then(|_| Ok(()))
+                }),
+        )
+    }
+
+    // Snapshot the index, get the status of the snapshot, then restore it with a new name
+    fn request(&self, client: AsyncClient) -> Box<Future<Item = Self::Response, Error = Error>> {
+        let create_res = client
+            .snapshot_create(repository(REPOSITORY), snapshot(SNAPSHOT))
+            .index(INDEX)
+            .include_global_state(false)
+            .wait_for_completion()
+            .send();
+
+        let status_client = client.clone();
+        let restore_client = client.clone();
+
+        Box::new(
+            create_res
+                .and_then(move |_| {
+                    status_client
+                        .snapshot_status(repository(REPOSITORY))
+                        .snapshot(SNAPSHOT)
+                        .send()
+                })
+                .and_then(move |status| {
+                    restore_client
+                        .snapshot_restore(repository(REPOSITORY), snapshot(SNAPSHOT))
+                        .index(INDEX)
+                        .rename("(.+)", "restored_$1")
+                        .wait_for_completion()
+                        .send()
+                        .map(|restore| (status, restore))
+                }),
+        )
+    }
+
+    // Ensure every shard was snapshotted and the index was restored with its new name
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let status = match res.0.get(SNAPSHOT) {
+            Some(status) => status,
+            None => return false,
+        };
+        let restore = &res.1;
+
+        status.shards_stats().done() == status.shards_stats().total() && restore.indices() == &[RESTORED_INDEX.to_owned()]
+    }
+}
@@ -0,0 +1,9 @@
+use run_tests::{test, Test};
+
+mod create_restore;
+
+pub fn tests() -> Vec<Test> {
+    vec![
+        Box::new(|client| test(client, create_restore::CreateRestore)),
+    ]
+}
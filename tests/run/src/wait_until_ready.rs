@@ -7,15 +7,20 @@ use elastic::prelude::*;
 use elastic::Error;
 
 #[derive(Clone)]
-struct Ping {
+struct Health {
     client: AsyncClient,
 }
 
-impl Ping {
+impl Health {
     fn is_ready(&self) -> Box<Future<Item = bool, Error = Box<StdError>>> {
-        let request = self.client.ping().send().map_err(|e| e.into());
+        let request = self.client
+            .cluster_health()
+            .wait_for_status(ClusterHealthStatus::Yellow)
+            .wait_timeout(Duration::from_secs(5))
+            .send()
+            .map_err(|e| e.into());
 
-        let check = request.then(|res: Result<PingResponse, Error>| match res {
+        let check = request.then(|res: Result<ClusterHealthResponse, Error>| match res {
             Ok(_) => Ok(true),
             _ => Ok(false),
         });
@@ -30,12 +35,12 @@ pub fn call(client: AsyncClient, timeout_secs: u64) -> Box<Future<Item = (), Err
         timeout_secs
     );
 
-    let stream = stream::repeat(Ping { client: client });
+    let stream = stream::repeat(Health { client: client });
 
     let wait = Interval::new(Instant::now(), Duration::from_secs(10)).from_err();
 
     let poll = stream
-        .take_while(|ping| ping.is_ready().map(|ready| !ready))
+        .take_while(|health| health.is_ready().map(|ready| !ready))
         .zip(wait)
         .collect();
 